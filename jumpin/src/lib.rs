@@ -4,12 +4,15 @@ use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
-use puzzle::Puzzle;
+use puzzle::{
+    Commuting, Heuristic, InvertiblePuzzle, Keyed, Labeled, MacroPuzzle, MutablePuzzle, PackState,
+    Puzzle, RankablePuzzle, Symmetric, ZobristHash,
+};
 
 /// The `Object` type represents objects that may be placed (and moved) on the
 /// gameboard.  (Note that there is no `Hole` variant, as the holes are always
 /// at fixed positions on the gameboard.)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Object {
     WhiteRabbit,
     BrownRabbit,
@@ -45,6 +48,18 @@ impl Object {
                 | (Object::Fox2Tail, Object::Fox2Head)
         )
     }
+    /// A human-readable name of the object (the same for the head and the tail
+    /// of a fox).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Object::WhiteRabbit => "white rabbit",
+            Object::BrownRabbit => "brown rabbit",
+            Object::GreyRabbit => "grey rabbit",
+            Object::Mushroom => "mushroom",
+            Object::Fox1Head | Object::Fox1Tail => "fox 1",
+            Object::Fox2Head | Object::Fox2Tail => "fox 2",
+        }
+    }
     pub fn checking_for_obst(&self) -> bool {
         matches!(
             self,
@@ -148,6 +163,22 @@ impl Pos {
         };
         Some(Pos::new(x, y))
     }
+    /// Returns the images of `self` under the eight rotations and reflections
+    /// of the gameboard (the first of which is the identity).
+    fn symmetries(&self) -> [Self; 8] {
+        let (x, y) = self.xy();
+        [
+            (x, y),
+            (4 - y, x),
+            (4 - x, 4 - y),
+            (y, 4 - x),
+            (4 - x, y),
+            (x, 4 - y),
+            (y, x),
+            (4 - y, 4 - x),
+        ]
+        .map(|(x, y)| Pos::new(x, y))
+    }
     /// An iterator over all positions of the gameboard.
     pub fn values() -> impl Iterator<Item = Self> {
         (0..5).flat_map(|y| (0..5).map(move |x| Pos::new(x, y)))
@@ -162,7 +193,7 @@ impl Display for Pos {
 
 /// The `Direction` type represents the cardinal directions, in which objects
 /// may be moved on the gameboard.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Direction {
     North,
     South,
@@ -178,15 +209,16 @@ impl Direction {
             Direction::East => Direction::West,
         }
     }
+    /// All directions.
+    const VALUES: [Self; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ];
     /// An iterator over all directions.
     fn values() -> impl Iterator<Item = Self> {
-        [
-            Direction::North,
-            Direction::South,
-            Direction::West,
-            Direction::East,
-        ]
-        .into_iter()
+        Self::VALUES.into_iter()
     }
 }
 impl Display for Direction {
@@ -206,26 +238,29 @@ impl Display for Direction {
 mod jumpin {
     use super::Object;
     use super::Pos;
+    use puzzle::Grid;
     use serde::{Deserialize, Serialize};
 
     /// The `JumpIN` type represents a
     /// https://www.smartgames.eu/uk/one-player-games/jumpin[JumpIN'] puzzle state:
     /// a gameboard with placed objects.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-    pub struct JumpIN([[Option<Object>; 5]; 5]);
+    ///
+    /// The gameboard is indexed by `(x, y)` (i.e., each "row" of the grid is a
+    /// column of the gameboard), which preserves the serialized format of
+    /// puzzle states.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+    pub struct JumpIN(Grid<Option<Object>, 5, 5>);
     impl JumpIN {
         pub(super) fn new() -> Self {
-            JumpIN([[None; 5]; 5])
+            JumpIN(Grid::default())
         }
         /// Returns a reference to the gameboard at position `pos`.
         pub(super) fn get(&self, pos: Pos) -> &Option<Object> {
-            let (x, y) = pos.xy();
-            &self.0[x][y]
+            &self.0[pos.xy()]
         }
         /// Returns a mutable reference to the gameboard at position `pos`.
         pub(super) fn get_mut(&mut self, pos: Pos) -> &mut Option<Object> {
-            let (x, y) = pos.xy();
-            &mut self.0[x][y]
+            &mut self.0[pos.xy()]
         }
     }
 }
@@ -355,33 +390,470 @@ impl Puzzle for JumpIN {
         true
     }
 
-    fn next(&self) -> Vec<(Self::Move, Self)> {
-        let mut next = Vec::new();
-        // iterate through all positions
+    /// A gameboard is a dead end if it has exactly one rabbit, which is not
+    /// in a hole and can never jump.
+    ///
+    /// Mushrooms never move, and a fox only slides along its own row or
+    /// column.  So, if there is no other rabbit, no mushroom adjacent to the
+    /// rabbit, and no fox in the row or column of a position adjacent to the
+    /// rabbit (in a direction in which the rabbit could land on the
+    /// gameboard), then no obstacle can ever be adjacent to the rabbit, and
+    /// the rabbit can never move to a hole.
+    fn is_dead(&self) -> bool {
+        let mut rabbits =
+            Pos::values().filter(|&pos| self.get(pos).is_some_and(|obj| obj.is_rabbit()));
+        let pos = match (rabbits.next(), rabbits.next()) {
+            (Some(pos), None) if !pos.is_hole() => pos,
+            _ => return false,
+        };
+        Direction::values().all(|dir| {
+            let posf = match pos.step(dir) {
+                Some(posf) if posf.step(dir).is_some() => posf,
+                _ => return true,
+            };
+            let (xf, yf) = posf.xy();
+            *self.get(posf) != Some(Object::Mushroom)
+                && !Pos::values().any(|q| {
+                    let (x, y) = q.xy();
+                    (x == xf || y == yf) && self.get(q).is_some_and(|obj| obj.is_fox())
+                })
+        })
+    }
+
+    /// Dead-end gameboards (see `is_dead`) are pruned by every solver.
+    fn prune(&self) -> bool {
+        self.is_dead()
+    }
+
+    /// The number of rabbits that are not in a hole (see `JumpIN::estimate`).
+    fn lower_bound(&self) -> usize {
+        self.estimate()
+    }
+
+    /// The legal moves, in the same order as `successors`, found by checking
+    /// the game rules (see `rabbit_jump` and `fox_slide`) without making the
+    /// moves.
+    fn legal_moves(&self) -> Vec<Self::Move> {
+        let mut moves = vec![];
         for pos in Pos::values() {
-            // iterate through all directions
             for dir in Direction::values() {
-                // try to move a rabbit
+                if let Some((obj, _)) = self.rabbit_jump(pos, dir) {
+                    moves.push((obj, dir));
+                }
+                if let Some((head, _, _)) = self.fox_slide(pos, dir) {
+                    moves.push((head, dir));
+                }
+            }
+        }
+        moves
+    }
 
-                if let Some((obj, jumpin)) = self.move_rabbit(pos, dir) {
-                    // record the move and successor puzzle state
-                    // println!("obj {} jumpin {}", obj , jumpin);
-                    // println!("{}", jumpin);
-                    next.push(((obj, dir), jumpin))
+    /// Move the object `obj` (a rabbit or a fox head) in the direction `dir`.
+    ///
+    /// The move is made at whichever position of the object (for a fox, of
+    /// either its head or its tail) it is legal at.
+    fn apply_move(&self, (obj, dir): &Self::Move) -> Self {
+        let moved = Pos::values()
+            .filter(|&pos| (*self.get(pos)).is_some_and(|o| o == *obj || o.is_foxmatch(obj)))
+            .find_map(|pos| {
+                let (o, jumpin) = if obj.is_rabbit() {
+                    self.move_rabbit(pos, *dir)?
+                } else {
+                    self.move_fox(pos, *dir)?
+                };
+                (o == *obj).then_some(jumpin)
+            });
+        match moved {
+            Some(jumpin) => jumpin,
+            None => panic!("JumpIN::apply_move move ({}{}) should be legal", obj, dir),
+        }
+    }
+
+    fn next(&self) -> Vec<(Self::Move, Self)> {
+        self.successors().collect()
+    }
+
+    /// The gameboard with every rabbit replaced by a white rabbit.
+    ///
+    /// The game rules treat all rabbits alike, and the goal is for every rabbit
+    /// to be in a hole, so gameboards that differ only in the identities of the
+    /// rabbits need the same number of moves to reach a goal state.  (The
+    /// representative need not be a legal gameboard, as it is only used as a
+    /// hash-table key.)
+    fn canonicalize(&self) -> Self {
+        let mut jumpin = *self;
+        for pos in Pos::values() {
+            if let Some(obj) = jumpin.get_mut(pos) {
+                if obj.is_rabbit() {
+                    *obj = Object::WhiteRabbit;
                 }
-                // try to move a fox
-                if let Some((obj, jumpin)) = self.move_fox(pos, dir) {
-                    // record the move and successor puzzle state
-                    // println!("{}", jumpin);
-                    next.push(((obj, dir), jumpin))
+            }
+        }
+        jumpin
+    }
+
+    /// Rabbit jumps are tried before fox slides, as only rabbit jumps can move
+    /// a rabbit into a hole.
+    fn order_hint(&self, (obj, _): &Self::Move) -> i32 {
+        if obj.is_rabbit() {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// A rabbit jump costs `1` and a fox slide costs `2`.
+    fn move_cost(&self, (obj, _): &Self::Move) -> u32 {
+        if obj.is_rabbit() {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn successors(&self) -> impl Iterator<Item = (Self::Move, Self)> {
+        // iterate through all positions (in the order of `Pos::values`), all
+        // directions, and trying to move a rabbit and then a fox
+        let mut i = 0;
+        std::iter::from_fn(move || {
+            while i < 25 * 4 * 2 {
+                let (pos, dir, rabbit) = (
+                    Pos::new(i / 8 % 5, i / 40),
+                    Direction::VALUES[i / 2 % 4],
+                    i % 2 == 0,
+                );
+                i += 1;
+                let moved = if rabbit {
+                    self.move_rabbit(pos, dir)
+                } else {
+                    self.move_fox(pos, dir)
+                };
+                if let Some((obj, jumpin)) = moved {
+                    return Some(((obj, dir), jumpin));
                 }
             }
+            None
+        })
+    }
+}
+
+impl InvertiblePuzzle for JumpIN {
+    /// Every move can be undone by moving the same object in the reverse
+    /// direction: a rabbit jumps back over the same obstacles to the (now
+    /// empty) position that it jumped from, and a fox slides back by one
+    /// position.  Thus, the predecessors of a gameboard are exactly its
+    /// successors, with the directions of the moves reversed.
+    fn prev(&self) -> Vec<(Self::Move, Self)> {
+        self.next()
+            .into_iter()
+            .map(|((obj, dir), jumpin)| ((obj, dir.rev()), jumpin))
+            .collect()
+    }
+}
+
+impl MacroPuzzle for JumpIN {
+    /// A fox sliding by two or more positions in one direction.
+    fn macro_moves(&self) -> Vec<Vec<Self::Move>> {
+        let mut mss = vec![];
+        for (m, mut jumpin) in self.successors() {
+            if m.0.is_rabbit() {
+                continue;
+            }
+            let mut ms = vec![m];
+            while jumpin.legal_moves().contains(&m) {
+                jumpin = jumpin.apply_move(&m);
+                ms.push(m);
+                mss.push(ms.clone());
+            }
+        }
+        mss
+    }
+}
+
+impl Commuting for JumpIN {
+    /// Two moves commute if the rows or columns of positions between the
+    /// positions changed by each move (for a rabbit, the positions that it
+    /// jumps from, over, and to; for a fox, the positions of the fox before and
+    /// after sliding) are disjoint.
+    ///
+    /// Whether or not a move is legal, and the positions that it changes, only
+    /// depend on the objects at these positions, so each move is unaffected by
+    /// making the other move first.  A move `m2` that is not legal after `m1`
+    /// does not commute with `m1`.
+    fn commute(&self, m1: &Self::Move, m2: &Self::Move) -> bool {
+        let jumpin = self.apply_move(m1);
+        if !jumpin.legal_moves().contains(m2) {
+            return false;
+        }
+        let (lo1, hi1) = self.footprint(&jumpin);
+        let (lo2, hi2) = jumpin.footprint(&jumpin.apply_move(m2));
+        lo1.0 > hi2.0 || lo2.0 > hi1.0 || lo1.1 > hi2.1 || lo2.1 > hi1.1
+    }
+}
+
+impl Labeled for JumpIN {
+    type Label = String;
+
+    /// A description of the move, such as "brown rabbit jumps North over
+    /// mushroom, fox 1" or "fox 2 slides East".
+    fn label(&self, (obj, dir): &Self::Move) -> Self::Label {
+        if !obj.is_rabbit() {
+            return format!("{} slides {:?}", obj.name(), dir);
+        }
+        // the obstacles between the positions that the rabbit jumps from and to
+        let jumpin = self.apply_move(&(*obj, *dir));
+        let ((xl, yl), (xh, yh)) = self.footprint(&jumpin);
+        let mut over: Vec<&str> = Pos::values()
+            .filter(|pos| {
+                let (x, y) = pos.xy();
+                (xl..=xh).contains(&x) && (yl..=yh).contains(&y)
+            })
+            .filter_map(|pos| match self.get(pos) {
+                Some(o) if o != obj => Some(o.name()),
+                _ => None,
+            })
+            .collect();
+        // in the order that they are jumped over
+        if matches!(dir, Direction::North | Direction::West) {
+            over.reverse();
+        }
+        format!("{} jumps {:?} over {}", obj.name(), dir, over.join(", "))
+    }
+}
+
+impl Keyed for JumpIN {
+    type Key = u64;
+
+    /// A packing of the gameboard into 60 bits: a 25-bit mask of the
+    /// positions of the mushrooms, followed by the 5-bit position index (or
+    /// `31`, if absent) of each rabbit and fox component.
+    ///
+    /// Mushrooms are interchangeable and every other object occurs at most once
+    /// on a gameboard, so different gameboards have different keys.
+    fn key(&self) -> Self::Key {
+        let mut mushrooms = 0u64;
+        let mut slots = [31u64; 7];
+        for (k, pos) in Pos::values().enumerate() {
+            let slot = match self.get(pos) {
+                None => continue,
+                Some(Object::Mushroom) => {
+                    mushrooms |= 1 << k;
+                    continue;
+                }
+                Some(Object::WhiteRabbit) => 0,
+                Some(Object::BrownRabbit) => 1,
+                Some(Object::GreyRabbit) => 2,
+                Some(Object::Fox1Head) => 3,
+                Some(Object::Fox1Tail) => 4,
+                Some(Object::Fox2Head) => 5,
+                Some(Object::Fox2Tail) => 6,
+            };
+            slots[slot] = k as u64;
         }
-        next
+        slots.iter().fold(mushrooms, |key, slot| (key << 5) | slot)
+    }
+}
+
+/// The Zobrist keys of the objects at the positions of the gameboard, indexed
+/// by the position index (in the order of `Pos::values`) times the number of
+/// objects plus the object index (in the order of the `Object` variants).
+const ZOBRIST_KEYS: [u64; 25 * 8] = puzzle::zobrist_keys(0x4a75_6d70_494e);
+
+fn zobrist_key(pos: Pos, obj: Object) -> u64 {
+    let (x, y) = pos.xy();
+    ZOBRIST_KEYS[(y * 5 + x) * 8 + obj as usize]
+}
+
+impl ZobristHash for JumpIN {
+    fn zobrist(&self) -> u64 {
+        Pos::values()
+            .filter_map(|pos| Some(zobrist_key(pos, (*self.get(pos))?)))
+            .fold(0, |hash, key| hash ^ key)
+    }
+
+    /// A rabbit moves from its position to the position at which it lands, and
+    /// both components of a fox move one position in the direction `dir`; the
+    /// keys of only those objects are updated.
+    fn zobrist_after(&self, hash: u64, (obj, dir): &Self::Move) -> u64 {
+        let mut hash = hash;
+        for pos in Pos::values() {
+            let o = match self.get(pos) {
+                Some(o) if o == obj || o.is_foxmatch(obj) => *o,
+                _ => continue,
+            };
+            let posd = if o.is_rabbit() {
+                pos.step(*dir)
+                    .and_then(|posf| get_next_empty_pos_rabbit(self, posf, *dir))
+            } else {
+                pos.step(*dir)
+            };
+            match posd {
+                Some(posd) => hash ^= zobrist_key(pos, o) ^ zobrist_key(posd, o),
+                None => panic!(
+                    "JumpIN::zobrist_after move ({}{}) should be legal",
+                    obj, dir
+                ),
+            }
+        }
+        hash
+    }
+}
+
+impl PackState for JumpIN {
+    type Packed = u64;
+
+    /// The packing of `Keyed::key`, which determines the gameboard.
+    fn pack(&self) -> Self::Packed {
+        self.key()
+    }
+
+    fn unpack(packed: &Self::Packed) -> Self {
+        const SLOTS: [Object; 7] = [
+            Object::WhiteRabbit,
+            Object::BrownRabbit,
+            Object::GreyRabbit,
+            Object::Fox1Head,
+            Object::Fox1Tail,
+            Object::Fox2Head,
+            Object::Fox2Tail,
+        ];
+        let mut jumpin = JumpIN::new();
+        let mushrooms = packed >> (5 * SLOTS.len());
+        for (k, pos) in Pos::values().enumerate() {
+            if mushrooms & (1 << k) != 0 {
+                *jumpin.get_mut(pos) = Some(Object::Mushroom);
+            }
+        }
+        for (s, obj) in SLOTS.into_iter().enumerate() {
+            let k = (packed >> (5 * (SLOTS.len() - 1 - s))) & 31;
+            if k != 31 {
+                let k = k as usize;
+                *jumpin.get_mut(Pos::new(k % 5, k / 5)) = Some(obj);
+            }
+        }
+        jumpin
+    }
+}
+
+impl RankablePuzzle for JumpIN {
+    /// Each rabbit has 25 positions and each fox (whose orientation and row or
+    /// column never change) has 5 positions of its head along its row or
+    /// column.
+    fn max_rank(&self) -> usize {
+        25 * 25 * 25 * 5 * 5
+    }
+
+    /// The mixed-radix number of the position indices (in the order of
+    /// `Pos::values`) of the rabbits and the position of the head of each fox
+    /// along its row or column (or `0` for an absent object).
+    ///
+    /// The mushrooms never move, so gameboards reachable from one another
+    /// differ only in the positions of the rabbits and the foxes.
+    fn rank(&self) -> usize {
+        let find = |obj: Object| Pos::values().find(|&pos| *self.get(pos) == Some(obj));
+        let rabbits = [Object::WhiteRabbit, Object::BrownRabbit, Object::GreyRabbit]
+            .map(|obj| find(obj).map_or(0, |pos| pos.xy().1 * 5 + pos.xy().0));
+        let foxes = [
+            (Object::Fox1Head, Object::Fox1Tail),
+            (Object::Fox2Head, Object::Fox2Tail),
+        ]
+        .map(|(head, tail)| match (find(head), find(tail)) {
+            (Some(head), Some(tail)) => {
+                let ((xh, yh), (_, yt)) = (head.xy(), tail.xy());
+                if yh == yt {
+                    xh
+                } else {
+                    yh
+                }
+            }
+            _ => 0,
+        });
+        let rank = rabbits.into_iter().fold(0, |rank, r| rank * 25 + r);
+        foxes.into_iter().fold(rank, |rank, f| rank * 5 + f)
+    }
+}
+
+impl MutablePuzzle for JumpIN {
+    /// The gameboard is small enough that it is simply overwritten by the
+    /// successor gameboard (see `JumpIN::apply_move`).
+    fn apply(&mut self, m: &Self::Move) {
+        *self = self.apply_move(m)
+    }
+
+    /// Every move is undone by moving the same object in the reverse direction
+    /// (see `JumpIN::prev`).
+    fn undo(&mut self, (obj, dir): &Self::Move) {
+        self.apply(&(*obj, dir.rev()))
+    }
+}
+
+impl Heuristic for JumpIN {
+    const ADMISSIBLE: bool = true;
+    const CONSISTENT: bool = true;
+
+    /// The number of rabbits that are not in a hole.
+    ///
+    /// Each move moves at most one rabbit, and each rabbit that is not in a hole
+    /// must be moved at least once, so the estimate is admissible.  Moreover,
+    /// each move changes the estimate by at most one, so the estimate is also
+    /// consistent.
+    fn estimate(&self) -> usize {
+        Pos::values()
+            .filter(|&pos| matches!(self.get(pos), Some(obj) if obj.is_rabbit() && !pos.is_hole()))
+            .count()
+    }
+
+    /// The estimates of the gameboards `ps`, finding the positions that are not
+    /// holes only once for the whole batch.
+    fn estimate_batch(ps: &[Self]) -> Vec<usize> {
+        let open: Vec<Pos> = Pos::values().filter(|pos| !pos.is_hole()).collect();
+        ps.iter()
+            .map(|p| {
+                open.iter()
+                    .filter(|&&pos| matches!(p.get(pos), Some(obj) if obj.is_rabbit()))
+                    .count()
+            })
+            .collect()
+    }
+}
+
+impl Symmetric for JumpIN {
+    /// The eight gameboards obtained by the rotations and reflections of the
+    /// gameboard.
+    ///
+    /// The holes and raised positions are placed symmetrically on the
+    /// gameboard, and the game rules treat all directions alike, so each
+    /// rotation or reflection of a gameboard needs the same number of moves to
+    /// reach a goal state.
+    fn symmetries(&self) -> Vec<Self> {
+        (0..8)
+            .map(|i| {
+                let mut jumpin = JumpIN::new();
+                for pos in Pos::values() {
+                    *jumpin.get_mut(pos.symmetries()[i]) = *self.get(pos);
+                }
+                jumpin
+            })
+            .collect()
+    }
+
+    /// The least of the rotations and reflections of the gameboard.
+    fn canonical(&self) -> Self {
+        puzzle::least_symmetry(self)
     }
 }
 
 impl JumpIN {
+    /// Returns the least and greatest coordinates of the positions at which
+    /// the gameboard and the gameboard `jumpin` differ.
+    fn footprint(&self, jumpin: &JumpIN) -> ((usize, usize), (usize, usize)) {
+        Pos::values()
+            .filter(|&pos| self.get(pos) != jumpin.get(pos))
+            .map(|pos| pos.xy())
+            .fold(((4, 4), (0, 0)), |((xl, yl), (xh, yh)), (x, y)| {
+                ((xl.min(x), yl.min(y)), (xh.max(x), yh.max(y)))
+            })
+    }
     /// Attempt to move a rabbit at position `pos` in the direction `dir`;
     /// if successful, return the rabbit object that was moved and the new
     /// gameboard (`JumpIN` puzzle state).
@@ -390,12 +862,22 @@ impl JumpIN {
     /// that there is a rabbit at position `pos`, that the next position in the
     /// direction `dir` is an obstacle (a rabbit must jump over at least one
     /// obstacle), and find the position `posd` (destination) of the first empty
-    /// space in the direction `dir`.  If these can be satisfied, then it should
-    /// copy the current gameboard into a new gameboard, update `pos` in the new
-    /// gameboard to `None` and update `posd` in the new gameboard to `Some`.
+    /// space in the direction `dir` (see `rabbit_jump`).  If these can be
+    /// satisfied, then it should copy the current gameboard into a new
+    /// gameboard, update `pos` in the new gameboard to `None` and update `posd`
+    /// in the new gameboard to `Some`.
     fn move_rabbit(&self, pos: Pos, dir: Direction) -> Option<(Object, Self)> {
-        // Your code here
+        let (obj_p, posd) = self.rabbit_jump(pos, dir)?;
         let self_copy = &mut self.clone();
+        *self_copy.get_mut(posd) = Some(obj_p);
+        *self_copy.get_mut(pos) = None;
+        Some((obj_p, *self_copy))
+    }
+
+    /// The rabbit at position `pos` and the position `posd` (destination) that
+    /// it would jump to in the direction `dir`, if that is a legal move (see
+    /// `move_rabbit`), without making the move.
+    fn rabbit_jump(&self, pos: Pos, dir: Direction) -> Option<(Object, Pos)> {
         let obj_p = match self.get(pos) {
             Some(o) => o,
             None => return None,
@@ -418,18 +900,12 @@ impl JumpIN {
             },
             None => return None,
         };
-        let mut p = posf;
         // step untill rabbit finds a valid position
-        p = match get_next_empty_pos_rabbit(self, p, dir) {
-            Some(p) => p,
-            None => return None,
-        };
+        let p = get_next_empty_pos_rabbit(self, posf, dir)?;
         if p == posf {
             return None;
         }
-        *self_copy.get_mut(p) = Some(*obj_p);
-        *self_copy.get_mut(pos) = None;
-        return Some((*obj_p, *self_copy));
+        Some((*obj_p, p))
     }
 
     /// Attempt to move a fox at position `pos` in the direction `dir`;
@@ -449,7 +925,19 @@ impl JumpIN {
     /// not necessarily the object that is at position `pos` in the current
     /// gameboard.
     fn move_fox(&self, pos: Pos, dir: Direction) -> Option<(Object, Self)> {
+        let (head, posb, posf) = self.fox_slide(pos, dir)?;
         let self_copy = &mut self.clone();
+        *self_copy.get_mut(posf) = *self.get(pos);
+        *self_copy.get_mut(pos) = *self.get(posb);
+        *self_copy.get_mut(posb) = None;
+
+        Some((head, *self_copy))
+    }
+
+    /// The fox head object of the fox at (the forward position) `pos` and the
+    /// positions `posb` and `posf` (see `move_fox`), if sliding the fox in the
+    /// direction `dir` is a legal move, without making the move.
+    fn fox_slide(&self, pos: Pos, dir: Direction) -> Option<(Object, Pos, Pos)> {
         let current = match self.get(pos) {
             Some(o) => o,
             None => return None,
@@ -459,10 +947,7 @@ impl JumpIN {
             return None;
         }
 
-        let posb = match pos.step(dir.rev()) {
-            Some(p) => p,
-            None => return None,
-        };
+        let posb = pos.step(dir.rev())?;
 
         let back = match self.get(posb) {
             Some(o) => o,
@@ -473,25 +958,15 @@ impl JumpIN {
             Some((h1, h2)) => (h1, h2),
             None => return None,
         };
-        let posf = match pos.step(dir) {
-            Some(p) => p,
-            None => return None,
-        };
+        let posf = pos.step(dir)?;
 
-        match self.get(posf) {
-            Some(o) => {
-                if o.checking_for_obst() {
-                    return None;
-                }
+        if let Some(o) = self.get(posf) {
+            if o.checking_for_obst() {
+                return None;
             }
-            None => {}
         };
 
-        *self_copy.get_mut(posf) = Some(*current);
-        *self_copy.get_mut(pos) = Some(*back);
-        *self_copy.get_mut(posb) = None;
-
-        Some((head, *self_copy))
+        Some((head, posb, posf))
     }
 }
 fn get_next_empty_pos_rabbit(board: &JumpIN, mut p: Pos, dir: Direction) -> Option<Pos> {
@@ -519,14 +994,14 @@ fn get_next_empty_pos_rabbit(board: &JumpIN, mut p: Pos, dir: Direction) -> Opti
 fn match_fox_head(current_obj: &Object, obj_posb: &Object) -> Option<(Object, Object)> {
     match (current_obj, obj_posb) {
         (Object::Fox1Tail, Object::Fox1Head) | (Object::Fox2Tail, Object::Fox2Head) => {
-            return Some((*obj_posb, *current_obj));
+            Some((*obj_posb, *current_obj))
         }
         (Object::Fox1Head, Object::Fox1Tail) | (Object::Fox2Head, Object::Fox2Tail) => {
-            return Some((*current_obj, *obj_posb));
+            Some((*current_obj, *obj_posb))
         }
 
-        _ => return None,
-    };
+        _ => None,
+    }
 }
 
 #[cfg(test)]
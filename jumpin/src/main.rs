@@ -1,9 +1,9 @@
+//! Solves https://www.smartgames.eu/uk/one-player-games/jumpin[JumpIN'] puzzles.
+
 use std::env;
 use std::fs;
 use std::process;
 
-/// Solves https://www.smartgames.eu/uk/one-player-games/jumpin[JumpIN'] puzzles.
-
 fn main() {
     let args: Vec<String> = env::args().collect();
     let file: &str = match &args[..] {
@@ -1,11 +1,28 @@
+use std::alloc::System;
+use std::collections::hash_map::{DefaultHasher, RandomState};
+use std::collections::HashSet;
 use std::fs;
+use std::hash::{BuildHasher, BuildHasherDefault};
+use std::path::Path;
+use std::time::Duration;
 
-use super::{JumpIN, JumpINParseError};
+use super::{JumpIN, JumpINParseError, Object, Pos};
 use puzzle::{
     test::{MoveTree, MoveTreeVerifyError},
-    Puzzle,
+    BfsCheckpoint, BloomSet, BoxedPuzzle, ByteArray, CancelToken, CheckError, ChunkedQueue,
+    Commuting, CountingAllocator, DiskSet, DotOptions, Events, Heuristic, Labeled, LayerStats,
+    Macro, MemoryLimit, MemoryLimitExceeded, NondeterministicPuzzle, Observer, PackState, Product,
+    Puzzle, RankSet, RankablePuzzle, SearchEvent, SearchStats, SharedGrid, SolveError,
+    SolveOutcome, Solver, SolverConfig, Strategy, Symmetric, TimedOut, WithLabels, ZobristHash,
 };
 
+// count the allocations of the tests, for `MemoryStats::peak_bytes`
+#[global_allocator]
+static ALLOC: CountingAllocator<System> = CountingAllocator::new(System);
+
+/// The result of solving a `JumpIN` puzzle, such as by `puzzle::solve`.
+type Solution = Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>;
+
 fn moves_to_string(ms: &[<JumpIN as Puzzle>::Move]) -> String {
     let mut s = String::new();
     let mut last_obj = None;
@@ -74,6 +91,71 @@ fn check_test(
     Ok(())
 }
 
+fn check_steps_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    (mvs_soln, goal_soln): (Vec<<JumpIN as Puzzle>::Move>, JumpIN),
+) -> Result<(), String> {
+    let mvs = moves_to_string(&mvs_soln);
+    let states = match puzzle::check_steps(puzzle, &mvs_soln) {
+        Ok(states) => states,
+        Err(err) => {
+            return Err(format!(
+                "{file_stem} reference solution ({mvs}) failed `puzzle::check_steps` ({err:?})",
+                file_stem = file_stem,
+                mvs = mvs,
+                err = err
+            ))
+        }
+    };
+    if states.len() != mvs_soln.len() + 1
+        || states.first() != Some(&puzzle)
+        || states.last() != Some(&goal_soln)
+    {
+        return Err(format!(
+            "{file_stem} reference solution ({mvs}) `puzzle::check_steps` states do not start at the puzzle and end at the goal.",
+            file_stem = file_stem,
+            mvs = mvs
+        ));
+    }
+    for (i, m) in mvs_soln.iter().enumerate() {
+        if states[i].apply_move(m) != states[i + 1] {
+            return Err(format!(
+                "{file_stem} reference solution ({mvs}) `puzzle::check_steps` state {i} is not reached by move {i}.",
+                file_stem = file_stem,
+                mvs = mvs,
+                i = i
+            ));
+        }
+    }
+    if let Some((_, mvs_prefix)) = mvs_soln.split_last() {
+        let last = states[mvs_prefix.len()];
+        if puzzle::check_steps(puzzle, mvs_prefix) != Err(CheckError::NotGoal(last)) {
+            return Err(format!(
+                "{file_stem} reference solution ({mvs}) without its last move should not reach a goal.",
+                file_stem = file_stem,
+                mvs = mvs
+            ));
+        }
+    }
+    let legal = puzzle.legal_moves();
+    if let Some(m) = states
+        .iter()
+        .flat_map(|p| p.legal_moves())
+        .find(|m| !legal.contains(m))
+    {
+        if puzzle::check_steps(puzzle, &[m]) != Err(CheckError::IllegalMove { step: 0, p: puzzle })
+        {
+            return Err(format!(
+                "{file_stem} `puzzle::check_steps` should reject the illegal move {m:?}.",
+                file_stem = file_stem,
+                m = m
+            ));
+        }
+    }
+    Ok(())
+}
+
 fn solve_test(
     file_stem: &str,
     puzzle: JumpIN,
@@ -140,1180 +222,11195 @@ fn solve_test(
     }
 }
 
-fn moves_test(
+fn optimal_solve_test(
     file_stem: &str,
-    puzzle: &JumpIN,
-    move_tree: &MoveTree<<JumpIN as Puzzle>::Move, JumpIN>,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+    solver: &str,
+    solve: impl FnOnce(JumpIN) -> Solution,
 ) -> Result<(), String> {
-    match move_tree.verify::<_, _, ()>(
-        puzzle,
-        |q, q_soln| if q == q_soln { Ok(()) } else { Err(()) },
-    ) {
-        Ok(()) => Ok(()),
-        Err((ms, err)) => {
-            let state = if ms.is_empty() {
-                file_stem.to_string()
+    match (solve(puzzle), soln) {
+        (None, None) => Ok(()),
+        (Some((mvs, _)), None) => Err(format!(
+            "{file_stem} has {solver} solution ({mvs}), but reference has no solution; likely has an invalid move and/or an incorrect `JumpIN::is_goal`.",
+            file_stem = file_stem,
+            solver = solver,
+            mvs = moves_to_string(&mvs)
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no {solver} solution, but reference has solution ({mvs}).",
+            file_stem = file_stem,
+            solver = solver,
+            mvs = moves_to_string(&mvs_soln)
+        )),
+        (Some((mvs, goal)), Some((mvs_soln, _))) => {
+            match puzzle::check(puzzle, &mvs) {
+                None => {
+                    return Err(format!(
+                        "{file_stem} {solver} solution ({mvs}) failed `puzzle::check`.",
+                        file_stem = file_stem,
+                        solver = solver,
+                        mvs = moves_to_string(&mvs)
+                    ))
+                }
+                Some(goal_chk) => {
+                    if goal != goal_chk {
+                        return Err(format!(
+                            "{file_stem} {solver} solution ({mvs}) final gameboard does not equal `puzzle::check` final gameboard.",
+                            file_stem = file_stem,
+                            solver = solver,
+                            mvs = moves_to_string(&mvs)
+                        ));
+                    }
+                }
+            };
+            if mvs.len() == mvs_soln.len() {
+                Ok(())
             } else {
-                format!(
-                    "{file_stem} with moves {mvs}",
+                Err(format!(
+                    "{file_stem} {solver} solution ({mvs}) does not have the same length as reference solution ({mvs_soln}); likely has an inadmissible `JumpIN::estimate`.",
                     file_stem = file_stem,
-                    mvs = moves_to_string(&ms)
-                )
-            };
-            match err {
-                MoveTreeVerifyError::MissingMove(m) => Err(format!(
-                    "{state}, `JumpIN::next` is missing move {m}",
-                    state = state,
-                    m = moves_to_string(&[m])
-                )),
-                MoveTreeVerifyError::DuplicateMove(m) => Err(format!(
-                    "{state}, `JumpIN::next` has duplicate move {m}",
-                    state = state,
-                    m = moves_to_string(&[m])
-                )),
-                MoveTreeVerifyError::ExtraMove(m) => Err(format!(
-                    "{state}, `JumpIN::next` has invalid move {m}",
-                    state = state,
-                    m = moves_to_string(&[m])
-                )),
-                MoveTreeVerifyError::ChkState(m, _) => Err(format!(
-                    "{state}, `JumpIN::next` includes move {m} leading to an incorrect state",
-                    state = state,
-                    m = moves_to_string(&[m])
-                )),
+                    solver = solver,
+                    mvs = moves_to_string(&mvs),
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ))
             }
         }
     }
 }
 
-mod badinput11 {
-    use super::*;
-
-    #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput11", parse_res())
-    }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+fn seeded_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+) -> Result<(), String> {
+    for seed in 0..4 {
+        let solver = format!("seeded BFS (seed {seed})", seed = seed);
+        let seeded = puzzle::solve_seeded(puzzle, seed);
+        optimal_solve_test(file_stem, puzzle, soln.clone(), &solver, |_| seeded.clone())?;
+        if puzzle::solve_seeded(puzzle, seed) != seeded {
+            return Err(format!(
+                "{file_stem} {solver} solution is not reproducible.",
+                file_stem = file_stem,
+                solver = solver
+            ));
+        }
     }
+    Ok(())
 }
-mod badinput13 {
-    use super::*;
 
-    #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput13", parse_res())
+fn solve_to_any_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+) -> Result<(), String> {
+    if puzzle::solve_to_any(puzzle, &HashSet::new()).is_some() {
+        return Err(format!(
+            "{file_stem} has solution to an empty set of targets.",
+            file_stem = file_stem
+        ));
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    match puzzle::solve_to_any(puzzle, &HashSet::from([puzzle])) {
+        Some((mvs, goal)) if mvs.is_empty() && goal == puzzle => {}
+        _ => {
+            return Err(format!(
+                "{file_stem} has no empty solution to the initial gameboard.",
+                file_stem = file_stem
+            ))
+        }
     }
+    // any (reachable) goal gameboard at the fewest number of moves
+    let targets = match puzzle::solve(puzzle) {
+        Some((_, goal)) => HashSet::from([goal]),
+        None => HashSet::new(),
+    };
+    optimal_solve_test(file_stem, puzzle, soln, "targeted BFS", |p| {
+        puzzle::solve_to_any(p, &targets).filter(|(_, goal)| targets.contains(goal))
+    })
 }
-mod wizard50 {
-    use super::*;
 
-    #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("wizard50", Ok(puzzle()))
+fn solve_until_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+) -> Result<(), String> {
+    optimal_solve_test(file_stem, puzzle, soln.clone(), "BFS until goal", |p| {
+        puzzle::solve_until(p, JumpIN::is_goal)
+    })?;
+    // some (rather than every) rabbit in a hole
+    let some_rabbit_home = |p: &JumpIN| {
+        Pos::values().any(|pos| pos.is_hole() && p.get(pos).is_some_and(|obj| obj.is_rabbit()))
+    };
+    match (puzzle::solve_until(puzzle, some_rabbit_home), soln) {
+        (None, None) => Ok(()),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no solution with some rabbit in a hole, but reference has solution ({mvs}).",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs_soln)
+        )),
+        (Some((mvs, goal)), soln) => {
+            let mut p = puzzle;
+            for mv in mvs.iter() {
+                match p.next().into_iter().find(|(m, _)| m == mv) {
+                    Some((_, q)) => p = q,
+                    None => {
+                        return Err(format!(
+                            "{file_stem} solution with some rabbit in a hole ({mvs}) has an invalid move.",
+                            file_stem = file_stem,
+                            mvs = moves_to_string(&mvs)
+                        ))
+                    }
+                }
+            }
+            if p != goal || !some_rabbit_home(&goal) {
+                Err(format!(
+                    "{file_stem} solution with some rabbit in a hole ({mvs}) does not reach a gameboard with some rabbit in a hole.",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs)
+                ))
+            } else if soln.is_some_and(|(mvs_soln, _)| mvs.len() > mvs_soln.len()) {
+                Err(format!(
+                    "{file_stem} solution with some rabbit in a hole ({mvs}) is longer than reference solution.",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs)
+                ))
+            } else {
+                Ok(())
+            }
+        }
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/wizard50.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+}
+
+fn meet_in_the_middle_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    (mvs_soln, goal): (Vec<<JumpIN as Puzzle>::Move>, JumpIN),
+) -> Result<(), String> {
+    let depth = mvs_soln.len();
+    optimal_solve_test(
+        file_stem,
+        puzzle,
+        Some((mvs_soln.clone(), goal)),
+        "meet-in-the-middle",
+        |p| puzzle::solve_meet_in_the_middle(p, goal, depth),
+    )?;
+    match depth.checked_sub(1) {
+        Some(depth) => match puzzle::solve_meet_in_the_middle(puzzle, goal, depth) {
+            None => Ok(()),
+            Some((mvs, _)) => Err(format!(
+                "{file_stem} has meet-in-the-middle solution ({mvs}) that is shorter than reference solution ({mvs_soln}).",
+                file_stem = file_stem,
+                mvs = moves_to_string(&mvs),
+                mvs_soln = moves_to_string(&mvs_soln)
+            )),
+        },
+        None => Ok(()),
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/wizard50.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+}
+
+/// An abstraction of a gameboard that forgets the identities of the rabbits
+/// (which are interchangeable with respect to the moves and the goal).
+fn indistinct_rabbits(p: &JumpIN) -> JumpIN {
+    let mut p = *p;
+    for pos in Pos::values() {
+        if let Some(obj) = p.get_mut(pos) {
+            if obj.is_rabbit() {
+                *obj = Object::WhiteRabbit;
+            }
+        }
     }
-    #[test]
-    fn check() -> Result<(), String> {
-        check_test("wizard50", puzzle(), soln().unwrap())
+    p
+}
+
+fn pattern_db_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+) -> Result<(), String> {
+    let db = puzzle::PatternDatabase::new(indistinct_rabbits, [puzzle]);
+    // the abstraction is exact
+    let len_soln = soln.as_ref().map(|(mvs_soln, _)| mvs_soln.len());
+    if db.lookup(&puzzle) != len_soln {
+        return Err(format!(
+            "{file_stem} pattern database lookup ({lookup:?}) does not equal reference solution length ({len_soln:?}).",
+            file_stem = file_stem,
+            lookup = db.lookup(&puzzle),
+            len_soln = len_soln
+        ));
+    }
+    // the guided puzzle state is the same puzzle (e.g., with the same
+    // representatives and dead ends), so its BFS is the same search
+    let (_, guided) = puzzle::solve_with_stats(db.guide(puzzle), &SolverConfig::new());
+    let (_, expected) = puzzle::solve_with_stats(puzzle, &SolverConfig::new());
+    if (guided.expanded, guided.visited) != (expected.expanded, expected.visited) {
+        return Err(format!(
+            "{file_stem} guided BFS stats ({guided:?}) differ from BFS stats ({expected:?}).",
+            file_stem = file_stem,
+            guided = guided,
+            expected = expected
+        ));
     }
-    #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("wizard50", puzzle(), soln())
+    optimal_solve_test(file_stem, puzzle, soln, "pattern database A*", |p| {
+        puzzle::solve_astar(db.guide(p)).map(|(mvs, goal)| (mvs, goal.p))
+    })
+}
+
+fn analyze_backwards_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    (mvs_soln, goal): (Vec<<JumpIN as Puzzle>::Move>, JumpIN),
+) -> Result<(), String> {
+    let hash_map = puzzle::analyze_backwards([goal]);
+    if hash_map.get(&puzzle) != Some(&mvs_soln.len()) {
+        return Err(format!(
+            "{file_stem} retrograde distance ({dist:?}) does not equal reference solution ({mvs_soln}) length.",
+            file_stem = file_stem,
+            dist = hash_map.get(&puzzle),
+            mvs_soln = moves_to_string(&mvs_soln)
+        ));
+    }
+    for (p, d) in hash_map.iter() {
+        let consistent = match d.checked_sub(1) {
+            None => *p == goal,
+            Some(d) => p.next().iter().any(|(_, q)| hash_map.get(q) == Some(&d)),
+        };
+        if !consistent {
+            return Err(format!(
+                "{file_stem} retrograde distance ({d}) of gameboard\n{p}is not one more than that of any successor.",
+                file_stem = file_stem,
+                d = d,
+                p = p
+            ));
+        }
     }
-    #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("wizard50", &puzzle(), &move_tree())
+    Ok(())
+}
+
+fn is_dead_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    (mvs_soln, _): (Vec<<JumpIN as Puzzle>::Move>, JumpIN),
+) -> Result<(), String> {
+    // the gameboards along the reference solution
+    let mut ps = vec![puzzle];
+    for mv in mvs_soln.iter() {
+        match ps.last().unwrap().next().into_iter().find(|(m, _)| m == mv) {
+            Some((_, q)) => ps.push(q),
+            None => break,
+        }
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 62905
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/wizard50.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    for p in ps {
+        if p.is_dead() {
+            return Err(format!(
+                "{file_stem} gameboard\n{p}along reference solution ({mvs_soln}) is a dead end.",
+                file_stem = file_stem,
+                p = p,
+                mvs_soln = moves_to_string(&mvs_soln)
+            ));
+        }
     }
+    Ok(())
 }
-mod starter12 {
-    use super::*;
 
-    #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("starter12", Ok(puzzle()))
-    }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/starter12.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+fn symmetries_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let canonical = puzzle.canonical();
+    for p in puzzle.symmetries() {
+        if p.canonical() != canonical {
+            return Err(format!(
+                "{file_stem} gameboard\n{p}has canonical form\n{pc}but symmetric gameboard\n{puzzle}has canonical form\n{canonical}",
+                file_stem = file_stem,
+                p = p,
+                pc = p.canonical(),
+                puzzle = puzzle,
+                canonical = canonical
+            ));
+        }
+        let n = puzzle::solve(p).map(|(mvs, _)| mvs.len());
+        if n != soln.as_ref().map(|(mvs, _)| mvs.len()) {
+            return Err(format!(
+                "{file_stem} gameboard\n{p}has a different number of moves ({n:?}) to a goal state than the reference solution.",
+                file_stem = file_stem,
+                p = p,
+                n = n
+            ));
+        }
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/starter12.soln.btc.z").unwrap(),
+    Ok(())
+}
+
+fn persist_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let file = |ext: &str| {
+        fs::File::open(format!(
+            "./assets/btc/{file_stem}.{ext}.btc.z",
+            file_stem = file_stem,
+            ext = ext
         ))
         .unwrap()
+    };
+    let p: JumpIN = puzzle::read_puzzle(file("puz")).map_err(|err| err.to_string())?;
+    let s: Solution = puzzle::read_solution(file("soln")).map_err(|err| err.to_string())?;
+    if p != puzzle || s != soln {
+        return Err(format!(
+            "{file_stem} puzzle or reference solution read by puzzle::read_puzzle or puzzle::read_solution differs.",
+            file_stem = file_stem
+        ));
+    }
+    let mut buf = vec![];
+    puzzle::write_puzzle(&mut buf, &puzzle).map_err(|err| err.to_string())?;
+    let p: JumpIN = puzzle::read_puzzle(&buf[..]).map_err(|err| err.to_string())?;
+    let mut buf = vec![];
+    puzzle::write_solution(&mut buf, &soln).map_err(|err| err.to_string())?;
+    let s: Solution = puzzle::read_solution(&buf[..]).map_err(|err| err.to_string())?;
+    if p != puzzle || s != soln {
+        return Err(format!(
+            "{file_stem} puzzle or reference solution differs after a round trip through puzzle::write_puzzle or puzzle::write_solution.",
+            file_stem = file_stem
+        ));
     }
-    #[test]
-    fn check() -> Result<(), String> {
-        check_test("starter12", puzzle(), soln().unwrap())
+    Ok(())
+}
+
+fn macro_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    match (puzzle::solve(Macro(puzzle)), soln) {
+        (None, None) => Ok(()),
+        (Some((mss, goal)), Some((mvs_soln, _))) => {
+            let steps = mss.len();
+            let mvs = Macro::<JumpIN>::expand(mss);
+            if steps > mvs_soln.len() {
+                return Err(format!(
+                    "{file_stem} macro solution ({mvs}) has more steps ({steps}) than reference solution ({mvs_soln}) has moves.",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs),
+                    steps = steps,
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ));
+            }
+            match puzzle::check(puzzle, &mvs) {
+                Some(goal_chk) if goal_chk == goal.0 => Ok(()),
+                _ => Err(format!(
+                    "{file_stem} expanded macro solution ({mvs}) failed `puzzle::check`",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs)
+                )),
+            }
+        }
+        (Some((mss, _)), None) => Err(format!(
+            "{file_stem} has macro solution ({mvs}), but reference has no solution.",
+            file_stem = file_stem,
+            mvs = moves_to_string(&Macro::<JumpIN>::expand(mss))
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no macro solution, but reference has solution ({mvs_soln}).",
+            file_stem = file_stem,
+            mvs_soln = moves_to_string(&mvs_soln)
+        )),
+    }
+}
+
+fn product_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let product = Product(puzzle, puzzle);
+    match (puzzle::solve_astar(product), soln) {
+        (None, None) => Ok(()),
+        (Some((mvs, goal)), Some((mvs_soln, _))) => {
+            if mvs.len() != 2 * mvs_soln.len() {
+                Err(format!(
+                    "{file_stem} product solution length ({len}) does not equal twice the reference solution ({mvs_soln}) length.",
+                    file_stem = file_stem,
+                    len = mvs.len(),
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ))
+            } else if puzzle::check(product, &mvs) != Some(goal) {
+                Err(format!(
+                    "{file_stem} product solution failed `puzzle::check`",
+                    file_stem = file_stem
+                ))
+            } else {
+                Ok(())
+            }
+        }
+        (Some(_), None) => Err(format!(
+            "{file_stem} has a product solution, but reference has no solution.",
+            file_stem = file_stem
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no product solution, but reference has solution ({mvs_soln}).",
+            file_stem = file_stem,
+            mvs_soln = moves_to_string(&mvs_soln)
+        )),
+    }
+}
+
+fn labeled_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let labeled = puzzle::solve(WithLabels(puzzle)).map(|(lmvs, goal)| {
+        let labels: Vec<String> = lmvs.iter().map(|lmv| lmv.label.clone()).collect();
+        let mvs: Vec<<JumpIN as Puzzle>::Move> = lmvs.into_iter().map(|lmv| lmv.m).collect();
+        (labels, (mvs, goal.0))
+    });
+    let (labels, soln_labeled) = match labeled {
+        Some((labels, soln)) => (labels, Some(soln)),
+        None => (vec![], None),
+    };
+    optimal_solve_test(file_stem, puzzle, soln, "labeled BFS", |_| {
+        soln_labeled.clone()
+    })?;
+    // the labels are those of the moves along the solution
+    let mut p = puzzle;
+    for (mv, label) in soln_labeled.iter().flat_map(|(mvs, _)| mvs).zip(labels) {
+        if label != p.label(mv) || !label.starts_with(mv.0.name()) {
+            return Err(format!(
+                "{file_stem} labeled solution has label \"{label}\" for move {obj}{dir}.",
+                file_stem = file_stem,
+                label = label,
+                obj = mv.0,
+                dir = mv.1
+            ));
+        }
+        p = p.apply_move(mv);
+    }
+    Ok(())
+}
+
+fn injected_rng_test(file_stem: &str, puzzle: JumpIN) -> Result<(), String> {
+    use rand::{rngs::StdRng, SeedableRng};
+    for seed in 0..2 {
+        let rng = || StdRng::seed_from_u64(seed);
+        let differs = if puzzle::solve_seeded_with_rng(puzzle, &mut rng())
+            != puzzle::solve_seeded(puzzle, seed)
+        {
+            Some("seeded BFS")
+        } else if puzzle::estimate_tree_size_with_rng(&puzzle, 4, 100, &mut rng())
+            != puzzle::estimate_tree_size(&puzzle, 4, 100, seed)
+        {
+            Some("tree size estimate")
+        } else if puzzle::solve_mcts_with_rng(puzzle, 200, 20, 1.4, &mut rng())
+            != puzzle::solve_mcts(puzzle, 200, 20, 1.4, seed)
+        {
+            Some("MCTS")
+        } else {
+            None
+        };
+        if let Some(solver) = differs {
+            return Err(format!(
+                "{file_stem} {solver} with an injected random number generator (seed {seed}) differs from {solver} with the seed.",
+                file_stem = file_stem,
+                solver = solver,
+                seed = seed
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn estimate_tree_size_test(file_stem: &str, puzzle: JumpIN) -> Result<(), String> {
+    // the exact number of nodes of the search tree of each depth, to depth 4
+    fn count(p: &JumpIN, depth: usize, layers: &mut [usize]) {
+        layers[depth] += 1;
+        if depth + 1 < layers.len() && !p.is_goal() && !p.prune() {
+            for (_, q) in p.next() {
+                count(&q, depth + 1, layers);
+            }
+        }
+    }
+    let mut layers = [0; 5];
+    count(&puzzle, 0, &mut layers);
+    let estimate = puzzle::estimate_tree_size(&puzzle, 4, 2000, 0);
+    if estimate != puzzle::estimate_tree_size(&puzzle, 4, 2000, 0) {
+        return Err(format!(
+            "{file_stem} tree size estimates with the same seed differ.",
+            file_stem = file_stem
+        ));
+    }
+    // the first two layers are estimated exactly, and the others within 50%
+    let ok = estimate.layers.len() == layers.len()
+        && estimate.layers[..2] == [layers[0] as f64, layers[1] as f64]
+        && estimate
+            .layers
+            .iter()
+            .zip(layers)
+            .all(|(e, n)| (e - n as f64).abs() <= 0.5 * n as f64);
+    if !ok {
+        return Err(format!(
+            "{file_stem} tree size estimate ({estimate:?}) is far from the exact tree size ({layers:?}).",
+            file_stem = file_stem,
+            estimate = estimate.layers,
+            layers = layers
+        ));
+    }
+    Ok(())
+}
+
+fn solve_k_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+    k: usize,
+) -> Result<(), String> {
+    let solns = puzzle::solve_k(puzzle, k);
+    let (mvs_soln, _) = match soln {
+        None if solns.is_empty() => return Ok(()),
+        None => {
+            return Err(format!(
+                "{file_stem} has k-shortest solutions, but reference has no solution; likely has an invalid move and/or an incorrect `JumpIN::is_goal`.",
+                file_stem = file_stem
+            ))
+        }
+        Some(soln) => soln,
+    };
+    for (i, (mvs, goal)) in solns.iter().enumerate() {
+        if puzzle::check(puzzle, mvs) != Some(*goal) {
+            return Err(format!(
+                "{file_stem} k-shortest solution ({mvs}) failed `puzzle::check`.",
+                file_stem = file_stem,
+                mvs = moves_to_string(mvs)
+            ));
+        }
+        if mvs.len() < mvs_soln.len() || (i > 0 && mvs.len() < solns[i - 1].0.len()) {
+            return Err(format!(
+                "{file_stem} k-shortest solution ({mvs}) is out of order.",
+                file_stem = file_stem,
+                mvs = moves_to_string(mvs)
+            ));
+        }
+        if solns[..i].iter().any(|(mvs_prev, _)| mvs_prev == mvs) {
+            return Err(format!(
+                "{file_stem} has duplicate k-shortest solution ({mvs}).",
+                file_stem = file_stem,
+                mvs = moves_to_string(mvs)
+            ));
+        }
+    }
+    // every shortest solution is among the k shortest solutions, unless there
+    // are more than k of them
+    let optimal = solns
+        .iter()
+        .filter(|(mvs, _)| mvs.len() == mvs_soln.len())
+        .count();
+    let count = puzzle::count_optimal_solutions(puzzle).map_or(0, |(_, count)| count);
+    if optimal != count.min(k) {
+        return Err(format!(
+            "{file_stem} has {optimal} shortest k-shortest solutions, but should have {expected}.",
+            file_stem = file_stem,
+            optimal = optimal,
+            expected = count.min(k)
+        ));
+    }
+    Ok(())
+}
+
+fn astar_certified_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+) -> Result<(), String> {
+    let certified = puzzle::solve_astar_certified(puzzle);
+    if certified.as_ref().is_some_and(|(_, _, optimal)| !optimal) {
+        return Err(format!(
+            "{file_stem} certified A* solution is not reported as optimal; likely has an incorrect `JumpIN::ADMISSIBLE`.",
+            file_stem = file_stem
+        ));
+    }
+    optimal_solve_test(file_stem, puzzle, soln, "certified A*", |_| {
+        certified.map(|(mvs, goal, _)| (mvs, goal))
+    })
+}
+
+fn apply_move_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    (mvs_soln, _): (Vec<<JumpIN as Puzzle>::Move>, JumpIN),
+) -> Result<(), String> {
+    // check the gameboards along the reference solution
+    let mut p = puzzle;
+    for mv in mvs_soln.iter() {
+        let next = p
+            .legal_moves()
+            .into_iter()
+            .map(|m| {
+                let q = p.apply_move(&m);
+                (m, q)
+            })
+            .collect::<Vec<_>>();
+        if next != p.next() {
+            return Err(format!(
+                "{file_stem} gameboard\n{p}has legal moves ({mvs}) that do not apply to its successors.",
+                file_stem = file_stem,
+                p = p,
+                mvs = moves_to_string(&p.legal_moves())
+            ));
+        }
+        if !p.legal_moves().contains(mv) {
+            break;
+        }
+        p = p.apply_move(mv);
+    }
+    Ok(())
+}
+
+/// Solve a puzzle behind a `BoxedPuzzle` (using `puzzle::solve`), and downcast
+/// the solution.
+fn boxed_solve(puzzle: JumpIN) -> Solution {
+    let (mvs, p) = puzzle::solve(BoxedPuzzle::new(puzzle))?;
+    let mvs = mvs
+        .iter()
+        .map(|mv| *mv.downcast_ref::<<JumpIN as Puzzle>::Move>().unwrap())
+        .collect();
+    Some((mvs, *p.downcast_ref::<JumpIN>().unwrap()))
+}
+
+/// A minimal FxHash-style hasher (as in `rustc`), standing in for a fast,
+/// non-cryptographic hasher.
+#[derive(Default)]
+struct FxHasher(u64);
+
+impl std::hash::Hasher for FxHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0.rotate_left(5) ^ u64::from(b)).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+        }
+    }
+}
+
+fn solve_with_hasher_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let hash_builder = std::hash::BuildHasherDefault::<FxHasher>::default();
+    match (puzzle::solve_with_hasher(puzzle, hash_builder), soln) {
+        (None, None) => Ok(()),
+        (Some((mvs, goal)), Some((mvs_soln, _))) => {
+            if mvs.len() != mvs_soln.len() || puzzle::check(puzzle, &mvs) != Some(goal) {
+                Err(format!(
+                    "{file_stem} FxHash solution ({mvs}) is not a shortest solution (reference {mvs_soln}).",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs),
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ))
+            } else {
+                Ok(())
+            }
+        }
+        (Some((mvs, _)), None) => Err(format!(
+            "{file_stem} has FxHash solution ({mvs}), but reference has no solution.",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs)
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no FxHash solution, but reference has solution ({mvs_soln}).",
+            file_stem = file_stem,
+            mvs_soln = moves_to_string(&mvs_soln)
+        )),
+    }
+}
+
+fn zobrist_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    // the incremental hash of every move along the reference solution (or from
+    // the initial gameboard) equals the hash of the successor gameboard
+    let states = match &soln {
+        Some((mvs_soln, _)) => puzzle::check_steps(puzzle, mvs_soln).unwrap_or_default(),
+        None => vec![puzzle],
+    };
+    for p in states {
+        for (m, q) in p.next() {
+            if p.zobrist_after(p.zobrist(), &m) != q.zobrist() {
+                return Err(format!(
+                    "{file_stem} `zobrist_after` of move {m:?} does not equal the `zobrist` of the successor gameboard\n{p}",
+                    file_stem = file_stem,
+                    m = m,
+                    p = p
+                ));
+            }
+        }
+    }
+    match (puzzle::solve_zobrist(puzzle), soln) {
+        (None, None) => Ok(()),
+        (Some((mvs, goal)), Some((mvs_soln, _))) => {
+            if mvs.len() != mvs_soln.len() || puzzle::check(puzzle, &mvs) != Some(goal) {
+                Err(format!(
+                    "{file_stem} Zobrist solution ({mvs}) is not a shortest solution (reference {mvs_soln}).",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs),
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ))
+            } else {
+                Ok(())
+            }
+        }
+        (Some((mvs, _)), None) => Err(format!(
+            "{file_stem} has Zobrist solution ({mvs}), but reference has no solution.",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs)
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no Zobrist solution, but reference has solution ({mvs_soln}).",
+            file_stem = file_stem,
+            mvs_soln = moves_to_string(&mvs_soln)
+        )),
+    }
+}
+
+fn packed_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let states = match &soln {
+        Some((mvs_soln, _)) => puzzle::check_steps(puzzle, mvs_soln).unwrap_or_default(),
+        None => vec![puzzle],
+    };
+    for q in states
+        .iter()
+        .flat_map(|p| p.next().into_iter().map(|(_, q)| q))
+    {
+        if JumpIN::unpack(&q.pack()) != q {
+            return Err(format!(
+                "{file_stem} unpacking the packed form of a gameboard does not equal the gameboard\n{q}",
+                file_stem = file_stem,
+                q = q
+            ));
+        }
+    }
+    match (puzzle::solve_packed(puzzle), soln) {
+        (None, None) => Ok(()),
+        (Some((mvs, goal)), Some((mvs_soln, _))) => {
+            if mvs.len() != mvs_soln.len() || puzzle::check(puzzle, &mvs) != Some(goal) {
+                Err(format!(
+                    "{file_stem} packed solution ({mvs}) is not a shortest solution (reference {mvs_soln}).",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs),
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ))
+            } else {
+                Ok(())
+            }
+        }
+        (Some((mvs, _)), None) => Err(format!(
+            "{file_stem} has packed solution ({mvs}), but reference has no solution.",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs)
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no packed solution, but reference has solution ({mvs_soln}).",
+            file_stem = file_stem,
+            mvs_soln = moves_to_string(&mvs_soln)
+        )),
+    }
+}
+
+fn solve_bounded_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let unbounded = puzzle::solve(puzzle);
+    let states = puzzle::enumerate_reachable(puzzle).states;
+    if puzzle::solve_bounded(puzzle, MemoryLimit::States(states)) != Ok(unbounded) {
+        return Err(format!(
+            "{file_stem} solution within a limit of all {states} reachable states does not equal the unbounded solution.",
+            file_stem = file_stem,
+            states = states
+        ));
+    }
+    if puzzle::solve_bounded(puzzle, MemoryLimit::Bytes(0))
+        != Err(MemoryLimitExceeded { states: 0 })
+    {
+        return Err(format!(
+            "{file_stem} should exceed a limit of 0 bytes.",
+            file_stem = file_stem
+        ));
+    }
+    // the initial gameboard is not a goal, so some successor must be visited
+    if soln.is_some_and(|(mvs_soln, _)| !mvs_soln.is_empty())
+        && puzzle::solve_bounded(puzzle, MemoryLimit::States(1))
+            != Err(MemoryLimitExceeded { states: 1 })
+    {
+        return Err(format!(
+            "{file_stem} should exceed a limit of 1 state.",
+            file_stem = file_stem
+        ));
+    }
+    Ok(())
+}
+
+fn external_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    // a small chunk, so that the successors of a layer spill to several runs,
+    // and a chunk of one puzzle state, so that a layer spills to more runs
+    // than are merged at once
+    for chunk in [64, 1] {
+        let external =
+            in_external_dir(file_stem, |dir| puzzle::solve_external(puzzle, dir, chunk))?;
+        let external = external.map_err(|err| err.to_string())?;
+        optimal_solve_test(file_stem, puzzle, soln.clone(), "external", |_| external)?;
+    }
+    if puzzle.is_goal() {
+        return Ok(());
+    }
+    let config = SolverConfig::new().max_nodes(1);
+    let limited = in_external_dir(file_stem, |dir| {
+        puzzle::solve_external_with_config(puzzle, dir, 64, &config)
+    })?;
+    match limited.map_err(|err| err.to_string())? {
+        SolveOutcome::Limit(SolveError::NodeLimit { .. }) => Ok(()),
+        r => Err(format!(
+            "{file_stem} configured external solver with a node limit returned {r:?}.",
+            file_stem = file_stem,
+            r = r
+                .into_result()
+                .map(|soln| soln.map(|(mvs, _)| moves_to_string(&mvs)))
+        )),
+    }
+}
+
+/// Runs an external solver in a fresh directory, checking that it neither
+/// overwrites a file of the caller nor leaves any of its own files behind.
+fn in_external_dir<T>(file_stem: &str, solve: impl FnOnce(&Path) -> T) -> Result<T, String> {
+    let dir = std::env::temp_dir().join(format!(
+        "jumpin-external-{}-{}",
+        file_stem,
+        std::process::id()
+    ));
+    fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+    // a file of the caller, named as a layer of the search, must survive it
+    let own = dir.join("layer0.bin");
+    fs::write(&own, file_stem).map_err(|err| err.to_string())?;
+    let r = solve(&dir);
+    let kept = fs::read_to_string(&own).ok();
+    let _ = fs::remove_file(&own);
+    let leftover = fs::read_dir(&dir)
+        .map(|entries| entries.count())
+        .unwrap_or(0);
+    let _ = fs::remove_dir(&dir);
+    if kept.as_deref() != Some(file_stem) {
+        return Err(format!(
+            "{file_stem} external solver overwrote a file of its directory.",
+            file_stem = file_stem
+        ));
+    }
+    if leftover != 0 {
+        return Err(format!(
+            "{file_stem} external solver left {leftover} files behind.",
+            file_stem = file_stem,
+            leftover = leftover
+        ));
+    }
+    Ok(r)
+}
+
+fn bloom_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    // a Bloom filter large enough that false positives are very unlikely
+    match (puzzle::solve_bloom(puzzle, usize::MAX, 1 << 24, 4), soln) {
+        (None, None) => Ok(()),
+        (Some((mvs, goal)), Some(_)) => {
+            if puzzle::check(puzzle, &mvs) != Some(goal) {
+                Err(format!(
+                    "{file_stem} Bloom filter solution ({mvs}) failed `puzzle::check`.",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs)
+                ))
+            } else {
+                Ok(())
+            }
+        }
+        (Some((mvs, _)), None) => Err(format!(
+            "{file_stem} has Bloom filter solution ({mvs}), but reference has no solution.",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs)
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no Bloom filter solution, but reference has solution ({mvs_soln}).",
+            file_stem = file_stem,
+            mvs_soln = moves_to_string(&mvs_soln)
+        )),
+    }
+}
+
+fn ranked_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    // the ranks of distinct reachable gameboards are distinct
+    let reachable = puzzle::enumerate_reachable(puzzle);
+    let mut ranks = HashSet::new();
+    let mut queue = vec![puzzle];
+    let mut seen = HashSet::from([puzzle]);
+    while let Some(p) = queue.pop() {
+        if p.rank() >= p.max_rank() || !ranks.insert(p.rank()) {
+            return Err(format!(
+                "{file_stem} rank ({rank}) is out of range or not distinct\n{p}",
+                file_stem = file_stem,
+                rank = p.rank(),
+                p = p
+            ));
+        }
+        for (_, q) in p.next() {
+            if seen.insert(q) {
+                queue.push(q);
+            }
+        }
+    }
+    if ranks.len() != reachable.states {
+        return Err(format!(
+            "{file_stem} has {ranks} ranks, but {states} reachable gameboards.",
+            file_stem = file_stem,
+            ranks = ranks.len(),
+            states = reachable.states
+        ));
+    }
+    match (puzzle::solve_ranked(puzzle), soln) {
+        (None, None) => Ok(()),
+        (Some((mvs, goal)), Some((mvs_soln, _))) => {
+            if mvs.len() != mvs_soln.len() || puzzle::check(puzzle, &mvs) != Some(goal) {
+                Err(format!(
+                    "{file_stem} ranked solution ({mvs}) is not a shortest solution (reference {mvs_soln}).",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs),
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ))
+            } else {
+                Ok(())
+            }
+        }
+        (Some((mvs, _)), None) => Err(format!(
+            "{file_stem} has ranked solution ({mvs}), but reference has no solution.",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs)
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no ranked solution, but reference has solution ({mvs_soln}).",
+            file_stem = file_stem,
+            mvs_soln = moves_to_string(&mvs_soln)
+        )),
+    }
+}
+
+fn memory_stats_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let mut stats = None;
+    optimal_solve_test(file_stem, puzzle, soln, "measured", |p| {
+        let (soln, s) = puzzle::solve_with_memory_stats(p);
+        stats = Some(s);
+        soln
+    })?;
+    let stats = stats.unwrap();
+    if stats.max_queue_len == 0 || stats.max_queue_len > stats.max_closed_len {
+        return Err(format!(
+            "{file_stem} has a maximum queue length of {queue}, but should have between 1 and the maximum closed-list length ({closed}).",
+            file_stem = file_stem,
+            queue = stats.max_queue_len,
+            closed = stats.max_closed_len
+        ));
+    }
+    if stats.peak_bytes.is_none() {
+        return Err(format!(
+            "{file_stem} has no peak bytes, but the counting allocator is installed.",
+            file_stem = file_stem
+        ));
+    }
+    Ok(())
+}
+
+fn byte_array_test(file_stem: &str, puzzle: JumpIN) -> Result<(), String> {
+    // the puzzle states within two moves of `puzzle`, with one byte per cell
+    let cells = |p: &JumpIN| -> [u8; 25] {
+        let mut cells = [0; 25];
+        for (i, pos) in Pos::values().enumerate() {
+            cells[i] = p.get(pos).map_or(0, |obj| obj as u8 + 1);
+        }
+        cells
+    };
+    let mut ps = vec![puzzle];
+    for (_, q) in puzzle.next() {
+        ps.extend(q.next().into_iter().map(|(_, r)| r));
+        ps.push(q);
+    }
+    let hash_builder = RandomState::new();
+    for p in &ps {
+        for q in &ps {
+            let (bp, bq) = (ByteArray(cells(p)), ByteArray(cells(q)));
+            if (bp == bq) != (p == q) || bp.cmp(&bq) != cells(p).cmp(&cells(q)) {
+                return Err(format!(
+                    "{file_stem} has byte arrays {bp:?} and {bq:?} that compare unlike their puzzle states.",
+                    file_stem = file_stem,
+                    bp = bp,
+                    bq = bq
+                ));
+            }
+            if bp == bq && hash_builder.hash_one(bp) != hash_builder.hash_one(bq) {
+                return Err(format!(
+                    "{file_stem} has equal byte arrays {bp:?} with distinct hashes.",
+                    file_stem = file_stem,
+                    bp = bp
+                ));
+            }
+        }
+    }
+    let states = ps.iter().collect::<HashSet<_>>().len();
+    let arrays = ps
+        .iter()
+        .map(|p| ByteArray(cells(p)))
+        .collect::<HashSet<_>>()
+        .len();
+    if states != arrays {
+        return Err(format!(
+            "{file_stem} has {states} distinct puzzle states within two moves, but {arrays} distinct byte arrays.",
+            file_stem = file_stem,
+            states = states,
+            arrays = arrays
+        ));
+    }
+    Ok(())
+}
+
+fn search_graph_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let mut graph = None;
+    optimal_solve_test(file_stem, puzzle, soln, "BFS with graph", |p| {
+        let (soln, g) = puzzle::solve_with_graph(p);
+        graph = Some(g);
+        soln
+    })?;
+    let graph = graph.unwrap();
+    for p in graph.states() {
+        // every path of the graph leads (by legal moves) to its puzzle state
+        let ms = graph.path_to(p).unwrap();
+        let q = ms.iter().fold(puzzle, |q, m| q.apply_move(m));
+        if q != *p || graph.parent(p).is_some() == ms.is_empty() {
+            return Err(format!(
+                "{file_stem} has a graph path ({mvs}) that does not lead to its puzzle state.",
+                file_stem = file_stem,
+                mvs = moves_to_string(&ms)
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn dot_export_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let export = |options: &DotOptions| {
+        let (_, graph) = puzzle::solve_with_graph(puzzle);
+        let mut dot = vec![];
+        puzzle::export_search_graph_with(&graph, &mut dot, options).unwrap();
+        (graph, String::from_utf8(dot).unwrap())
+    };
+    // the number of nodes, edges, and bold edges of a DOT graph
+    let count = |dot: &str| {
+        let edges = dot.lines().filter(|line| line.contains(" -> ")).count();
+        let bold = dot
+            .lines()
+            .filter(|line| line.contains("style=bold"))
+            .count();
+        let nodes = dot.lines().filter(|line| line.contains("[label=")).count() - edges;
+        (nodes, edges, bold)
+    };
+    let depth = soln.as_ref().map_or(0, |(ms, _)| ms.len());
+    let (graph, dot) = export(&DotOptions::new());
+    if !dot.starts_with("digraph search {") || count(&dot) != (graph.len(), graph.len() - 1, depth)
+    {
+        return Err(format!(
+            "{file_stem} DOT export (nodes, edges, bold edges) is {counts:?}, but the graph has {states} states and a solution of {depth} moves.",
+            file_stem = file_stem,
+            counts = count(&dot),
+            states = graph.len(),
+            depth = depth
+        ));
+    }
+    if export(&DotOptions::new()).1 != dot {
+        return Err(format!(
+            "{file_stem} DOT export differs from run to run.",
+            file_stem = file_stem
+        ));
+    }
+    let max_nodes = 5.min(graph.len());
+    let truncated = export(&DotOptions::new().max_nodes(max_nodes)).1;
+    let (nodes, edges, _) = count(&truncated);
+    if (nodes, edges) != (max_nodes, max_nodes - 1) {
+        return Err(format!(
+            "{file_stem} DOT export of {max_nodes} nodes has {nodes} nodes and {edges} edges.",
+            file_stem = file_stem,
+            max_nodes = max_nodes,
+            nodes = nodes,
+            edges = edges
+        ));
+    }
+    let cone = export(&DotOptions::new().solution_cone()).1;
+    let (nodes, edges, bold) = count(&cone);
+    if bold != depth || edges + 1 != nodes || nodes > graph.len() {
+        return Err(format!(
+            "{file_stem} DOT export of the solution cone (nodes, edges, bold edges) is {counts:?}, but the solution has {depth} moves.",
+            file_stem = file_stem,
+            counts = (nodes, edges, bold),
+            depth = depth
+        ));
+    }
+    Ok(())
+}
+
+fn chunked_queue_test(file_stem: &str, puzzle: JumpIN) -> Result<(), String> {
+    // enqueue the successor puzzle states of each dequeued puzzle state (as
+    // BFS does, but without duplicate detection), across several chunks
+    let mut chunked = ChunkedQueue::new();
+    let mut queue = std::collections::VecDeque::new();
+    chunked.push_back(puzzle);
+    queue.push_back(puzzle);
+    for _ in 0..3 * puzzle::CHUNK_LEN {
+        let (p, q) = match (chunked.pop_front(), queue.pop_front()) {
+            (Some(p), Some(q)) => (p, q),
+            (None, None) => break,
+            (p, q) => {
+                return Err(format!(
+                    "{file_stem} chunked queue dequeued {p:?}, but should dequeue {q:?}.",
+                    file_stem = file_stem,
+                    p = p,
+                    q = q
+                ))
+            }
+        };
+        if p != q || chunked.len() != queue.len() {
+            return Err(format!(
+                "{file_stem} chunked queue dequeued {p:?} (with {len} remaining), but should dequeue {q:?} (with {qlen} remaining).",
+                file_stem = file_stem,
+                p = p,
+                len = chunked.len(),
+                q = q,
+                qlen = queue.len()
+            ));
+        }
+        for (_, r) in p.next() {
+            chunked.push_back(r);
+            queue.push_back(r);
+        }
+    }
+    if !chunked.into_iter().eq(queue) {
+        return Err(format!(
+            "{file_stem} chunked queue has remaining elements unlike a `VecDeque`.",
+            file_stem = file_stem
+        ));
+    }
+    Ok(())
+}
+
+fn solve_within_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let depth = soln.as_ref().map_or(64, |(mvs, _)| mvs.len());
+    optimal_solve_test(file_stem, puzzle, soln, "horizon-bounded BFS", |p| {
+        puzzle::solve_within(p, depth)
+    })?;
+    // a solution needs its whole depth, beyond a horizon just short of it
+    if let Some((mvs, _)) = depth
+        .checked_sub(1)
+        .and_then(|depth| puzzle::solve_within(puzzle, depth))
+    {
+        return Err(format!(
+            "{file_stem} has a solution ({mvs}) within a horizon of {depth} moves, but should not.",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs),
+            depth = depth - 1
+        ));
+    }
+    Ok(())
+}
+
+fn shared_grid_test(file_stem: &str, puzzle: JumpIN) -> Result<(), String> {
+    // the gameboard of a puzzle state, as a persistent grid indexed by `(y, x)`
+    let shared =
+        |p: &JumpIN| SharedGrid::<Option<Object>, 5, 5>::from_fn(|(y, x)| *p.get(Pos::new(x, y)));
+    let grid = shared(&puzzle);
+    for (_, q) in puzzle.next() {
+        // apply the move as changes of cells of a clone of the grid
+        let mut next = grid.clone();
+        for pos in Pos::values() {
+            let (x, y) = pos.xy();
+            if puzzle.get(pos) != q.get(pos) {
+                next.set((y, x), *q.get(pos));
+            }
+        }
+        if next != shared(&q) {
+            return Err(format!(
+                "{file_stem} has a persistent grid {next:?} unlike its successor puzzle state {q:?}.",
+                file_stem = file_stem,
+                next = next,
+                q = q
+            ));
+        }
+        for y in 0..5 {
+            let changed = (0..5).any(|x| {
+                let pos = Pos::new(x, y);
+                puzzle.get(pos) != q.get(pos)
+            });
+            if next.shares_row(&grid, y) == changed {
+                return Err(format!(
+                    "{file_stem} has a persistent grid whose row {y} is {shared}shared with its predecessor, but should {not}be.",
+                    file_stem = file_stem,
+                    y = y,
+                    shared = if changed { "" } else { "not " },
+                    not = if changed { "not " } else { "" }
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn zobrist_exact_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    for (m, q, hash) in puzzle.successors_hashed(puzzle.zobrist()) {
+        if hash != q.zobrist() {
+            return Err(format!(
+                "{file_stem} has successor hash {hash:#x} after {mv}, but should be {zobrist:#x}.",
+                file_stem = file_stem,
+                hash = hash,
+                mv = moves_to_string(&[m]),
+                zobrist = q.zobrist()
+            ));
+        }
+    }
+    optimal_solve_test(
+        file_stem,
+        puzzle,
+        soln,
+        "exact Zobrist BFS",
+        puzzle::solve_zobrist_exact,
+    )
+}
+
+fn perf_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let mut report = None;
+    optimal_solve_test(file_stem, puzzle, soln, "measured BFS", |p| {
+        let (soln, r) = puzzle::measure(p, puzzle::solve);
+        report = Some(r);
+        soln.map(|(mvs, q)| (mvs, q.0))
+    })?;
+    let report = report.unwrap();
+    // the measured search is the search of `solve` (with the same representatives)
+    let (_, stats) = puzzle::solve_with_stats(puzzle, &SolverConfig::new());
+    // every expansion of `solve` hashes each of its successor puzzle states
+    if report.hashes < report.successors
+        || report.expansion_time > report.elapsed
+        || report.expansions != stats.expanded as u64
+    {
+        return Err(format!(
+            "{file_stem} has an inconsistent performance report {report:?}.",
+            file_stem = file_stem,
+            report = report
+        ));
+    }
+    Ok(())
+}
+
+fn distance_table_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    (mvs_soln, goal): (Vec<<JumpIN as Puzzle>::Move>, JumpIN),
+) -> Result<(), String> {
+    let path = std::env::temp_dir().join(format!(
+        "jumpin-distance-table-{}-{}",
+        file_stem,
+        std::process::id()
+    ));
+    let write = fs::File::create(&path).and_then(|file| {
+        puzzle::write_distance_table(file, puzzle.max_rank(), puzzle::analyze_backwards([goal]))
+    });
+    let table = write.and_then(|()| puzzle::DistanceTable::open(&path));
+    // SAFETY: the file is not modified until the mapped table is dropped
+    let mapped = unsafe { puzzle::DistanceTable::open_mapped(&path) };
+    let table = table.map_err(|err| err.to_string())?;
+    let mapped = mapped.map_err(|err| err.to_string())?;
+    let same = puzzle::analyze_backwards([goal])
+        .into_iter()
+        .all(|(p, _)| table.lookup(&p) == mapped.lookup(&p));
+    drop(mapped);
+    let _ = fs::remove_file(&path);
+    if !same {
+        return Err(format!(
+            "{file_stem} memory-mapped distance table differs from the distance table read into memory.",
+            file_stem = file_stem
+        ));
+    }
+    if table.len() != puzzle.max_rank() || table.lookup(&puzzle) != Some(mvs_soln.len()) {
+        return Err(format!(
+            "{file_stem} distance table lookup ({lookup:?}) does not equal reference solution ({mvs_soln}) length.",
+            file_stem = file_stem,
+            lookup = table.lookup(&puzzle),
+            mvs_soln = moves_to_string(&mvs_soln)
+        ));
+    }
+    optimal_solve_test(
+        file_stem,
+        puzzle,
+        Some((mvs_soln, goal)),
+        "distance table A*",
+        |p| puzzle::solve_astar(table.guide(p)).map(|(mvs, goal)| (mvs, goal.p)),
+    )
+}
+
+fn frontier_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    optimal_solve_test(file_stem, puzzle, soln.clone(), "FIFO frontier", |p| {
+        puzzle::solve_with_frontier(p, puzzle::Fifo::new(), |_, _| ())
+    })?;
+    optimal_solve_test(
+        file_stem,
+        puzzle,
+        soln.clone(),
+        "bucket queue frontier",
+        |p| puzzle::solve_with_frontier(p, puzzle::BucketQueue::new(), |g, _| g),
+    )?;
+    optimal_solve_test(file_stem, puzzle, soln.clone(), "heap frontier", |p| {
+        puzzle::solve_with_frontier(p, puzzle::Heap::new(), |g, q: &JumpIN| g + q.estimate())
+    })?;
+    suboptimal_solve_test(
+        file_stem,
+        puzzle,
+        soln,
+        "LIFO frontier",
+        f64::INFINITY,
+        |p| puzzle::solve_with_frontier(p, puzzle::Lifo::new(), |_, _| ()),
+    )
+}
+
+fn closed_set_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    optimal_solve_test(file_stem, puzzle, soln.clone(), "hash set BFS", |p| {
+        puzzle::solve_with_closed_set(p, HashSet::new())
+    })?;
+    optimal_solve_test(file_stem, puzzle, soln.clone(), "rank set BFS", |p| {
+        puzzle::solve_with_closed_set(p, RankSet::new(p.max_rank()))
+    })?;
+    optimal_solve_test(file_stem, puzzle, soln.clone(), "Bloom set BFS", |p| {
+        puzzle::solve_with_closed_set(p, BloomSet::new(1 << 20, 7))
+    })?;
+    let dir = std::env::temp_dir().join(format!(
+        "jumpin-closed-set-{}-{}",
+        file_stem,
+        std::process::id()
+    ));
+    fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+    let disk = optimal_solve_test(file_stem, puzzle, soln, "disk set BFS", |p| {
+        puzzle::solve_with_closed_set(p, DiskSet::new(&dir, 16))
+    });
+    let leftover = fs::read_dir(&dir)
+        .map(|entries| entries.count())
+        .unwrap_or(0);
+    let _ = fs::remove_dir(&dir);
+    if leftover != 0 {
+        return Err(format!(
+            "{file_stem} disk set left {leftover} runs behind.",
+            file_stem = file_stem,
+            leftover = leftover
+        ));
+    }
+    disk
+}
+
+fn next_batch_test(file_stem: &str, puzzle: JumpIN) -> Result<(), String> {
+    let batch: Vec<JumpIN> = std::iter::once(puzzle)
+        .chain(puzzle.next().into_iter().map(|(_, q)| q))
+        .collect();
+    let next = JumpIN::next_batch(&batch);
+    if next.len() != batch.len() {
+        return Err(format!(
+            "{file_stem} has {len} batched expansions of {batch_len} gameboards.",
+            file_stem = file_stem,
+            len = next.len(),
+            batch_len = batch.len()
+        ));
+    }
+    for (p, next) in batch.iter().zip(next) {
+        if next != p.next() {
+            return Err(format!(
+                "{file_stem} batched successors of gameboard\n{p}differ from `next`.",
+                file_stem = file_stem,
+                p = p
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn solver_config_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let configured = |solver: &str, config: SolverConfig| {
+        optimal_solve_test(file_stem, puzzle, soln.clone(), solver, |p| {
+            puzzle::solve_with_config(p, &config)
+                .into_result()
+                .unwrap_or_else(|err| panic!("{}", err))
+        })
+    };
+    configured("configured BFS", SolverConfig::new())?;
+    configured("scoped configured BFS", SolverConfig::new().dedupe_scope(2))?;
+    if let Some((mvs_soln, _)) = &soln {
+        configured(
+            "depth-limited configured BFS",
+            SolverConfig::new().max_depth(mvs_soln.len()),
+        )?;
+    }
+    optimal_solve_test(
+        file_stem,
+        puzzle,
+        soln.clone(),
+        "hashed configured BFS",
+        |p| {
+            let config = SolverConfig::new().hasher(BuildHasherDefault::<DefaultHasher>::default());
+            puzzle::solve_with_config(p, &config)
+                .into_result()
+                .unwrap_or_else(|err| panic!("{}", err))
+        },
+    )?;
+    optimal_solve_test(file_stem, puzzle, soln, "configured parallel BFS", |p| {
+        let config = SolverConfig::new().parallelism(2);
+        puzzle::solve_parallel_with_config(p, &config)
+            .into_result()
+            .unwrap_or_else(|err| panic!("{}", err))
+    })?;
+    if puzzle.is_goal() {
+        return Ok(());
+    }
+    let limited = [
+        SolverConfig::new().max_nodes(1),
+        SolverConfig::new().memory_limit(MemoryLimit::States(1)),
+        SolverConfig::new().time_limit(Duration::ZERO),
+    ];
+    for (config, limit) in limited.iter().zip(["node", "memory", "time"]) {
+        let err = puzzle::solve_with_config(puzzle, config).into_result();
+        let expected = match err {
+            Err(SolveError::NodeLimit { .. }) => limit == "node",
+            Err(SolveError::MemoryLimit(_)) => limit == "memory",
+            Err(SolveError::TimeLimit { .. }) => limit == "time",
+            Ok(_) | Err(SolveError::Cancelled { .. }) => false,
+        };
+        if !expected {
+            return Err(format!(
+                "{file_stem} configured BFS with a {limit} limit returned {err:?}.",
+                file_stem = file_stem,
+                limit = limit,
+                err = err.map(|soln| soln.map(|(mvs, _)| moves_to_string(&mvs)))
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn search_stats_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let depth = soln.as_ref().map(|(mvs_soln, _)| mvs_soln.len());
+    optimal_solve_test(file_stem, puzzle, soln, "BFS with stats", |p| {
+        let (r, stats) = puzzle::solve_with_stats(p, &SolverConfig::new());
+        let r = r.into_result().unwrap_or_else(|err| panic!("{}", err));
+        if stats.depth != r.as_ref().map(|(mvs, _)| mvs.len()) {
+            panic!(
+                "{} stats depth (is {:?}) should be the number of moves of the solution",
+                file_stem, stats.depth
+            )
+        }
+        r
+    })?;
+    let (_, stats) = puzzle::solve_with_stats(puzzle, &SolverConfig::new());
+    let (_, memory) = puzzle::solve_with_memory_stats(puzzle);
+    if stats.depth != depth
+        || stats.duplicates > stats.generated
+        || stats.max_closed > stats.generated + 1
+        || stats.max_frontier != memory.max_queue_len
+        || stats.max_closed != memory.max_closed_len
+    {
+        return Err(format!(
+            "{file_stem} stats are inconsistent: {stats:?} (memory stats {memory:?}).",
+            file_stem = file_stem,
+            stats = stats,
+            memory = memory
+        ));
+    }
+    for (solver, solve) in configured_solvers() {
+        let mut stats = SearchStats::default();
+        let outcome = solve(puzzle, &SolverConfig::new().observer(&mut stats));
+        let moves = outcome.into_solution().map(|(mvs, _)| mvs.len());
+        if stats.depth != moves || stats.visited == 0 || stats.expanded > stats.visited {
+            return Err(format!(
+                "{file_stem} stats of configured {solver} are {stats:?}.",
+                file_stem = file_stem,
+                solver = solver,
+                stats = stats
+            ));
+        }
+    }
+    if !puzzle.is_goal() {
+        let (r, limited) = puzzle::solve_with_stats(puzzle, &SolverConfig::new().max_nodes(1));
+        if !matches!(r, SolveOutcome::Limit(_)) || limited.expanded != 1 || limited.depth.is_some()
+        {
+            return Err(format!(
+                "{file_stem} stats of a node-limited BFS are {stats:?}.",
+                file_stem = file_stem,
+                stats = limited
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// An observer that records the events of a search, to check them against its
+/// result (and its statistics).
+#[derive(Default)]
+struct Recorder {
+    expansions: usize,
+    layers: Vec<(usize, usize)>,
+    solution: Option<usize>,
+}
+
+impl Observer<JumpIN> for Recorder {
+    fn on_expand(&mut self, _: &JumpIN, depth: usize) {
+        self.expansions += 1;
+        if depth != self.layers.len() {
+            panic!(
+                "on_expand depth (is {}) should follow the completed layers (are {:?})",
+                depth, self.layers
+            )
+        }
+    }
+
+    fn on_layer_complete(&mut self, depth: usize, states: usize) {
+        self.layers.push((depth, states));
+    }
+
+    fn on_solution(&mut self, mvs: &[<JumpIN as Puzzle>::Move], _: &JumpIN) {
+        self.solution = Some(mvs.len());
+    }
+}
+
+fn observer_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let mut recorder = Recorder::default();
+    optimal_solve_test(file_stem, puzzle, soln, "observed BFS", |p| {
+        puzzle::solve_observed(p, &SolverConfig::new(), &mut recorder)
+            .into_result()
+            .unwrap_or_else(|err| panic!("{}", err))
+    })?;
+    let (r, stats) = puzzle::solve_with_stats(puzzle, &SolverConfig::new());
+    let depths_ok = recorder
+        .layers
+        .iter()
+        .enumerate()
+        .all(|(i, (depth, _))| i == *depth);
+    let states_ok = recorder.layers.windows(2).all(|w| w[0].1 <= w[1].1);
+    let solution = r.into_solution().map(|(mvs, _)| mvs.len());
+    if recorder.expansions != stats.expanded
+        || !depths_ok
+        || !states_ok
+        || recorder.solution != solution
+    {
+        return Err(format!(
+            "{file_stem} observed {expansions} expansions (expected {expanded}), layers {layers:?}, and solution {observed:?} (expected {solution:?}).",
+            file_stem = file_stem,
+            expansions = recorder.expansions,
+            expanded = stats.expanded,
+            layers = recorder.layers,
+            observed = recorder.solution,
+            solution = solution
+        ));
+    }
+    configured_observer_test(file_stem, puzzle, solution)
+}
+
+/// An observer that counts the events of a search (other than its solution),
+/// and records the number of moves of its solution.
+#[derive(Default)]
+struct Tally {
+    events: usize,
+    solution: Option<usize>,
+}
+
+impl Observer<JumpIN> for Tally {
+    fn on_expand(&mut self, _: &JumpIN, _: usize) {
+        self.events += 1;
+    }
+
+    fn on_layer_complete(&mut self, _: usize, _: usize) {
+        self.events += 1;
+    }
+
+    fn on_solution(&mut self, mvs: &[<JumpIN as Puzzle>::Move], _: &JumpIN) {
+        self.solution = Some(mvs.len());
+    }
+}
+
+type Configured<O> = fn(JumpIN, &SolverConfig<RandomState, &mut O>) -> SolveOutcome<JumpIN>;
+
+/// A solver configured by a `SolverConfig` without an observer.
+type Unobserved = fn(JumpIN, &SolverConfig) -> SolveOutcome<JumpIN>;
+
+/// The solvers configured by a `SolverConfig` (with a borrowed observer) that
+/// are quick on every puzzle of the tests, by name.
+fn configured_solvers<O: Observer<JumpIN> + Send>() -> [(&'static str, Configured<O>); 12] {
+    [
+        ("BFS", |p, config| puzzle::solve_with_config(p, config)),
+        ("A*", |p, config| puzzle::solve_astar_with_config(p, config)),
+        ("greedy", |p, config| {
+            puzzle::solve_greedy_with_config(p, config)
+        }),
+        ("weighted A*", |p, config| {
+            puzzle::solve_weighted_astar_with_config(p, 2.0, config)
+        }),
+        ("DFS", |p, config| puzzle::solve_any_with_config(p, config)),
+        ("shared BFS", |p, config| {
+            puzzle::solve_shared_with_config(p, config)
+        }),
+        ("keyed BFS", |p, config| {
+            puzzle::solve_keyed_with_config(p, config)
+        }),
+        ("partial-order-reduced BFS", |p, config| {
+            puzzle::solve_por_with_config(p, config)
+        }),
+        ("symmetric BFS", |p, config| {
+            puzzle::solve_symmetric_with_config(p, config)
+        }),
+        ("layered BFS", |p, config| {
+            puzzle::solve_layered_with_config(p, config)
+        }),
+        ("beam", |p, config| {
+            puzzle::solve_beam_with_config(p, 100, config)
+        }),
+        ("parallel BFS", |p, config| {
+            puzzle::solve_parallel_with_config(p, config)
+        }),
+    ]
+}
+
+fn configured_observer_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    solution: Option<usize>,
+) -> Result<(), String> {
+    for (solver, solve) in configured_solvers() {
+        let mut tally = Tally::default();
+        let outcome = solve(puzzle, &SolverConfig::new().observer(&mut tally));
+        let moves = outcome.into_solution().map(|(mvs, _)| mvs.len());
+        if tally.solution != moves
+            || moves.is_some() != solution.is_some()
+            || (tally.events == 0) != puzzle.is_goal()
+        {
+            return Err(format!(
+                "{file_stem} observed configured {solver} reported {events} events and solution {observed:?} (expected {solution:?}).",
+                file_stem = file_stem,
+                solver = solver,
+                events = tally.events,
+                observed = tally.solution,
+                solution = solution
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// An observer that cancels its token once the first layer is expanded.
+struct Canceller(CancelToken);
+
+impl Observer<JumpIN> for Canceller {
+    fn on_layer_complete(&mut self, _: usize, _: usize) {
+        self.0.cancel();
+    }
+}
+
+fn cancel_token_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    optimal_solve_test(file_stem, puzzle, soln, "BFS with a cancel token", |p| {
+        let config = SolverConfig::new().cancel_token(CancelToken::new());
+        puzzle::solve_with_config(p, &config)
+            .into_result()
+            .unwrap_or_else(|err| panic!("{}", err))
+    })?;
+    if puzzle.is_goal() {
+        return Ok(());
+    }
+    let token = CancelToken::new();
+    token.cancel();
+    let config = SolverConfig::new().cancel_token(token.clone());
+    let (r, stats) = puzzle::solve_with_stats(puzzle, &config);
+    let r = r.into_result();
+    if r != Err(SolveError::Cancelled { states: 1 }) || stats.expanded != 0 {
+        return Err(format!(
+            "{file_stem} cancelled BFS returned {r:?} after {expanded} expansions.",
+            file_stem = file_stem,
+            r = r.map(|soln| soln.map(|(mvs, _)| moves_to_string(&mvs))),
+            expanded = stats.expanded
+        ));
+    }
+    let r = puzzle::solve_parallel_with_config(puzzle, &config).into_result();
+    if r != Err(SolveError::Cancelled { states: 1 }) {
+        return Err(format!(
+            "{file_stem} cancelled parallel BFS returned {r:?}.",
+            file_stem = file_stem,
+            r = r.map(|soln| soln.map(|(mvs, _)| moves_to_string(&mvs)))
+        ));
+    }
+    let token = CancelToken::new();
+    let config = SolverConfig::new().cancel_token(token.clone());
+    let r = puzzle::solve_observed(puzzle, &config, &mut Canceller(token)).into_result();
+    match r {
+        Ok(Some((mvs, _))) if mvs.len() <= 1 => Ok(()),
+        Ok(None) | Err(SolveError::Cancelled { .. }) => Ok(()),
+        _ => Err(format!(
+            "{file_stem} BFS cancelled after the first layer returned {r:?}.",
+            file_stem = file_stem,
+            r = r.map(|soln| soln.map(|(mvs, _)| moves_to_string(&mvs)))
+        )),
+    }
+}
+
+fn timed_out_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    optimal_solve_test(file_stem, puzzle, soln, "timed anytime", |p| {
+        puzzle::solve_anytime_timed(p, 1.0, Duration::from_secs(600), |_, _| ())
+            .unwrap_or_else(|timed_out| panic!("timed out after {} states", timed_out.states))
+    })?;
+    if puzzle.is_goal() {
+        return Ok(());
+    }
+    let timed_out = puzzle::solve_anytime_timed(puzzle, 3.0, Duration::ZERO, |_, _| ());
+    let expected = TimedOut {
+        states: 0,
+        depth: 0,
+        incumbent: None,
+    };
+    if timed_out != Err(expected) {
+        return Err(format!(
+            "{file_stem} anytime search with no time returned {r:?}.",
+            file_stem = file_stem,
+            r = timed_out.map(|soln| soln.map(|(mvs, _)| moves_to_string(&mvs)))
+        ));
+    }
+    let config = SolverConfig::new().time_limit(Duration::ZERO);
+    match puzzle::solve_with_config(puzzle, &config).into_result() {
+        Err(SolveError::TimeLimit {
+            states: 1,
+            depth: 0,
+        }) => Ok(()),
+        r => Err(format!(
+            "{file_stem} configured BFS with no time returned {r:?}.",
+            file_stem = file_stem,
+            r = r.map(|soln| soln.map(|(mvs, _)| moves_to_string(&mvs)))
+        )),
+    }
+}
+
+fn checkpoint_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let path = std::env::temp_dir().join(format!(
+        "jumpin-checkpoint-{}-{}",
+        file_stem,
+        std::process::id()
+    ));
+    optimal_solve_test(file_stem, puzzle, soln.clone(), "checkpointed BFS", |p| {
+        puzzle::solve_checkpointed(p, &path, 3).unwrap_or_else(|err| panic!("{}", err))
+    })?;
+    if path.exists() {
+        return Err(format!(
+            "{file_stem} checkpointed BFS left its checkpoint {path:?}.",
+            file_stem = file_stem,
+            path = path
+        ));
+    }
+    // interrupt a search after a few expansions, and resume it from disk
+    let resumed = match BfsCheckpoint::new(puzzle).run(5) {
+        Ok(soln) => soln,
+        Err(checkpoint) => {
+            let loaded = checkpoint
+                .save(&path)
+                .and_then(|()| BfsCheckpoint::<JumpIN>::load(&path));
+            let loaded = loaded.map_err(|err| err.to_string())?;
+            if loaded.states() != checkpoint.states()
+                || loaded.frontier_len() != checkpoint.frontier_len()
+            {
+                let _ = fs::remove_file(&path);
+                return Err(format!(
+                    "{file_stem} loaded checkpoint ({states} states, {frontier} to expand) differs from the saved checkpoint ({saved_states} states, {saved_frontier} to expand).",
+                    file_stem = file_stem,
+                    states = loaded.states(),
+                    frontier = loaded.frontier_len(),
+                    saved_states = checkpoint.states(),
+                    saved_frontier = checkpoint.frontier_len()
+                ));
+            }
+            // a checkpoint of another initial puzzle state is rejected
+            if let Some((_, q)) = puzzle.successors().next() {
+                if puzzle::solve_checkpointed(q, &path, 1).is_ok() {
+                    let _ = fs::remove_file(&path);
+                    return Err(format!(
+                        "{file_stem} checkpointed BFS resumed the checkpoint of another puzzle.",
+                        file_stem = file_stem
+                    ));
+                }
+            }
+            let resumed = puzzle::solve_checkpointed(puzzle, &path, 3);
+            let _ = fs::remove_file(&path);
+            resumed.map_err(|err| err.to_string())?
+        }
+    };
+    optimal_solve_test(file_stem, puzzle, soln, "resumed BFS", |_| resumed)
+}
+
+/// A `JumpIN` puzzle whose moves are enumerated in the reverse order, standing
+/// in for a puzzle whose order of moves varies from run to run.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Reversed(JumpIN);
+
+impl Puzzle for Reversed {
+    type Move = <JumpIN as Puzzle>::Move;
+
+    fn is_goal(&self) -> bool {
+        self.0.is_goal()
+    }
+
+    fn legal_moves(&self) -> Vec<Self::Move> {
+        let mut mvs = self.0.legal_moves();
+        mvs.reverse();
+        mvs
+    }
+
+    fn apply_move(&self, m: &Self::Move) -> Self {
+        Reversed(self.0.apply_move(m))
+    }
+}
+
+fn deterministic_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    optimal_solve_test(file_stem, puzzle, soln, "deterministic BFS", |p| {
+        puzzle::solve_deterministic(p)
+    })?;
+    let mvs = puzzle::solve_deterministic(puzzle).map(|(mvs, _)| mvs);
+    let reversed = puzzle::solve_deterministic(Reversed(puzzle)).map(|(mvs, _)| mvs);
+    if mvs != reversed {
+        return Err(format!(
+            "{file_stem} deterministic BFS solution ({mvs:?}) depends on the order of moves ({reversed:?}).",
+            file_stem = file_stem,
+            mvs = mvs.as_deref().map(moves_to_string),
+            reversed = reversed.as_deref().map(moves_to_string)
+        ));
+    }
+    Ok(())
+}
+
+fn solve_outcome_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let outcome = puzzle::solve_with_config(puzzle, &SolverConfig::new());
+    if let SolveOutcome::Unsolvable { states_explored } = outcome {
+        let states = puzzle::enumerate_reachable(puzzle).states;
+        // only the representatives of the reachable puzzle states are explored
+        if soln.is_some() || states_explored == 0 || states_explored > states {
+            return Err(format!(
+                "{file_stem} outcome is unsolvable after {states_explored} states (reachable {states}).",
+                file_stem = file_stem,
+                states_explored = states_explored,
+                states = states
+            ));
+        }
+    } else if outcome.is_solved() != soln.is_some() {
+        return Err(format!(
+            "{file_stem} outcome is {outcome:?}.",
+            file_stem = file_stem,
+            outcome = outcome
+        ));
+    }
+    optimal_solve_test(file_stem, puzzle, soln, "BFS outcome", |_| {
+        outcome.into_solution()
+    })?;
+    if puzzle.is_goal() {
+        return Ok(());
+    }
+    let outcome = puzzle::solve_with_config(puzzle, &SolverConfig::new().max_nodes(1));
+    if !matches!(
+        outcome,
+        SolveOutcome::Limit(SolveError::NodeLimit { states: 1 })
+    ) {
+        return Err(format!(
+            "{file_stem} node-limited outcome is {outcome:?}.",
+            file_stem = file_stem,
+            outcome = outcome
+        ));
+    }
+    // without a solution, a depth-limited search proves nothing
+    let outcome = puzzle::solve_with_config(puzzle, &SolverConfig::new().max_depth(0));
+    if !matches!(outcome, SolveOutcome::Inconclusive { states_explored: 1 }) {
+        return Err(format!(
+            "{file_stem} depth-limited outcome is {outcome:?}.",
+            file_stem = file_stem,
+            outcome = outcome
+        ));
+    }
+    Ok(())
+}
+
+fn stepping_solver_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let mut solver = Solver::new(puzzle);
+    while !solver.step_n(7) {
+        let depths: Vec<usize> = solver.frontier().map(|(_, d)| d).collect();
+        if depths.windows(2).any(|w| w[0] > w[1])
+            || depths.last() > depths.first().map(|d| d + 1).as_ref()
+        {
+            return Err(format!(
+                "{file_stem} stepping solver frontier depths ({depths:?}) are not of (at most) two successive layers.",
+                file_stem = file_stem,
+                depths = depths
+            ));
+        }
+    }
+    let stats = solver.stats();
+    let (_, expected) = puzzle::solve_with_stats(puzzle, &SolverConfig::new());
+    if (stats.expanded, stats.generated, stats.visited, stats.depth)
+        != (
+            expected.expanded,
+            expected.generated,
+            expected.visited,
+            expected.depth,
+        )
+    {
+        return Err(format!(
+            "{file_stem} stepping solver stats ({stats:?}) differ from BFS stats ({expected:?}).",
+            file_stem = file_stem,
+            stats = stats,
+            expected = expected
+        ));
+    }
+    optimal_solve_test(file_stem, puzzle, soln.clone(), "stepping solver", |_| {
+        solver.into_solution().unwrap()
+    })?;
+    let mut solver = Solver::new(puzzle);
+    let mut depth = 0;
+    while !solver.step_layer() {
+        depth += 1;
+        if solver.depth() != Some(depth) {
+            return Err(format!(
+                "{file_stem} stepping solver depth after {depth} layers is {solver_depth:?}.",
+                file_stem = file_stem,
+                depth = depth,
+                solver_depth = solver.depth()
+            ));
+        }
+    }
+    optimal_solve_test(file_stem, puzzle, soln, "layer-stepping solver", |_| {
+        solver.into_solution().unwrap()
+    })
+}
+
+fn event_stream_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut events = Events::new(
+        |p: &JumpIN| p.rank(),
+        move |event| {
+            let _ = sender.send(event);
+        },
+    );
+    let depth = soln.as_ref().map(|(mvs_soln, _)| mvs_soln.len());
+    optimal_solve_test(file_stem, puzzle, soln, "BFS with events", |p| {
+        puzzle::solve_observed(p, &SolverConfig::new(), &mut events)
+            .into_result()
+            .unwrap_or_else(|err| panic!("{}", err))
+    })?;
+    drop(events);
+    let (_, stats) = puzzle::solve_with_stats(puzzle, &SolverConfig::new());
+    // the depth of each reached puzzle state, by key
+    let mut depths = std::collections::HashMap::from([(puzzle.rank(), 0)]);
+    let (mut expanded, mut goal) = (0, None);
+    for event in receiver {
+        let ok = match event {
+            SearchEvent::Expanded { key, depth } => {
+                expanded += 1;
+                depths.get(&key) == Some(&depth)
+            }
+            SearchEvent::Discovered { key, parent, depth } => {
+                depths.get(&parent) == Some(&(depth - 1)) && depths.insert(key, depth).is_none()
+            }
+            SearchEvent::GoalFound { key, depth } => {
+                goal = Some(depth);
+                depths.get(&key) == Some(&depth)
+            }
+        };
+        if !ok {
+            return Err(format!(
+                "{file_stem} event {event:?} is inconsistent with the earlier events.",
+                file_stem = file_stem,
+                event = event
+            ));
+        }
+    }
+    if expanded != stats.expanded || depths.len() != stats.visited || goal != depth {
+        return Err(format!(
+            "{file_stem} events have {expanded} expansions, {states} states, and goal depth {goal:?} (expected {stats:?}).",
+            file_stem = file_stem,
+            expanded = expanded,
+            states = depths.len(),
+            goal = goal,
+            stats = stats
+        ));
+    }
+    Ok(())
+}
+
+fn layer_stats_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let (_, stats) = puzzle::solve_with_stats(puzzle, &SolverConfig::new());
+    if !stats.layers.is_empty() {
+        return Err(format!(
+            "{file_stem} BFS stats has layer stats ({layers:?}) that were not asked for.",
+            file_stem = file_stem,
+            layers = stats.layers
+        ));
+    }
+    let (_, stats) = puzzle::solve_with_stats(puzzle, &SolverConfig::new().layer_stats(true));
+    let layers = &stats.layers;
+    let sum = |f: fn(&LayerStats) -> usize| layers.iter().map(f).sum::<usize>();
+    let sums = (
+        sum(|l| l.states),
+        sum(|l| l.expanded),
+        sum(|l| l.generated),
+        sum(|l| l.duplicates),
+    );
+    let totals = (
+        stats.visited,
+        stats.expanded,
+        stats.generated,
+        stats.duplicates,
+    );
+    if sums != totals {
+        return Err(format!(
+            "{file_stem} BFS layer stats sums ({sums:?}) differ from the totals ({totals:?}).",
+            file_stem = file_stem,
+            sums = sums,
+            totals = totals
+        ));
+    }
+    if let Some((i, layer)) = layers
+        .iter()
+        .enumerate()
+        .skip(1)
+        .find(|(i, layer)| layer.states != layers[i - 1].new_states())
+    {
+        return Err(format!(
+            "{file_stem} BFS layer {i} has {states} states, but layer {prev} had {new} new successors.",
+            file_stem = file_stem,
+            i = i,
+            states = layer.states,
+            prev = i - 1,
+            new = layers[i - 1].new_states()
+        ));
+    }
+    if let Some((ms, _)) = soln {
+        if layers.get(ms.len()).is_none_or(|layer| layer.states == 0) {
+            return Err(format!(
+                "{file_stem} BFS layer stats ({layers:?}) has no states {depth} moves from the initial state.",
+                file_stem = file_stem,
+                layers = layers,
+                depth = ms.len()
+            ));
+        }
+    }
+    let mut solver = Solver::new(puzzle);
+    while !solver.step_n(100) {}
+    if solver.stats().layers != *layers {
+        return Err(format!(
+            "{file_stem} stepping solver layer stats ({solver:?}) differ from BFS layer stats ({layers:?}).",
+            file_stem = file_stem,
+            solver = solver.stats().layers,
+            layers = layers
+        ));
+    }
+    Ok(())
+}
+
+fn explain_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let report = match puzzle::solve_or_explain_by(puzzle, |p| p.estimate()) {
+        Ok(soln) => Some(soln),
+        Err(report) => {
+            let (_, stats) =
+                puzzle::solve_with_stats(puzzle, &SolverConfig::new().layer_stats(true));
+            let max_depth = stats.layers.iter().rposition(|layer| layer.states > 0);
+            let closest = match &report.closest {
+                Some(c) => c,
+                None => {
+                    return Err(format!(
+                        "{file_stem} has no closest state.",
+                        file_stem = file_stem
+                    ))
+                }
+            };
+            if (report.states, Some(report.max_depth)) != (stats.visited, max_depth)
+                || closest.distance != closest.state.estimate()
+                || closest.distance == 0
+                || closest.depth > report.max_depth
+            {
+                return Err(format!(
+                    "{file_stem} unsolvable report is {report:?}, but BFS visited {visited} states to a depth of {max_depth:?}.",
+                    file_stem = file_stem,
+                    report = report,
+                    visited = stats.visited,
+                    max_depth = max_depth
+                ));
+            }
+            if puzzle::solve_or_explain(puzzle).map_err(|r| (r.states, r.max_depth, r.closest))
+                != Err((report.states, report.max_depth, None))
+            {
+                return Err(format!(
+                    "{file_stem} unsolvable report without a metric differs from {report:?}.",
+                    file_stem = file_stem,
+                    report = report
+                ));
+            }
+            None
+        }
+    };
+    optimal_solve_test(file_stem, puzzle, soln, "BFS or explain", |_| report)
+}
+
+/// A logger that records the messages of the `puzzle` events emitted on each
+/// thread that is capturing them (see `log_events_test`); the events of the
+/// other tests, which run on other threads, are ignored.
+struct CapturingLogger;
+
+thread_local! {
+    static CAPTURED: std::cell::RefCell<Option<Vec<(log::Level, String)>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.target() == "puzzle"
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            CAPTURED.with(|captured| {
+                if let Some(events) = captured.borrow_mut().as_mut() {
+                    events.push((record.level(), record.args().to_string()));
+                }
+            })
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// The `puzzle` events emitted (on this thread) by `f`.
+fn capture_events(f: impl FnOnce()) -> Vec<(log::Level, String)> {
+    static LOGGER: CapturingLogger = CapturingLogger;
+    static INIT: std::sync::Once = std::sync::Once::new();
+    INIT.call_once(|| {
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+    });
+    CAPTURED.with(|captured| *captured.borrow_mut() = Some(vec![]));
+    f();
+    CAPTURED.with(|captured| captured.borrow_mut().take().unwrap())
+}
+
+fn log_events_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let mut result = None;
+    let events = capture_events(|| result = Some(puzzle::solve(puzzle)));
+    let last = match (&soln, &result) {
+        (Some((ms, _)), Some(Some(_))) => format!("bfs: solved in {} moves", ms.len()),
+        _ => "bfs: exhausted without a goal state".to_string(),
+    };
+    let expansions = events
+        .iter()
+        .filter(|(level, msg)| *level == log::Level::Trace && msg.starts_with("bfs: expanding"))
+        .count();
+    let (_, stats) = puzzle::solve_with_stats(puzzle, &SolverConfig::new());
+    if !events
+        .last()
+        .is_some_and(|(level, msg)| *level == log::Level::Debug && msg.starts_with(&last))
+        || expansions != stats.expanded
+    {
+        return Err(format!(
+            "{file_stem} BFS emitted {expansions} expansion events (of {expanded} expansions) and the last event {event:?} (expected {last:?}).",
+            file_stem = file_stem,
+            expansions = expansions,
+            expanded = stats.expanded,
+            event = events.last(),
+            last = last
+        ));
+    }
+    optimal_solve_test(
+        file_stem,
+        puzzle,
+        soln.clone(),
+        "BFS with log events",
+        |_| result.unwrap(),
+    )?;
+    if let Some((ms, _)) = soln {
+        let events = capture_events(|| {
+            puzzle::check(puzzle, &ms);
+        });
+        let checked = events
+            .iter()
+            .filter(|(level, msg)| *level == log::Level::Trace && msg.ends_with("is legal"))
+            .count();
+        let goal = format!("check: {} moves reach a goal state", ms.len());
+        if checked != ms.len() || events.last().map(|(_, msg)| msg) != Some(&goal) {
+            return Err(format!(
+                "{file_stem} check emitted the events {events:?}.",
+                file_stem = file_stem,
+                events = events
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn legal_moves_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let mut states = vec![puzzle];
+    if let Some((mvs, _)) = &soln {
+        for m in mvs {
+            states.push(states[states.len() - 1].apply_move(m));
+        }
+    }
+    for p in states {
+        let (mvs, next): (Vec<_>, Vec<_>) = p.successors().unzip();
+        let legal = p.legal_moves();
+        if legal != mvs || mvs.iter().zip(&next).any(|(m, q)| p.apply_move(m) != *q) {
+            return Err(format!(
+                "{file_stem} legal moves ({legal}) should be the moves of the successors ({mvs}) of\n{p}",
+                file_stem = file_stem,
+                legal = moves_to_string(&legal),
+                mvs = moves_to_string(&mvs),
+                p = p
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn commute_test(file_stem: &str, puzzle: JumpIN) -> Result<(), String> {
+    let mvs = puzzle.legal_moves();
+    for m1 in &mvs {
+        let p1 = puzzle.apply_move(m1);
+        for m2 in mvs.iter().chain(&p1.legal_moves()) {
+            if !puzzle.commute(m1, m2) {
+                continue;
+            }
+            let p12 = p1.apply_move(m2);
+            if !puzzle.legal_moves().contains(m2)
+                || !puzzle.apply_move(m2).legal_moves().contains(m1)
+                || puzzle.apply_move(m2).apply_move(m1) != p12
+            {
+                return Err(format!(
+                    "{file_stem} moves {m1} and {m2} should not commute from\n{p}",
+                    file_stem = file_stem,
+                    m1 = moves_to_string(&[*m1]),
+                    m2 = moves_to_string(&[*m2]),
+                    p = puzzle
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn configured_solvers_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let unwrap = |r: SolveOutcome<JumpIN>| r.into_result().unwrap_or_else(|err| panic!("{}", err));
+    let depth = soln.as_ref().map(|(mvs_soln, _)| mvs_soln.len());
+    let configs = [
+        SolverConfig::new(),
+        SolverConfig::new()
+            .hasher(RandomState::new())
+            .max_depth(depth.unwrap_or(usize::MAX)),
+    ];
+    for config in &configs {
+        optimal_solve_test(file_stem, puzzle, soln.clone(), "configured A*", |p| {
+            unwrap(puzzle::solve_astar_with_config(p, config))
+        })?;
+        optimal_solve_test(
+            file_stem,
+            puzzle,
+            soln.clone(),
+            "configured layered BFS",
+            |p| unwrap(puzzle::solve_layered_with_config(p, config)),
+        )?;
+        if let Some((_, goal)) = &soln {
+            optimal_solve_test(
+                file_stem,
+                puzzle,
+                soln.clone(),
+                "configured bidirectional BFS",
+                |p| unwrap(puzzle::solve_bidirectional_with_config(p, *goal, config)),
+            )?;
+        }
+        suboptimal_solve_test(
+            file_stem,
+            puzzle,
+            soln.clone(),
+            "configured beam",
+            f64::INFINITY,
+            |p| unwrap(puzzle::solve_beam_with_config(p, 100, config)),
+        )?;
+        suboptimal_solve_test(
+            file_stem,
+            puzzle,
+            soln.clone(),
+            "configured focal",
+            1.5,
+            |p| unwrap(puzzle::solve_focal_with_config(p, 0.5, config)),
+        )?;
+        let optimal: [(&str, Unobserved); 3] = [
+            ("deterministic BFS", |p, config| {
+                puzzle::solve_deterministic_with_config(p, config)
+            }),
+            ("packed BFS", |p, config| {
+                puzzle::solve_packed_with_config(p, config)
+            }),
+            ("Zobrist BFS", |p, config| {
+                puzzle::solve_zobrist_exact_with_config(p, config)
+            }),
+        ];
+        for (solver, solve) in optimal {
+            optimal_solve_test(file_stem, puzzle, soln.clone(), solver, |p| {
+                unwrap(solve(p, config))
+            })?;
+        }
+    }
+    // the moves may cost more than one, so a horizon of moves is no bound
+    optimal_solve_test(
+        file_stem,
+        puzzle,
+        soln.clone(),
+        "configured min-cost",
+        |p| unwrap(puzzle::solve_min_cost_with_config(p, &SolverConfig::new())),
+    )?;
+    optimal_solve_test(
+        file_stem,
+        puzzle,
+        soln.clone(),
+        "scoped configured layered BFS",
+        |p| {
+            unwrap(puzzle::solve_layered_with_config(
+                p,
+                &SolverConfig::new().dedupe_scope(2),
+            ))
+        },
+    )?;
+    let (depth, goal) = match (depth, &soln) {
+        (Some(depth), Some((_, goal))) if depth > 0 => (depth, *goal),
+        _ => return Ok(()),
+    };
+    // a shortest solution is just beyond a horizon of one move fewer
+    let config = SolverConfig::new().max_depth(depth - 1);
+    let shallow = [
+        ("A*", puzzle::solve_astar_with_config(puzzle, &config)),
+        (
+            "shared BFS",
+            puzzle::solve_shared_with_config(puzzle, &config),
+        ),
+        (
+            "symmetric BFS",
+            puzzle::solve_symmetric_with_config(puzzle, &config),
+        ),
+        (
+            "layered BFS",
+            puzzle::solve_layered_with_config(puzzle, &config),
+        ),
+        (
+            "bidirectional BFS",
+            puzzle::solve_bidirectional_with_config(puzzle, goal, &config),
+        ),
+    ];
+    let config = SolverConfig::new().max_nodes(1);
+    let limited = [
+        ("A*", puzzle::solve_astar_with_config(puzzle, &config)),
+        ("greedy", puzzle::solve_greedy_with_config(puzzle, &config)),
+        ("DFS", puzzle::solve_any_with_config(puzzle, &config)),
+        (
+            "shared BFS",
+            puzzle::solve_shared_with_config(puzzle, &config),
+        ),
+        (
+            "partial-order-reduced BFS",
+            puzzle::solve_por_with_config(puzzle, &config),
+        ),
+        ("HDA*", puzzle::solve_hdastar_with_config(puzzle, &config)),
+        (
+            "layered BFS",
+            puzzle::solve_layered_with_config(puzzle, &config),
+        ),
+        (
+            "bidirectional BFS",
+            puzzle::solve_bidirectional_with_config(puzzle, goal, &config),
+        ),
+        ("beam", puzzle::solve_beam_with_config(puzzle, 100, &config)),
+    ];
+    for (solver, r) in shallow {
+        if !matches!(r, SolveOutcome::Inconclusive { .. }) {
+            return Err(format!(
+                "{file_stem} configured {solver} within {depth} moves returned {r:?}.",
+                file_stem = file_stem,
+                solver = solver,
+                depth = depth - 1,
+                r = r
+                    .into_result()
+                    .map(|soln| soln.map(|(mvs, _)| moves_to_string(&mvs)))
+            ));
+        }
+    }
+    for (solver, r) in limited {
+        if !matches!(r, SolveOutcome::Limit(SolveError::NodeLimit { .. })) {
+            return Err(format!(
+                "{file_stem} configured {solver} with a node limit returned {r:?}.",
+                file_stem = file_stem,
+                solver = solver,
+                r = r
+                    .into_result()
+                    .map(|soln| soln.map(|(mvs, _)| moves_to_string(&mvs)))
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn idastar_config_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let mut tally = Tally::default();
+    optimal_solve_test(file_stem, puzzle, soln.clone(), "configured IDA*", |p| {
+        puzzle::solve_idastar_with_config(p, &SolverConfig::new().observer(&mut tally))
+            .into_result()
+            .unwrap_or_else(|err| panic!("{}", err))
+    })?;
+    let moves = soln.as_ref().map(|(mvs_soln, _)| mvs_soln.len());
+    if tally.solution != moves || (tally.events == 0) != puzzle.is_goal() {
+        return Err(format!(
+            "{file_stem} observed configured IDA* reported {events} expansions and solution {observed:?}.",
+            file_stem = file_stem,
+            events = tally.events,
+            observed = tally.solution
+        ));
+    }
+    iterative_config_test(file_stem, puzzle, soln.clone(), "IDDFS", |p, config| {
+        puzzle::solve_iddfs_with_config(p, config)
+    })?;
+    iterative_config_test(
+        file_stem,
+        puzzle,
+        soln.clone(),
+        "mutable IDA*",
+        puzzle::solve_idastar_mut_with_config,
+    )?;
+    let upper_bound = moves.map_or(0, |moves| moves + 2);
+    iterative_config_test(
+        file_stem,
+        puzzle,
+        soln.clone(),
+        "branch-and-bound",
+        |p, config| puzzle::solve_bnb_with_heuristic_with_config(p, upper_bound, config),
+    )?;
+    let depth = match &soln {
+        Some((mvs_soln, _)) if !mvs_soln.is_empty() => mvs_soln.len(),
+        _ => return Ok(()),
+    };
+    let shallow =
+        puzzle::solve_idastar_with_config(puzzle, &SolverConfig::new().max_depth(depth - 1));
+    let limited = puzzle::solve_idastar_with_config(puzzle, &SolverConfig::new().max_nodes(1));
+    if !matches!(shallow, SolveOutcome::Inconclusive { .. })
+        || !matches!(
+            limited,
+            SolveOutcome::Limit(SolveError::NodeLimit { states: 1 })
+        )
+    {
+        return Err(format!(
+            "{file_stem} configured IDA* within {depth} moves returned {shallow:?}, and with a node limit {limited:?}.",
+            file_stem = file_stem,
+            depth = depth - 1,
+            shallow = shallow.into_result().map(|soln| soln.map(|(mvs, _)| moves_to_string(&mvs))),
+            limited = limited.into_result().map(|soln| soln.map(|(mvs, _)| moves_to_string(&mvs)))
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that a configured depth-first `solver` finds a shortest solution, and
+/// is inconclusive within a horizon of one move fewer.
+fn iterative_config_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Solution,
+    solver: &str,
+    solve: impl Fn(JumpIN, &SolverConfig) -> SolveOutcome<JumpIN>,
+) -> Result<(), String> {
+    optimal_solve_test(file_stem, puzzle, soln.clone(), solver, |p| {
+        solve(p, &SolverConfig::new())
+            .into_result()
+            .unwrap_or_else(|err| panic!("{}", err))
+    })?;
+    let depth = match &soln {
+        Some((mvs_soln, _)) if !mvs_soln.is_empty() => mvs_soln.len(),
+        _ => return Ok(()),
+    };
+    let shallow = solve(puzzle, &SolverConfig::new().max_depth(depth - 1));
+    if !matches!(shallow, SolveOutcome::Inconclusive { .. }) {
+        return Err(format!(
+            "{file_stem} configured {solver} within {depth} moves returned {shallow:?}.",
+            file_stem = file_stem,
+            solver = solver,
+            depth = depth - 1,
+            shallow = shallow
+                .into_result()
+                .map(|soln| soln.map(|(mvs, _)| moves_to_string(&mvs)))
+        ));
+    }
+    Ok(())
+}
+
+fn dfs_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    (mvs_soln, _): (Vec<<JumpIN as Puzzle>::Move>, JumpIN),
+) -> Result<(), String> {
+    match puzzle::solve_dfs(puzzle, mvs_soln.len()) {
+        None => {
+            return Err(format!(
+                "{file_stem} has no depth-limited DFS solution within {depth} moves, but reference has solution ({mvs_soln}).",
+                file_stem = file_stem,
+                depth = mvs_soln.len(),
+                mvs_soln = moves_to_string(&mvs_soln)
+            ))
+        }
+        Some((mvs, goal)) => {
+            if mvs.len() > mvs_soln.len() || puzzle::check(puzzle, &mvs) != Some(goal) {
+                return Err(format!(
+                    "{file_stem} depth-limited DFS solution ({mvs}) is not a solution of at most {depth} moves.",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs),
+                    depth = mvs_soln.len()
+                ));
+            }
+        }
+    };
+    match mvs_soln.len().checked_sub(1) {
+        Some(depth) => match puzzle::solve_dfs(puzzle, depth) {
+            None => Ok(()),
+            Some((mvs, _)) => Err(format!(
+                "{file_stem} has depth-limited DFS solution ({mvs}) that is shorter than reference solution ({mvs_soln}).",
+                file_stem = file_stem,
+                mvs = moves_to_string(&mvs),
+                mvs_soln = moves_to_string(&mvs_soln)
+            )),
+        },
+        None => Ok(()),
+    }
+}
+
+fn suboptimal_solve_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+    solver: &str,
+    factor: f64,
+    solve: impl FnOnce(JumpIN) -> Solution,
+) -> Result<(), String> {
+    match (solve(puzzle), soln) {
+        (None, None) => Ok(()),
+        (Some((mvs, _)), None) => Err(format!(
+            "{file_stem} has {solver} solution ({mvs}), but reference has no solution; likely has an invalid move and/or an incorrect `JumpIN::is_goal`.",
+            file_stem = file_stem,
+            solver = solver,
+            mvs = moves_to_string(&mvs)
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no {solver} solution, but reference has solution ({mvs}).",
+            file_stem = file_stem,
+            solver = solver,
+            mvs = moves_to_string(&mvs_soln)
+        )),
+        (Some((mvs, goal)), Some((mvs_soln, _))) => {
+            if puzzle::check(puzzle, &mvs) != Some(goal) {
+                return Err(format!(
+                    "{file_stem} {solver} solution ({mvs}) failed `puzzle::check`.",
+                    file_stem = file_stem,
+                    solver = solver,
+                    mvs = moves_to_string(&mvs)
+                ));
+            }
+            if mvs.len() as f64 > factor * mvs_soln.len() as f64 {
+                return Err(format!(
+                    "{file_stem} {solver} solution ({mvs}) is more than {factor} times as long as reference solution ({mvs_soln}).",
+                    file_stem = file_stem,
+                    solver = solver,
+                    mvs = moves_to_string(&mvs),
+                    factor = factor,
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ));
+            }
+            Ok(())
+        }
+    }
+}
+
+fn anytime_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+) -> Result<(), String> {
+    let mut lens = Vec::new();
+    let res = puzzle::solve_anytime(puzzle, 3.0, usize::MAX, |mvs, _| lens.push(mvs.len()));
+    if lens.windows(2).any(|w| w[0] <= w[1]) {
+        return Err(format!(
+            "{file_stem} anytime solutions (of lengths {lens:?}) are not successively shorter.",
+            file_stem = file_stem,
+            lens = lens
+        ));
+    }
+    match res {
+        Some((_, _, false)) => Err(format!(
+            "{file_stem} anytime solution is not optimal, despite an unlimited budget.",
+            file_stem = file_stem
+        )),
+        Some((mvs, _, true)) if lens.last() != Some(&mvs.len()) => Err(format!(
+            "{file_stem} anytime solution ({mvs}) is not the last solution found.",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs)
+        )),
+        res => optimal_solve_test(file_stem, puzzle, soln, "anytime", |_| {
+            res.map(|(mvs, goal, _)| (mvs, goal))
+        }),
+    }
+}
+
+fn min_cost_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+) -> Result<(), String> {
+    let cost =
+        |mvs: &[<JumpIN as Puzzle>::Move]| -> u32 { mvs.iter().map(|m| puzzle.move_cost(m)).sum() };
+    match (puzzle::solve_min_cost(puzzle), soln) {
+        (None, None) => Ok(()),
+        (Some((mvs, _)), None) => Err(format!(
+            "{file_stem} has min-cost solution ({mvs}), but reference has no solution; likely has an invalid move and/or an incorrect `JumpIN::is_goal`.",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs)
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no min-cost solution, but reference has solution ({mvs}).",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs_soln)
+        )),
+        (Some((mvs, goal)), Some((mvs_soln, _))) => {
+            if puzzle::check(puzzle, &mvs) != Some(goal) {
+                return Err(format!(
+                    "{file_stem} min-cost solution ({mvs}) failed `puzzle::check`.",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs)
+                ));
+            }
+            if cost(&mvs) > cost(&mvs_soln) {
+                return Err(format!(
+                    "{file_stem} min-cost solution ({mvs}) costs more than reference solution ({mvs_soln}).",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs),
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ));
+            }
+            Ok(())
+        }
+    }
+}
+
+fn solve_all_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+) -> Result<(), String> {
+    match (puzzle::solve_all(puzzle), soln) {
+        (None, None) => Ok(()),
+        (Some(_), None) => Err(format!(
+            "{file_stem} has solutions, but reference has no solution; likely has an invalid move and/or an incorrect `JumpIN::is_goal`.",
+            file_stem = file_stem
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no solutions, but reference has solution ({mvs}).",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs_soln)
+        )),
+        (Some((mvss, goal)), Some((mvs_soln, goal_soln))) => {
+            for (i, mvs) in mvss.iter().enumerate() {
+                if mvs.len() != mvs_soln.len() || puzzle::check(puzzle, mvs).is_none() {
+                    return Err(format!(
+                        "{file_stem} solution ({mvs}) is not a shortest solution.",
+                        file_stem = file_stem,
+                        mvs = moves_to_string(mvs)
+                    ));
+                }
+                if mvss[..i].contains(mvs) {
+                    return Err(format!(
+                        "{file_stem} has duplicate solution ({mvs}).",
+                        file_stem = file_stem,
+                        mvs = moves_to_string(mvs)
+                    ));
+                }
+            }
+            if !mvss.contains(&mvs_soln) {
+                return Err(format!(
+                    "{file_stem} solutions do not include reference solution ({mvs_soln}).",
+                    file_stem = file_stem,
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ));
+            }
+            if goal != goal_soln {
+                return Err(format!(
+                    "{file_stem} final gameboard of first solution does not equal reference solution ({mvs_soln}) final gameboard.",
+                    file_stem = file_stem,
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ));
+            }
+            Ok(())
+        }
+    }
+}
+
+/// A `JumpIN` puzzle in which only the rabbits are moved by the solver; after
+/// each move, the foxes may react by (at most) one move that is not under the
+/// solver's control.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct FoxReacts(JumpIN);
+
+impl NondeterministicPuzzle for FoxReacts {
+    type Move = <JumpIN as Puzzle>::Move;
+
+    fn is_goal(&self) -> bool {
+        self.0.is_goal()
+    }
+
+    fn outcomes(&self) -> Vec<(Self::Move, Vec<Self>)> {
+        self.0
+            .next()
+            .into_iter()
+            .filter(|((obj, _), _)| obj.is_rabbit())
+            .map(|(m, q)| {
+                let mut qs = vec![FoxReacts(q)];
+                if !q.is_goal() {
+                    qs.extend(
+                        q.next()
+                            .into_iter()
+                            .filter(|((obj, _), _)| obj.is_fox())
+                            .map(|(_, r)| FoxReacts(r)),
+                    );
+                }
+                (m, qs)
+            })
+            .collect()
+    }
+}
+
+fn verify_strategy(
+    p: &FoxReacts,
+    strategy: &Strategy<<JumpIN as Puzzle>::Move, FoxReacts>,
+) -> Result<(), String> {
+    match strategy {
+        Strategy::Goal if p.is_goal() => Ok(()),
+        Strategy::Goal => Err(format!("strategy stops at non-goal state\n{}", p.0)),
+        Strategy::Move(m, outcomes) => {
+            let qs = match p.outcomes().into_iter().find(|(n, _)| n == m) {
+                Some((_, qs)) => qs,
+                None => return Err(format!("strategy makes illegal move {:?}\n{}", m, p.0)),
+            };
+            if outcomes.len() != qs.len() || qs.iter().any(|q| strategy.after(q).is_none()) {
+                return Err(format!(
+                    "strategy does not cover every outcome of move {:?}\n{}",
+                    m, p.0
+                ));
+            }
+            outcomes.iter().try_for_each(|(q, s)| verify_strategy(q, s))
+        }
+    }
+}
+
+fn and_or_test(file_stem: &str, puzzle: JumpIN, soln: Solution) -> Result<(), String> {
+    let has_foxes = Pos::values().any(|pos| puzzle.get(pos).is_some_and(|obj| obj.is_fox()));
+    match (puzzle::solve_and_or(FoxReacts(puzzle)), soln) {
+        (None, None) => Ok(()),
+        (Some(_), None) => Err(format!(
+            "{file_stem} has an AND/OR strategy, but reference has no solution.",
+            file_stem = file_stem
+        )),
+        (None, Some(_)) if has_foxes => Ok(()),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no AND/OR strategy, but reference has solution ({mvs_soln}) and no foxes.",
+            file_stem = file_stem,
+            mvs_soln = moves_to_string(&mvs_soln)
+        )),
+        (Some(strategy), Some((mvs_soln, _))) => {
+            verify_strategy(&FoxReacts(puzzle), &strategy)
+                .map_err(|err| format!("{file_stem} AND/OR {err}", file_stem = file_stem, err = err))?;
+            // the foxes may never react, so a strategy is never shorter than a
+            // solution; without foxes, the puzzle is deterministic
+            if strategy.depth() < mvs_soln.len() || (!has_foxes && strategy.depth() != mvs_soln.len()) {
+                return Err(format!(
+                    "{file_stem} AND/OR strategy depth ({depth}) is inconsistent with reference solution ({mvs_soln}).",
+                    file_stem = file_stem,
+                    depth = strategy.depth(),
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ));
+            }
+            Ok(())
+        }
+    }
+}
+
+/// A `JumpIN` puzzle whose goal states are scored by the sum of the position
+/// indices of the fox heads, so that `puzzle::solve` prefers goal states with
+/// the foxes nearer the top of the gameboard.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct FoxScored(JumpIN);
+
+impl Puzzle for FoxScored {
+    type Move = <JumpIN as Puzzle>::Move;
+
+    fn is_goal(&self) -> bool {
+        self.0.is_goal()
+    }
+
+    fn legal_moves(&self) -> Vec<Self::Move> {
+        self.0.legal_moves()
+    }
+
+    fn apply_move(&self, m: &Self::Move) -> Self {
+        FoxScored(self.0.apply_move(m))
+    }
+
+    fn goal_score(&self) -> i64 {
+        Pos::values()
+            .filter(|&pos| matches!(self.0.get(pos), Some(Object::Fox1Head | Object::Fox2Head)))
+            .map(|pos| {
+                let (x, y) = pos.xy();
+                (y * 5 + x) as i64
+            })
+            .sum()
+    }
+}
+
+fn goal_score_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+) -> Result<(), String> {
+    // the least score of the goal states of all of the shortest solutions
+    let score = puzzle::solve_all(puzzle).and_then(|(mvss, _)| {
+        mvss.iter()
+            .map(|mvs| {
+                mvs.iter()
+                    .fold(FoxScored(puzzle), |p, mv| p.apply_move(mv))
+                    .goal_score()
+            })
+            .min()
+    });
+    match (puzzle::solve(FoxScored(puzzle)), soln) {
+        (None, None) => Ok(()),
+        (Some((mvs, goal)), Some((mvs_soln, _))) => {
+            if mvs.len() != mvs_soln.len() {
+                Err(format!(
+                    "{file_stem} scored solution ({mvs}) length does not equal reference solution ({mvs_soln}) length.",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs),
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ))
+            } else if Some(goal.goal_score()) != score {
+                Err(format!(
+                    "{file_stem} scored solution ({mvs}) reaches a goal state with score {goal_score}, but the least score is {score:?}.",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&mvs),
+                    goal_score = goal.goal_score(),
+                    score = score
+                ))
+            } else {
+                Ok(())
+            }
+        }
+        (Some((mvs, _)), None) => Err(format!(
+            "{file_stem} has scored solution ({mvs}), but reference has no solution.",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs)
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no scored solution, but reference has solution ({mvs_soln}).",
+            file_stem = file_stem,
+            mvs_soln = moves_to_string(&mvs_soln)
+        )),
+    }
+}
+
+fn count_optimal_solutions_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+) -> Result<(), String> {
+    let count = puzzle::count_optimal_solutions(puzzle);
+    let count_soln = puzzle::solve_all(puzzle).map(|(mvss, _)| mvss.len());
+    match (count, soln) {
+        (None, None) => Ok(()),
+        (Some(_), None) => Err(format!(
+            "{file_stem} has solutions, but reference has no solution; likely has an invalid move and/or an incorrect `JumpIN::is_goal`.",
+            file_stem = file_stem
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no solutions, but reference has solution ({mvs}).",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs_soln)
+        )),
+        (Some((len, count)), Some((mvs_soln, _))) => {
+            if len != mvs_soln.len() {
+                Err(format!(
+                    "{file_stem} shortest solution length ({len}) does not equal reference solution ({mvs_soln}) length.",
+                    file_stem = file_stem,
+                    len = len,
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ))
+            } else if Some(count) != count_soln {
+                Err(format!(
+                    "{file_stem} has {count} shortest solutions, but `puzzle::solve_all` has {count_soln:?}.",
+                    file_stem = file_stem,
+                    count = count,
+                    count_soln = count_soln
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+fn enumerate_reachable_test(
+    file_stem: &str,
+    puzzle: JumpIN,
+    soln: Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)>,
+) -> Result<(), String> {
+    let report = puzzle::enumerate_reachable(puzzle);
+    if report.layers.first() != Some(&1) || report.states != report.layers.iter().sum() {
+        return Err(format!(
+            "{file_stem} reachable states ({states}) are not the sum of layer counts ({layers:?}).",
+            file_stem = file_stem,
+            states = report.states,
+            layers = report.layers
+        ));
+    }
+    match (report.nearest_goal, soln) {
+        (None, None) => Ok(()),
+        (Some(_), None) => Err(format!(
+            "{file_stem} has reachable goal, but reference has no solution; likely has an invalid move and/or an incorrect `JumpIN::is_goal`.",
+            file_stem = file_stem
+        )),
+        (None, Some((mvs_soln, _))) => Err(format!(
+            "{file_stem} has no reachable goal, but reference has solution ({mvs}).",
+            file_stem = file_stem,
+            mvs = moves_to_string(&mvs_soln)
+        )),
+        (Some((depth, _)), Some((mvs_soln, _))) => {
+            if depth != mvs_soln.len() {
+                Err(format!(
+                    "{file_stem} nearest goal depth ({depth}) does not equal reference solution ({mvs_soln}) length.",
+                    file_stem = file_stem,
+                    depth = depth,
+                    mvs_soln = moves_to_string(&mvs_soln)
+                ))
+            } else if report.goals == 0 {
+                Err(format!(
+                    "{file_stem} has nearest goal, but no reachable goals.",
+                    file_stem = file_stem
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+fn moves_test(
+    file_stem: &str,
+    puzzle: &JumpIN,
+    move_tree: &MoveTree<<JumpIN as Puzzle>::Move, JumpIN>,
+) -> Result<(), String> {
+    match move_tree.verify::<_, _, ()>(
+        puzzle,
+        |q, q_soln| if q == q_soln { Ok(()) } else { Err(()) },
+    ) {
+        Ok(()) => Ok(()),
+        Err((ms, err)) => {
+            let state = if ms.is_empty() {
+                file_stem.to_string()
+            } else {
+                format!(
+                    "{file_stem} with moves {mvs}",
+                    file_stem = file_stem,
+                    mvs = moves_to_string(&ms)
+                )
+            };
+            match err {
+                MoveTreeVerifyError::MissingMove(m) => Err(format!(
+                    "{state}, `JumpIN::next` is missing move {m}",
+                    state = state,
+                    m = moves_to_string(&[m])
+                )),
+                MoveTreeVerifyError::DuplicateMove(m) => Err(format!(
+                    "{state}, `JumpIN::next` has duplicate move {m}",
+                    state = state,
+                    m = moves_to_string(&[m])
+                )),
+                MoveTreeVerifyError::ExtraMove(m) => Err(format!(
+                    "{state}, `JumpIN::next` has invalid move {m}",
+                    state = state,
+                    m = moves_to_string(&[m])
+                )),
+                MoveTreeVerifyError::ChkState(m, _) => Err(format!(
+                    "{state}, `JumpIN::next` includes move {m} leading to an incorrect state",
+                    state = state,
+                    m = moves_to_string(&[m])
+                )),
+            }
+        }
+    }
+}
+
+mod badinput11 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput11", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod badinput13 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput13", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod wizard50 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("wizard50", Ok(puzzle()))
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/wizard50.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/wizard50.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("wizard50", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("wizard50", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("wizard50", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("wizard50", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "wizard50",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("wizard50", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("wizard50", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("wizard50", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("wizard50", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("wizard50", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "wizard50",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "wizard50",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("wizard50", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("wizard50", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("wizard50", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("wizard50", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 62905
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/wizard50.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod starter12 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("starter12", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("starter12", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/starter12.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/starter12.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("starter12", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("starter12", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("starter12", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("starter12", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test(
+            "starter12",
+            puzzle(),
+            soln(),
+            "shared",
+            puzzle::solve_shared,
+        )
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("starter12", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("starter12", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("starter12", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("starter12", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("starter12", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("starter12", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("starter12", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("starter12", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "starter12",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "starter12",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "starter12",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("starter12", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "starter12",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "starter12",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("starter12", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("starter12", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "starter12",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("starter12", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("starter12", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("starter12", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "starter12",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "starter12",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "starter12",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("starter12", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("starter12", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("starter12", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("starter12", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("starter12", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("starter12", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("starter12", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 540
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/starter12.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod badinput07 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput07", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod badinput02 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput02", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod expert26 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("expert26", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("expert26", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/expert26.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/expert26.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("expert26", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("expert26", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("expert26", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("expert26", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test("expert26", puzzle(), soln(), "shared", puzzle::solve_shared)
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("expert26", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("expert26", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("expert26", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("expert26", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("expert26", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("expert26", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("expert26", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("expert26", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "expert26",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "expert26",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "expert26",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("expert26", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "expert26",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "expert26",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("expert26", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("expert26", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "expert26",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("expert26", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("expert26", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("expert26", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "expert26",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "expert26",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "expert26",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("expert26", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("expert26", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("expert26", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("expert26", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("expert26", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("expert26", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("expert26", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 1225
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/expert26.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod junior14 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("junior14", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("junior14", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior14.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior14.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("junior14", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("junior14", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("junior14", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("junior14", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test("junior14", puzzle(), soln(), "shared", puzzle::solve_shared)
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("junior14", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("junior14", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("junior14", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("junior14", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("junior14", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("junior14", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn goal_score() -> Result<(), String> {
+        goal_score_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn solve_k() -> Result<(), String> {
+        solve_k_test("junior14", puzzle(), soln(), 5)
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("junior14", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("junior14", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "junior14",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "junior14",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "junior14",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("junior14", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "junior14",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn product() -> Result<(), String> {
+        product_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn symmetries() -> Result<(), String> {
+        symmetries_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "junior14",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("junior14", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("junior14", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "junior14",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("junior14", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("junior14", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("junior14", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior14",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior14",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior14",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn mcts() -> Result<(), String> {
+        suboptimal_solve_test("junior14", puzzle(), soln(), "MCTS", f64::INFINITY, |p| {
+            puzzle::solve_mcts(p, 2000, 50, 1.4, 0)
+        })
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("junior14", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("junior14", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("junior14", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("junior14", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("junior14", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn idastar() -> Result<(), String> {
+        optimal_solve_test("junior14", puzzle(), soln(), "IDA*", puzzle::solve_idastar)
+    }
+    #[test]
+    fn idastar_config() -> Result<(), String> {
+        idastar_config_test("junior14", puzzle(), soln())
+    }
+    #[test]
+    fn idastar_mut() -> Result<(), String> {
+        optimal_solve_test(
+            "junior14",
+            puzzle(),
+            soln(),
+            "in-place IDA*",
+            puzzle::solve_idastar_mut,
+        )
+    }
+    #[test]
+    fn iddfs() -> Result<(), String> {
+        optimal_solve_test("junior14", puzzle(), soln(), "IDDFS", puzzle::solve_iddfs)
+    }
+    #[test]
+    fn bnb() -> Result<(), String> {
+        let upper_bound = soln().unwrap().0.len() + 2;
+        optimal_solve_test("junior14", puzzle(), soln(), "branch-and-bound", |p| {
+            puzzle::solve_bnb(p, upper_bound)
+        })?;
+        optimal_solve_test("junior14", puzzle(), soln(), "branch-and-bound", |p| {
+            puzzle::solve_bnb_with_heuristic(p, upper_bound)
+        })
+    }
+    #[test]
+    fn dfs() -> Result<(), String> {
+        dfs_test("junior14", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("junior14", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 855
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior14.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod master47 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("master47", Ok(puzzle()))
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/master47.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/master47.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("master47", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("master47", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("master47", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("master47", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "master47",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("master47", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("master47", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("master47", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("master47", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("master47", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "master47",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "master47",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("master47", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("master47", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("master47", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("master47", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 1965
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/master47.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod junior13 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("junior13", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("junior13", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior13.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior13.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("junior13", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("junior13", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("junior13", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("junior13", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test("junior13", puzzle(), soln(), "shared", puzzle::solve_shared)
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("junior13", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("junior13", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("junior13", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("junior13", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("junior13", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("junior13", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn goal_score() -> Result<(), String> {
+        goal_score_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn solve_k() -> Result<(), String> {
+        solve_k_test("junior13", puzzle(), soln(), 5)
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("junior13", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("junior13", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "junior13",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "junior13",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "junior13",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("junior13", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "junior13",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn product() -> Result<(), String> {
+        product_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn symmetries() -> Result<(), String> {
+        symmetries_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "junior13",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("junior13", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("junior13", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "junior13",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("junior13", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("junior13", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("junior13", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior13",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior13",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior13",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn mcts() -> Result<(), String> {
+        suboptimal_solve_test("junior13", puzzle(), soln(), "MCTS", f64::INFINITY, |p| {
+            puzzle::solve_mcts(p, 2000, 50, 1.4, 0)
+        })
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("junior13", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("junior13", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("junior13", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("junior13", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("junior13", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn idastar() -> Result<(), String> {
+        optimal_solve_test("junior13", puzzle(), soln(), "IDA*", puzzle::solve_idastar)
+    }
+    #[test]
+    fn idastar_config() -> Result<(), String> {
+        idastar_config_test("junior13", puzzle(), soln())
+    }
+    #[test]
+    fn idastar_mut() -> Result<(), String> {
+        optimal_solve_test(
+            "junior13",
+            puzzle(),
+            soln(),
+            "in-place IDA*",
+            puzzle::solve_idastar_mut,
+        )
+    }
+    #[test]
+    fn iddfs() -> Result<(), String> {
+        optimal_solve_test("junior13", puzzle(), soln(), "IDDFS", puzzle::solve_iddfs)
+    }
+    #[test]
+    fn bnb() -> Result<(), String> {
+        let upper_bound = soln().unwrap().0.len() + 2;
+        optimal_solve_test("junior13", puzzle(), soln(), "branch-and-bound", |p| {
+            puzzle::solve_bnb(p, upper_bound)
+        })?;
+        optimal_solve_test("junior13", puzzle(), soln(), "branch-and-bound", |p| {
+            puzzle::solve_bnb_with_heuristic(p, upper_bound)
+        })
+    }
+    #[test]
+    fn dfs() -> Result<(), String> {
+        dfs_test("junior13", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("junior13", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 266
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior13.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod badinput10 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput10", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod expert36 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("expert36", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("expert36", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/expert36.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/expert36.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("expert36", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("expert36", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("expert36", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("expert36", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test("expert36", puzzle(), soln(), "shared", puzzle::solve_shared)
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("expert36", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("expert36", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("expert36", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("expert36", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("expert36", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("expert36", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("expert36", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("expert36", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "expert36",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "expert36",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "expert36",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("expert36", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "expert36",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "expert36",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("expert36", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("expert36", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "expert36",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("expert36", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("expert36", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("expert36", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "expert36",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "expert36",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "expert36",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("expert36", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("expert36", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("expert36", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("expert36", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("expert36", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("expert36", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("expert36", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 634
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/expert36.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod trivial01 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("trivial01", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("trivial01", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/trivial01.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/trivial01.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("trivial01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("trivial01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("trivial01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("trivial01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial01",
+            puzzle(),
+            soln(),
+            "shared",
+            puzzle::solve_shared,
+        )
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("trivial01", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("trivial01", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("trivial01", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("trivial01", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("trivial01", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("trivial01", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn goal_score() -> Result<(), String> {
+        goal_score_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_k() -> Result<(), String> {
+        solve_k_test("trivial01", puzzle(), soln(), 5)
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("trivial01", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("trivial01", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial01",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial01",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial01",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("trivial01", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial01",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn product() -> Result<(), String> {
+        product_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn symmetries() -> Result<(), String> {
+        symmetries_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial01",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("trivial01", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("trivial01", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial01",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("trivial01", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("trivial01", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("trivial01", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "trivial01",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "trivial01",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "trivial01",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn mcts() -> Result<(), String> {
+        suboptimal_solve_test("trivial01", puzzle(), soln(), "MCTS", f64::INFINITY, |p| {
+            puzzle::solve_mcts(p, 2000, 50, 1.4, 0)
+        })
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("trivial01", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("trivial01", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("trivial01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("trivial01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("trivial01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn idastar() -> Result<(), String> {
+        optimal_solve_test("trivial01", puzzle(), soln(), "IDA*", puzzle::solve_idastar)
+    }
+    #[test]
+    fn idastar_config() -> Result<(), String> {
+        idastar_config_test("trivial01", puzzle(), soln())
+    }
+    #[test]
+    fn idastar_mut() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial01",
+            puzzle(),
+            soln(),
+            "in-place IDA*",
+            puzzle::solve_idastar_mut,
+        )
+    }
+    #[test]
+    fn iddfs() -> Result<(), String> {
+        optimal_solve_test("trivial01", puzzle(), soln(), "IDDFS", puzzle::solve_iddfs)
+    }
+    #[test]
+    fn bnb() -> Result<(), String> {
+        let upper_bound = soln().unwrap().0.len() + 2;
+        optimal_solve_test("trivial01", puzzle(), soln(), "branch-and-bound", |p| {
+            puzzle::solve_bnb(p, upper_bound)
+        })?;
+        optimal_solve_test("trivial01", puzzle(), soln(), "branch-and-bound", |p| {
+            puzzle::solve_bnb_with_heuristic(p, upper_bound)
+        })
+    }
+    #[test]
+    fn dfs() -> Result<(), String> {
+        dfs_test("trivial01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("trivial01", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 0
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/trivial01.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod badinput01 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput01", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod badinput06 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput06", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod badinput14 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput14", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod starter11 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("starter11", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("starter11", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/starter11.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/starter11.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("starter11", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("starter11", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("starter11", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("starter11", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test(
+            "starter11",
+            puzzle(),
+            soln(),
+            "shared",
+            puzzle::solve_shared,
+        )
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("starter11", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("starter11", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("starter11", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("starter11", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("starter11", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("starter11", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("starter11", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("starter11", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "starter11",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "starter11",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "starter11",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("starter11", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "starter11",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "starter11",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("starter11", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("starter11", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "starter11",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("starter11", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("starter11", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("starter11", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "starter11",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "starter11",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "starter11",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("starter11", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("starter11", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("starter11", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("starter11", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("starter11", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("starter11", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("starter11", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 256
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/starter11.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod badinput04 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput04", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod badinput15 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput15", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod junior24 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("junior24", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("junior24", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior24.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior24.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("junior24", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("junior24", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("junior24", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("junior24", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test("junior24", puzzle(), soln(), "shared", puzzle::solve_shared)
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("junior24", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("junior24", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("junior24", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("junior24", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("junior24", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("junior24", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("junior24", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("junior24", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "junior24",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "junior24",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "junior24",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("junior24", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "junior24",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "junior24",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("junior24", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("junior24", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "junior24",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("junior24", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("junior24", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("junior24", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior24",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior24",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior24",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("junior24", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("junior24", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("junior24", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("junior24", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("junior24", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("junior24", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("junior24", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 9553
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior24.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod master38 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("master38", Ok(puzzle()))
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/master38.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/master38.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("master38", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("master38", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("master38", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("master38", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "master38",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("master38", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("master38", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("master38", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("master38", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("master38", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "master38",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "master38",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("master38", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("master38", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("master38", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("master38", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 5057
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/master38.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod wizard59 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("wizard59", Ok(puzzle()))
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/wizard59.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/wizard59.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("wizard59", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("wizard59", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("wizard59", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("wizard59", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "wizard59",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("wizard59", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("wizard59", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("wizard59", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("wizard59", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("wizard59", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "wizard59",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "wizard59",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("wizard59", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("wizard59", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("wizard59", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("wizard59", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 5826
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/wizard59.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod badinput16 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput16", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod badinput12 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput12", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod expert35 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("expert35", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("expert35", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/expert35.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/expert35.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("expert35", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("expert35", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("expert35", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("expert35", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test("expert35", puzzle(), soln(), "shared", puzzle::solve_shared)
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("expert35", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("expert35", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("expert35", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("expert35", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("expert35", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("expert35", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("expert35", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("expert35", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "expert35",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "expert35",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "expert35",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("expert35", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "expert35",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "expert35",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("expert35", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("expert35", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "expert35",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("expert35", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("expert35", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("expert35", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "expert35",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "expert35",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "expert35",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("expert35", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("expert35", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("expert35", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("expert35", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("expert35", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("expert35", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("expert35", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 1586
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/expert35.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod badinput05 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput05", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod wizard60 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("wizard60", Ok(puzzle()))
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/wizard60.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/wizard60.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("wizard60", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("wizard60", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("wizard60", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("wizard60", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "wizard60",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("wizard60", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("wizard60", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("wizard60", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("wizard60", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("wizard60", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "wizard60",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "wizard60",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("wizard60", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("wizard60", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("wizard60", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("wizard60", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 81
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/wizard60.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod badinput09 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput09", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod starter01 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("starter01", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("starter01", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/starter01.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/starter01.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("starter01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("starter01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("starter01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("starter01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test(
+            "starter01",
+            puzzle(),
+            soln(),
+            "shared",
+            puzzle::solve_shared,
+        )
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("starter01", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("starter01", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("starter01", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("starter01", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("starter01", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("starter01", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn goal_score() -> Result<(), String> {
+        goal_score_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_k() -> Result<(), String> {
+        solve_k_test("starter01", puzzle(), soln(), 5)
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("starter01", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("starter01", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "starter01",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "starter01",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "starter01",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("starter01", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "starter01",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn product() -> Result<(), String> {
+        product_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn symmetries() -> Result<(), String> {
+        symmetries_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "starter01",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("starter01", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("starter01", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "starter01",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("starter01", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("starter01", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("starter01", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "starter01",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "starter01",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "starter01",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn mcts() -> Result<(), String> {
+        suboptimal_solve_test("starter01", puzzle(), soln(), "MCTS", f64::INFINITY, |p| {
+            puzzle::solve_mcts(p, 2000, 50, 1.4, 0)
+        })
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("starter01", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("starter01", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("starter01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("starter01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("starter01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn idastar() -> Result<(), String> {
+        optimal_solve_test("starter01", puzzle(), soln(), "IDA*", puzzle::solve_idastar)
+    }
+    #[test]
+    fn idastar_config() -> Result<(), String> {
+        idastar_config_test("starter01", puzzle(), soln())
+    }
+    #[test]
+    fn idastar_mut() -> Result<(), String> {
+        optimal_solve_test(
+            "starter01",
+            puzzle(),
+            soln(),
+            "in-place IDA*",
+            puzzle::solve_idastar_mut,
+        )
+    }
+    #[test]
+    fn iddfs() -> Result<(), String> {
+        optimal_solve_test("starter01", puzzle(), soln(), "IDDFS", puzzle::solve_iddfs)
+    }
+    #[test]
+    fn bnb() -> Result<(), String> {
+        let upper_bound = soln().unwrap().0.len() + 2;
+        optimal_solve_test("starter01", puzzle(), soln(), "branch-and-bound", |p| {
+            puzzle::solve_bnb(p, upper_bound)
+        })?;
+        optimal_solve_test("starter01", puzzle(), soln(), "branch-and-bound", |p| {
+            puzzle::solve_bnb_with_heuristic(p, upper_bound)
+        })
+    }
+    #[test]
+    fn dfs() -> Result<(), String> {
+        dfs_test("starter01", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("starter01", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 29
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/starter01.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod badinput19 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("badinput19", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod junior20 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("junior20", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("junior20", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior20.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior20.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("junior20", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("junior20", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("junior20", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("junior20", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test("junior20", puzzle(), soln(), "shared", puzzle::solve_shared)
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("junior20", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("junior20", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("junior20", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("junior20", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("junior20", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("junior20", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("junior20", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("junior20", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "junior20",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "junior20",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "junior20",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("junior20", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "junior20",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "junior20",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("junior20", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("junior20", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "junior20",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("junior20", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("junior20", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("junior20", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior20",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior20",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior20",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("junior20", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("junior20", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("junior20", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("junior20", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("junior20", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("junior20", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("junior20", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 386625
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior20.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod master37 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("master37", Ok(puzzle()))
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/master37.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/master37.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("master37", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("master37", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("master37", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("master37", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "master37",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("master37", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("master37", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("master37", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("master37", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("master37", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "master37",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "master37",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("master37", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("master37", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("master37", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("master37", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 4956
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/master37.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod master48 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("master48", Ok(puzzle()))
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/master48.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/master48.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("master48", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("master48", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("master48", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("master48", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "master48",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("master48", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("master48", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("master48", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("master48", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("master48", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "master48",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "master48",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("master48", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("master48", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("master48", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("master48", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 1605
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/master48.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod wizard49 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("wizard49", Ok(puzzle()))
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/wizard49.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/wizard49.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("wizard49", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("wizard49", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("wizard49", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("wizard49", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "wizard49",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("wizard49", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("wizard49", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("wizard49", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("wizard49", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("wizard49", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "wizard49",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "wizard49",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("wizard49", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("wizard49", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("wizard49", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("wizard49", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 1378
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/wizard49.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod junior23 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("junior23", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("junior23", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior23.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior23.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("junior23", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("junior23", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("junior23", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("junior23", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test("junior23", puzzle(), soln(), "shared", puzzle::solve_shared)
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("junior23", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("junior23", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("junior23", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("junior23", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("junior23", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("junior23", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("junior23", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("junior23", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "junior23",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "junior23",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "junior23",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("junior23", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "junior23",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "junior23",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("junior23", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("junior23", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "junior23",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("junior23", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("junior23", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("junior23", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior23",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior23",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "junior23",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("junior23", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("junior23", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("junior23", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("junior23", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("junior23", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("junior23", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn moves() -> Result<(), String> {
+        moves_test("junior23", &puzzle(), &move_tree())
+    }
+    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
+        // move_tree.size() = 2769
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/junior23.mt.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+}
+mod starter02 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("starter02", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("starter02", puzzle(), soln())
+    }
+    fn puzzle() -> JumpIN {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/starter02.puz.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
+        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
+            fs::File::open("./assets/btc/starter02.soln.btc.z").unwrap(),
+        ))
+        .unwrap()
+    }
+    #[test]
+    fn check() -> Result<(), String> {
+        check_test("starter02", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("starter02", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("starter02", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("starter02", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn solve() -> Result<(), String> {
+        solve_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test(
+            "starter02",
+            puzzle(),
+            soln(),
+            "shared",
+            puzzle::solve_shared,
+        )
+    }
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("starter02", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
+    }
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("starter02", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("starter02", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("starter02", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("starter02", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("starter02", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn goal_score() -> Result<(), String> {
+        goal_score_test("starter02", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("starter12", puzzle(), soln())
+    fn solve_k() -> Result<(), String> {
+        solve_k_test("starter02", puzzle(), soln(), 5)
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("starter02", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("starter02", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "starter02",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "starter02",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "starter02",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("starter02", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "starter02",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn product() -> Result<(), String> {
+        product_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn symmetries() -> Result<(), String> {
+        symmetries_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "starter02",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("starter02", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("starter02", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "starter02",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("starter02", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("starter02", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("starter02", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "starter02",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
+    }
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "starter02",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
+    }
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "starter02",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn mcts() -> Result<(), String> {
+        suboptimal_solve_test("starter02", puzzle(), soln(), "MCTS", f64::INFINITY, |p| {
+            puzzle::solve_mcts(p, 2000, 50, 1.4, 0)
+        })
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("starter02", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("starter02", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
+    }
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("starter02", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("starter02", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("starter02", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn idastar() -> Result<(), String> {
+        optimal_solve_test("starter02", puzzle(), soln(), "IDA*", puzzle::solve_idastar)
+    }
+    #[test]
+    fn idastar_config() -> Result<(), String> {
+        idastar_config_test("starter02", puzzle(), soln())
+    }
+    #[test]
+    fn idastar_mut() -> Result<(), String> {
+        optimal_solve_test(
+            "starter02",
+            puzzle(),
+            soln(),
+            "in-place IDA*",
+            puzzle::solve_idastar_mut,
+        )
+    }
+    #[test]
+    fn iddfs() -> Result<(), String> {
+        optimal_solve_test("starter02", puzzle(), soln(), "IDDFS", puzzle::solve_iddfs)
+    }
+    #[test]
+    fn bnb() -> Result<(), String> {
+        let upper_bound = soln().unwrap().0.len() + 2;
+        optimal_solve_test("starter02", puzzle(), soln(), "branch-and-bound", |p| {
+            puzzle::solve_bnb(p, upper_bound)
+        })?;
+        optimal_solve_test("starter02", puzzle(), soln(), "branch-and-bound", |p| {
+            puzzle::solve_bnb_with_heuristic(p, upper_bound)
+        })
+    }
+    #[test]
+    fn dfs() -> Result<(), String> {
+        dfs_test("starter02", puzzle(), soln().unwrap())
     }
     #[test]
     fn moves() -> Result<(), String> {
-        moves_test("starter12", &puzzle(), &move_tree())
+        moves_test("starter02", &puzzle(), &move_tree())
     }
     fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 540
+        // move_tree.size() = 783
         bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/starter12.mt.btc.z").unwrap(),
+            fs::File::open("./assets/btc/starter02.mt.btc.z").unwrap(),
         ))
         .unwrap()
     }
 }
-mod badinput07 {
+mod badinput03 {
     use super::*;
 
     #[test]
     fn parse() -> Result<(), String> {
-        parse_test("badinput07", parse_res())
+        parse_test("badinput03", parse_res())
     }
     fn parse_res() -> Result<JumpIN, JumpINParseError> {
         Err(JumpINParseError)
     }
 }
-mod badinput02 {
+mod badinput18 {
     use super::*;
 
     #[test]
     fn parse() -> Result<(), String> {
-        parse_test("badinput02", parse_res())
+        parse_test("badinput18", parse_res())
     }
     fn parse_res() -> Result<JumpIN, JumpINParseError> {
         Err(JumpINParseError)
     }
 }
-mod expert26 {
+mod impossible {
     use super::*;
 
     #[test]
     fn parse() -> Result<(), String> {
-        parse_test("expert26", Ok(puzzle()))
+        parse_test("impossible", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("impossible", puzzle(), soln())
     }
     fn puzzle() -> JumpIN {
         bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/expert26.puz.btc.z").unwrap(),
+            fs::File::open("./assets/btc/impossible.puz.btc.z").unwrap(),
         ))
         .unwrap()
     }
     fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
         bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/expert26.soln.btc.z").unwrap(),
+            fs::File::open("./assets/btc/impossible.soln.btc.z").unwrap(),
         ))
         .unwrap()
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("expert26", puzzle(), soln().unwrap())
+    fn solve() -> Result<(), String> {
+        solve_test("impossible", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("expert26", puzzle(), soln())
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("impossible", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("expert26", &puzzle(), &move_tree())
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("impossible", puzzle(), soln())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 1225
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/expert26.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test(
+            "impossible",
+            puzzle(),
+            soln(),
+            "shared",
+            puzzle::solve_shared,
+        )
     }
-}
-mod junior14 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("junior14", Ok(puzzle()))
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("impossible", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior14.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("impossible", puzzle())
+    }
+    #[test]
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn observer() -> Result<(), String> {
+        observer_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn log_events() -> Result<(), String> {
+        log_events_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn commute() -> Result<(), String> {
+        commute_test("impossible", puzzle())
+    }
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("impossible", puzzle())
+    }
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("impossible", puzzle())
+    }
+    #[test]
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("impossible", puzzle())
+    }
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn packed() -> Result<(), String> {
+        packed_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn ranked() -> Result<(), String> {
+        ranked_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn solve_k() -> Result<(), String> {
+        solve_k_test("impossible", puzzle(), soln(), 5)
+    }
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("impossible", puzzle())
+    }
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("impossible", puzzle())
+    }
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn mcts() -> Result<(), String> {
+        suboptimal_solve_test("impossible", puzzle(), soln(), "MCTS", f64::INFINITY, |p| {
+            puzzle::solve_mcts(p, 2000, 50, 1.4, 0)
+        })
+    }
+    #[test]
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "impossible",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
+    }
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "impossible",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
+    }
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "impossible",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
+    }
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("impossible", puzzle(), soln(), "boxed BFS", boxed_solve)
+    }
+    #[test]
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "impossible",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
+    }
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "impossible",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
+    }
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("impossible", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
+    }
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("impossible", puzzle(), soln(), "A*", puzzle::solve_astar)
+    }
+    #[test]
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "impossible",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
+    }
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("impossible", puzzle(), soln())
+    }
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("impossible", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
+    }
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("impossible", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
+    }
+    #[test]
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("impossible", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
+    }
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("impossible", puzzle(), soln())
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior14.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "impossible",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("junior14", puzzle(), soln().unwrap())
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "impossible",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("junior14", puzzle(), soln())
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "impossible",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
+    }
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("impossible", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
+    }
+    #[test]
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("impossible", puzzle(), soln())
     }
     #[test]
     fn moves() -> Result<(), String> {
-        moves_test("junior14", &puzzle(), &move_tree())
+        moves_test("impossible", &puzzle(), &move_tree())
     }
     fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 855
+        // move_tree.size() = 3675
         bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior14.mt.btc.z").unwrap(),
+            fs::File::open("./assets/btc/impossible.mt.btc.z").unwrap(),
         ))
         .unwrap()
     }
 }
-mod master47 {
+mod badinput17 {
     use super::*;
 
     #[test]
     fn parse() -> Result<(), String> {
-        parse_test("master47", Ok(puzzle()))
+        parse_test("badinput17", parse_res())
+    }
+    fn parse_res() -> Result<JumpIN, JumpINParseError> {
+        Err(JumpINParseError)
+    }
+}
+mod trivial02 {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), String> {
+        parse_test("trivial02", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("trivial02", puzzle(), soln())
     }
     fn puzzle() -> JumpIN {
         bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/master47.puz.btc.z").unwrap(),
+            fs::File::open("./assets/btc/trivial02.puz.btc.z").unwrap(),
         ))
         .unwrap()
     }
     fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
         bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/master47.soln.btc.z").unwrap(),
+            fs::File::open("./assets/btc/trivial02.soln.btc.z").unwrap(),
         ))
         .unwrap()
     }
     #[test]
     fn check() -> Result<(), String> {
-        check_test("master47", puzzle(), soln().unwrap())
+        check_test("trivial02", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("trivial02", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("trivial02", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("trivial02", puzzle(), soln().unwrap())
     }
     #[test]
     fn solve() -> Result<(), String> {
-        solve_test("master47", puzzle(), soln())
+        solve_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("master47", &puzzle(), &move_tree())
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("trivial02", puzzle(), soln())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 1965
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/master47.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("trivial02", puzzle(), soln())
     }
-}
-mod junior13 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("junior13", Ok(puzzle()))
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial02",
+            puzzle(),
+            soln(),
+            "shared",
+            puzzle::solve_shared,
+        )
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior13.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("trivial02", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior13.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("junior13", puzzle(), soln().unwrap())
+    fn perf() -> Result<(), String> {
+        perf_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("junior13", puzzle(), soln())
+    fn frontier() -> Result<(), String> {
+        frontier_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("junior13", &puzzle(), &move_tree())
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("trivial02", puzzle(), soln())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 266
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior13.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("trivial02", puzzle())
     }
-}
-mod badinput10 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput10", parse_res())
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("trivial02", puzzle(), soln())
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("trivial02", puzzle(), soln())
     }
-}
-mod expert36 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("expert36", Ok(puzzle()))
+    fn observer() -> Result<(), String> {
+        observer_test("trivial02", puzzle(), soln())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/expert36.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("trivial02", puzzle(), soln())
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/expert36.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("expert36", puzzle(), soln().unwrap())
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("expert36", puzzle(), soln())
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("expert36", &puzzle(), &move_tree())
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("trivial02", puzzle(), soln())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 634
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/expert36.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("trivial02", puzzle(), soln())
     }
-}
-mod trivial01 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("trivial01", Ok(puzzle()))
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("trivial02", puzzle(), soln())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/trivial01.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("trivial02", puzzle(), soln())
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/trivial01.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("trivial01", puzzle(), soln().unwrap())
+    fn log_events() -> Result<(), String> {
+        log_events_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("trivial01", puzzle(), soln())
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("trivial01", &puzzle(), &move_tree())
+    fn commute() -> Result<(), String> {
+        commute_test("trivial02", puzzle())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 0
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/trivial01.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("trivial02", puzzle(), soln())
     }
-}
-mod badinput01 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput01", parse_res())
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("trivial02", puzzle())
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("trivial02", puzzle(), soln())
     }
-}
-mod badinput06 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput06", parse_res())
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("trivial02", puzzle(), soln())
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("trivial02", puzzle())
     }
-}
-mod badinput14 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput14", parse_res())
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("trivial02", puzzle(), soln())
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("trivial02", puzzle())
     }
-}
-mod starter11 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("starter11", Ok(puzzle()))
+    fn external() -> Result<(), String> {
+        external_test("trivial02", puzzle(), soln())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/starter11.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn bloom() -> Result<(), String> {
+        bloom_test("trivial02", puzzle(), soln())
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/starter11.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("starter11", puzzle(), soln().unwrap())
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("starter11", puzzle(), soln())
+    fn packed() -> Result<(), String> {
+        packed_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("starter11", &puzzle(), &move_tree())
+    fn ranked() -> Result<(), String> {
+        ranked_test("trivial02", puzzle(), soln())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 256
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/starter11.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("trivial02", puzzle(), soln())
     }
-}
-mod badinput04 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput04", parse_res())
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("trivial02", puzzle(), soln())
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn goal_score() -> Result<(), String> {
+        goal_score_test("trivial02", puzzle(), soln())
     }
-}
-mod badinput15 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput15", parse_res())
+    fn solve_k() -> Result<(), String> {
+        solve_k_test("trivial02", puzzle(), soln(), 5)
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("trivial02", puzzle(), soln())
     }
-}
-mod junior24 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("junior24", Ok(puzzle()))
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("trivial02", puzzle())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior24.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("trivial02", puzzle())
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior24.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("junior24", puzzle(), soln().unwrap())
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("junior24", puzzle(), soln())
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial02",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("junior24", &puzzle(), &move_tree())
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial02",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 9553
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior24.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn labeled() -> Result<(), String> {
+        labeled_test("trivial02", puzzle(), soln())
     }
-}
-mod master38 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("master38", Ok(puzzle()))
+    fn and_or() -> Result<(), String> {
+        and_or_test("trivial02", puzzle(), soln())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/master38.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial02",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/master38.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("trivial02", puzzle(), soln(), "boxed BFS", boxed_solve)
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("master38", puzzle(), soln().unwrap())
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial02",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("master38", puzzle(), soln())
+    fn product() -> Result<(), String> {
+        product_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("master38", &puzzle(), &move_tree())
+    fn macro_moves() -> Result<(), String> {
+        macro_test("trivial02", puzzle(), soln())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 5057
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/master38.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn symmetries() -> Result<(), String> {
+        symmetries_test("trivial02", puzzle(), soln())
     }
-}
-mod wizard59 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("wizard59", Ok(puzzle()))
+    fn seeded() -> Result<(), String> {
+        seeded_test("trivial02", puzzle(), soln())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/wizard59.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial02",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/wizard59.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("trivial02", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("wizard59", puzzle(), soln().unwrap())
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("trivial02", puzzle(), soln(), "A*", puzzle::solve_astar)
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("wizard59", puzzle(), soln())
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial02",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("wizard59", &puzzle(), &move_tree())
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("trivial02", puzzle(), soln())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 5826
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/wizard59.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("trivial02", puzzle(), soln())
     }
-}
-mod badinput16 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput16", parse_res())
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("trivial02", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("trivial02", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
     }
-}
-mod badinput12 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput12", parse_res())
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("trivial02", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn anytime() -> Result<(), String> {
+        anytime_test("trivial02", puzzle(), soln())
     }
-}
-mod expert35 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("expert35", Ok(puzzle()))
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "trivial02",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/expert35.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "trivial02",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/expert35.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "trivial02",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("expert35", puzzle(), soln().unwrap())
+    fn mcts() -> Result<(), String> {
+        suboptimal_solve_test("trivial02", puzzle(), soln(), "MCTS", f64::INFINITY, |p| {
+            puzzle::solve_mcts(p, 2000, 50, 1.4, 0)
+        })
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("expert35", puzzle(), soln())
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("trivial02", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("expert35", &puzzle(), &move_tree())
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("trivial02", puzzle(), soln())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 1586
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/expert35.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("trivial02", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
     }
-}
-mod badinput05 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput05", parse_res())
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("trivial02", puzzle(), soln().unwrap())
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("trivial02", puzzle(), soln().unwrap())
     }
-}
-mod wizard60 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("wizard60", Ok(puzzle()))
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("trivial02", puzzle(), soln().unwrap())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/wizard60.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn idastar() -> Result<(), String> {
+        optimal_solve_test("trivial02", puzzle(), soln(), "IDA*", puzzle::solve_idastar)
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/wizard60.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn idastar_config() -> Result<(), String> {
+        idastar_config_test("trivial02", puzzle(), soln())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("wizard60", puzzle(), soln().unwrap())
+    fn idastar_mut() -> Result<(), String> {
+        optimal_solve_test(
+            "trivial02",
+            puzzle(),
+            soln(),
+            "in-place IDA*",
+            puzzle::solve_idastar_mut,
+        )
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("wizard60", puzzle(), soln())
+    fn iddfs() -> Result<(), String> {
+        optimal_solve_test("trivial02", puzzle(), soln(), "IDDFS", puzzle::solve_iddfs)
+    }
+    #[test]
+    fn bnb() -> Result<(), String> {
+        let upper_bound = soln().unwrap().0.len() + 2;
+        optimal_solve_test("trivial02", puzzle(), soln(), "branch-and-bound", |p| {
+            puzzle::solve_bnb(p, upper_bound)
+        })?;
+        optimal_solve_test("trivial02", puzzle(), soln(), "branch-and-bound", |p| {
+            puzzle::solve_bnb_with_heuristic(p, upper_bound)
+        })
+    }
+    #[test]
+    fn dfs() -> Result<(), String> {
+        dfs_test("trivial02", puzzle(), soln().unwrap())
     }
     #[test]
     fn moves() -> Result<(), String> {
-        moves_test("wizard60", &puzzle(), &move_tree())
+        moves_test("trivial02", &puzzle(), &move_tree())
     }
     fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 81
+        // move_tree.size() = 44
         bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/wizard60.mt.btc.z").unwrap(),
+            fs::File::open("./assets/btc/trivial02.mt.btc.z").unwrap(),
         ))
         .unwrap()
     }
 }
-mod badinput09 {
+mod badinput08 {
     use super::*;
 
     #[test]
     fn parse() -> Result<(), String> {
-        parse_test("badinput09", parse_res())
+        parse_test("badinput08", parse_res())
     }
     fn parse_res() -> Result<JumpIN, JumpINParseError> {
         Err(JumpINParseError)
     }
 }
-mod starter01 {
+mod expert25 {
     use super::*;
 
     #[test]
     fn parse() -> Result<(), String> {
-        parse_test("starter01", Ok(puzzle()))
+        parse_test("expert25", Ok(puzzle()))
+    }
+    #[test]
+    fn persist() -> Result<(), String> {
+        persist_test("expert25", puzzle(), soln())
     }
     fn puzzle() -> JumpIN {
         bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/starter01.puz.btc.z").unwrap(),
+            fs::File::open("./assets/btc/expert25.puz.btc.z").unwrap(),
         ))
         .unwrap()
     }
     fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
         bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/starter01.soln.btc.z").unwrap(),
+            fs::File::open("./assets/btc/expert25.soln.btc.z").unwrap(),
         ))
         .unwrap()
     }
     #[test]
     fn check() -> Result<(), String> {
-        check_test("starter01", puzzle(), soln().unwrap())
+        check_test("expert25", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn check_steps() -> Result<(), String> {
+        check_steps_test("expert25", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn is_dead() -> Result<(), String> {
+        is_dead_test("expert25", puzzle(), soln().unwrap())
+    }
+    #[test]
+    fn apply_move() -> Result<(), String> {
+        apply_move_test("expert25", puzzle(), soln().unwrap())
     }
     #[test]
     fn solve() -> Result<(), String> {
-        solve_test("starter01", puzzle(), soln())
+        solve_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("starter01", &puzzle(), &move_tree())
+    fn solve_with_hasher() -> Result<(), String> {
+        solve_with_hasher_test("expert25", puzzle(), soln())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 29
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/starter01.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn solve_bounded() -> Result<(), String> {
+        solve_bounded_test("expert25", puzzle(), soln())
     }
-}
-mod badinput19 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput19", parse_res())
+    fn solve_shared() -> Result<(), String> {
+        optimal_solve_test("expert25", puzzle(), soln(), "shared", puzzle::solve_shared)
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn solve_with_capacity() -> Result<(), String> {
+        optimal_solve_test("expert25", puzzle(), soln(), "preallocated", |p| {
+            puzzle::solve_with_capacity(p, 1 << 12)
+        })
     }
-}
-mod junior20 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("junior20", Ok(puzzle()))
+    fn memory_stats() -> Result<(), String> {
+        memory_stats_test("expert25", puzzle(), soln())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior20.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn perf() -> Result<(), String> {
+        perf_test("expert25", puzzle(), soln())
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior20.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn frontier() -> Result<(), String> {
+        frontier_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("junior20", puzzle(), soln().unwrap())
+    fn closed_set() -> Result<(), String> {
+        closed_set_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("junior20", puzzle(), soln())
+    fn next_batch() -> Result<(), String> {
+        next_batch_test("expert25", puzzle())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("junior20", &puzzle(), &move_tree())
+    fn solver_config() -> Result<(), String> {
+        solver_config_test("expert25", puzzle(), soln())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 386625
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior20.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn search_stats() -> Result<(), String> {
+        search_stats_test("expert25", puzzle(), soln())
     }
-}
-mod master37 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("master37", Ok(puzzle()))
+    fn observer() -> Result<(), String> {
+        observer_test("expert25", puzzle(), soln())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/master37.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn cancel_token() -> Result<(), String> {
+        cancel_token_test("expert25", puzzle(), soln())
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/master37.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn timed_out() -> Result<(), String> {
+        timed_out_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("master37", puzzle(), soln().unwrap())
+    fn checkpoint() -> Result<(), String> {
+        checkpoint_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("master37", puzzle(), soln())
+    fn deterministic() -> Result<(), String> {
+        deterministic_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("master37", &puzzle(), &move_tree())
+    fn solve_outcome() -> Result<(), String> {
+        solve_outcome_test("expert25", puzzle(), soln())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 4956
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/master37.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn stepping_solver() -> Result<(), String> {
+        stepping_solver_test("expert25", puzzle(), soln())
     }
-}
-mod master48 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("master48", Ok(puzzle()))
+    fn event_stream() -> Result<(), String> {
+        event_stream_test("expert25", puzzle(), soln())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/master48.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn layer_stats() -> Result<(), String> {
+        layer_stats_test("expert25", puzzle(), soln())
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/master48.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn explain() -> Result<(), String> {
+        explain_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("master48", puzzle(), soln().unwrap())
+    fn log_events() -> Result<(), String> {
+        log_events_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("master48", puzzle(), soln())
+    fn legal_moves() -> Result<(), String> {
+        legal_moves_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("master48", &puzzle(), &move_tree())
+    fn commute() -> Result<(), String> {
+        commute_test("expert25", puzzle())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 1605
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/master48.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn configured_solvers() -> Result<(), String> {
+        configured_solvers_test("expert25", puzzle(), soln())
     }
-}
-mod wizard49 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("wizard49", Ok(puzzle()))
+    fn byte_array() -> Result<(), String> {
+        byte_array_test("expert25", puzzle())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/wizard49.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn search_graph() -> Result<(), String> {
+        search_graph_test("expert25", puzzle(), soln())
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/wizard49.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn dot_export() -> Result<(), String> {
+        dot_export_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("wizard49", puzzle(), soln().unwrap())
+    fn chunked_queue() -> Result<(), String> {
+        chunked_queue_test("expert25", puzzle())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("wizard49", puzzle(), soln())
+    fn solve_within() -> Result<(), String> {
+        solve_within_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("wizard49", &puzzle(), &move_tree())
+    fn shared_grid() -> Result<(), String> {
+        shared_grid_test("expert25", puzzle())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 1378
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/wizard49.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn external() -> Result<(), String> {
+        external_test("expert25", puzzle(), soln())
     }
-}
-mod junior23 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("junior23", Ok(puzzle()))
+    fn bloom() -> Result<(), String> {
+        bloom_test("expert25", puzzle(), soln())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior23.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn zobrist() -> Result<(), String> {
+        zobrist_test("expert25", puzzle(), soln())
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior23.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn zobrist_exact() -> Result<(), String> {
+        zobrist_exact_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("junior23", puzzle(), soln().unwrap())
+    fn packed() -> Result<(), String> {
+        packed_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("junior23", puzzle(), soln())
+    fn ranked() -> Result<(), String> {
+        ranked_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("junior23", &puzzle(), &move_tree())
+    fn solve_all() -> Result<(), String> {
+        solve_all_test("expert25", puzzle(), soln())
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 2769
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/junior23.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn count_optimal_solutions() -> Result<(), String> {
+        count_optimal_solutions_test("expert25", puzzle(), soln())
     }
-}
-mod starter02 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("starter02", Ok(puzzle()))
+    fn enumerate_reachable() -> Result<(), String> {
+        enumerate_reachable_test("expert25", puzzle(), soln())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/starter02.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn estimate_tree_size() -> Result<(), String> {
+        estimate_tree_size_test("expert25", puzzle())
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/starter02.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn injected_rng() -> Result<(), String> {
+        injected_rng_test("expert25", puzzle())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("starter02", puzzle(), soln().unwrap())
+    fn solve_to_any() -> Result<(), String> {
+        solve_to_any_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("starter02", puzzle(), soln())
+    fn solve_until() -> Result<(), String> {
+        solve_until_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("starter02", &puzzle(), &move_tree())
+    fn parallel() -> Result<(), String> {
+        optimal_solve_test(
+            "expert25",
+            puzzle(),
+            soln(),
+            "parallel BFS",
+            puzzle::solve_parallel,
+        )
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 783
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/starter02.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn keyed() -> Result<(), String> {
+        optimal_solve_test(
+            "expert25",
+            puzzle(),
+            soln(),
+            "keyed BFS",
+            puzzle::solve_keyed,
+        )
     }
-}
-mod badinput03 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput03", parse_res())
+    fn labeled() -> Result<(), String> {
+        labeled_test("expert25", puzzle(), soln())
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn and_or() -> Result<(), String> {
+        and_or_test("expert25", puzzle(), soln())
     }
-}
-mod badinput18 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput18", parse_res())
+    fn por() -> Result<(), String> {
+        optimal_solve_test(
+            "expert25",
+            puzzle(),
+            soln(),
+            "partial-order-reduced BFS",
+            puzzle::solve_por,
+        )
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn boxed() -> Result<(), String> {
+        optimal_solve_test("expert25", puzzle(), soln(), "boxed BFS", boxed_solve)
     }
-}
-mod impossible {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("impossible", Ok(puzzle()))
+    fn symmetric() -> Result<(), String> {
+        optimal_solve_test(
+            "expert25",
+            puzzle(),
+            soln(),
+            "symmetric BFS",
+            puzzle::solve_symmetric,
+        )
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/impossible.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn macro_moves() -> Result<(), String> {
+        macro_test("expert25", puzzle(), soln())
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/impossible.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn seeded() -> Result<(), String> {
+        seeded_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("impossible", puzzle(), soln())
+    fn layered() -> Result<(), String> {
+        optimal_solve_test(
+            "expert25",
+            puzzle(),
+            soln(),
+            "layered BFS",
+            puzzle::solve_layered,
+        )
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("impossible", &puzzle(), &move_tree())
+    fn layered_scoped() -> Result<(), String> {
+        optimal_solve_test("expert25", puzzle(), soln(), "scoped layered BFS", |p| {
+            puzzle::solve_layered_scoped(p, 2)
+        })
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 3675
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/impossible.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn astar() -> Result<(), String> {
+        optimal_solve_test("expert25", puzzle(), soln(), "A*", puzzle::solve_astar)
     }
-}
-mod badinput17 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput17", parse_res())
+    fn astar_batched() -> Result<(), String> {
+        optimal_solve_test(
+            "expert25",
+            puzzle(),
+            soln(),
+            "batched A*",
+            puzzle::solve_astar_batched,
+        )
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn astar_certified() -> Result<(), String> {
+        astar_certified_test("expert25", puzzle(), soln())
     }
-}
-mod trivial02 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("trivial02", Ok(puzzle()))
+    fn pattern_db() -> Result<(), String> {
+        pattern_db_test("expert25", puzzle(), soln())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/trivial02.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn hdastar() -> Result<(), String> {
+        optimal_solve_test("expert25", puzzle(), soln(), "HDA*", |p| {
+            puzzle::solve_hdastar(p, 4)
+        })
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/trivial02.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn weighted_astar() -> Result<(), String> {
+        suboptimal_solve_test("expert25", puzzle(), soln(), "weighted A*", 2.0, |p| {
+            puzzle::solve_weighted_astar(p, 2.0)
+        })
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("trivial02", puzzle(), soln().unwrap())
+    fn focal() -> Result<(), String> {
+        suboptimal_solve_test("expert25", puzzle(), soln(), "focal", 1.5, |p| {
+            puzzle::solve_focal(p, 0.5)
+        })
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("trivial02", puzzle(), soln())
+    fn anytime() -> Result<(), String> {
+        anytime_test("expert25", puzzle(), soln())
     }
     #[test]
-    fn moves() -> Result<(), String> {
-        moves_test("trivial02", &puzzle(), &move_tree())
+    fn greedy() -> Result<(), String> {
+        suboptimal_solve_test(
+            "expert25",
+            puzzle(),
+            soln(),
+            "greedy best-first",
+            f64::INFINITY,
+            puzzle::solve_greedy,
+        )
     }
-    fn move_tree() -> MoveTree<<JumpIN as Puzzle>::Move, JumpIN> {
-        // move_tree.size() = 44
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/trivial02.mt.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn any() -> Result<(), String> {
+        suboptimal_solve_test(
+            "expert25",
+            puzzle(),
+            soln(),
+            "DFS",
+            f64::INFINITY,
+            puzzle::solve_any,
+        )
     }
-}
-mod badinput08 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("badinput08", parse_res())
+    fn broadening() -> Result<(), String> {
+        suboptimal_solve_test(
+            "expert25",
+            puzzle(),
+            soln(),
+            "iterative broadening",
+            f64::INFINITY,
+            puzzle::solve_broadening,
+        )
     }
-    fn parse_res() -> Result<JumpIN, JumpINParseError> {
-        Err(JumpINParseError)
+    #[test]
+    fn beam() -> Result<(), String> {
+        suboptimal_solve_test("expert25", puzzle(), soln(), "beam", f64::INFINITY, |p| {
+            puzzle::solve_beam(p, 100)
+        })
     }
-}
-mod expert25 {
-    use super::*;
-
     #[test]
-    fn parse() -> Result<(), String> {
-        parse_test("expert25", Ok(puzzle()))
+    fn min_cost() -> Result<(), String> {
+        min_cost_test("expert25", puzzle(), soln())
     }
-    fn puzzle() -> JumpIN {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/expert25.puz.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn bidirectional() -> Result<(), String> {
+        let goal = soln().unwrap().1;
+        optimal_solve_test("expert25", puzzle(), soln(), "bidirectional BFS", |p| {
+            puzzle::solve_bidirectional(p, goal)
+        })
     }
-    fn soln() -> Option<(Vec<<JumpIN as Puzzle>::Move>, JumpIN)> {
-        bincode::deserialize_from(flate2::read::ZlibDecoder::new(
-            fs::File::open("./assets/btc/expert25.soln.btc.z").unwrap(),
-        ))
-        .unwrap()
+    #[test]
+    fn meet_in_the_middle() -> Result<(), String> {
+        meet_in_the_middle_test("expert25", puzzle(), soln().unwrap())
     }
     #[test]
-    fn check() -> Result<(), String> {
-        check_test("expert25", puzzle(), soln().unwrap())
+    fn analyze_backwards() -> Result<(), String> {
+        analyze_backwards_test("expert25", puzzle(), soln().unwrap())
     }
     #[test]
-    fn solve() -> Result<(), String> {
-        solve_test("expert25", puzzle(), soln())
+    fn distance_table() -> Result<(), String> {
+        distance_table_test("expert25", puzzle(), soln().unwrap())
     }
     #[test]
     fn moves() -> Result<(), String> {
@@ -0,0 +1,178 @@
+//! A derive macro for the `Puzzle` trait of grid puzzles.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Lit, Meta, NestedMeta, Path};
+
+/// Derives `puzzle::Puzzle` for a struct with a grid of cells, whose pieces
+/// move according to the `grid_puzzle` attribute of the struct.
+///
+/// The grid must be a field, of type `[[C; W]; H]`, marked by the `#[grid]`
+/// attribute.  The `grid_puzzle` attribute takes the following (string)
+/// arguments:
+///
+/// - `empty`: the path of a function `fn(&C) -> bool` that determines whether
+///   or not a cell is empty (every other cell is a piece);
+/// - `goal`: the path of a function `fn(&Self) -> bool` that determines whether
+///   or not the puzzle state is a goal state;
+/// - `movement` (optional): `"step"`, `"slide"`, or `"jump"` (see
+///   `puzzle::Movement`), defaulting to `"step"`;
+/// - `fixed` (optional): the path of a function `fn(&C) -> bool` that
+///   determines whether or not a piece is fixed (i.e., never moves, but is
+///   still an obstacle), defaulting to no fixed pieces.
+///
+/// The moves are `puzzle::GridMove`s (see `puzzle::grid_moves`), and the struct
+/// must implement `Clone`.  For example:
+///
+/// ```ignore
+/// #[derive(Clone, PartialEq, Eq, Hash, Debug, GridPuzzle)]
+/// #[grid_puzzle(empty = "is_blank", goal = "Eight::is_sorted")]
+/// struct Eight {
+///     #[grid]
+///     tiles: [[u8; 3]; 3],
+/// }
+/// ```
+#[proc_macro_derive(GridPuzzle, attributes(grid_puzzle, grid))]
+pub fn derive_grid_puzzle(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match grid_puzzle(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// The arguments of the `grid_puzzle` attribute.
+struct Args {
+    empty: Option<Path>,
+    goal: Option<Path>,
+    movement: proc_macro2::TokenStream,
+    fixed: Option<Path>,
+}
+
+fn grid_puzzle(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let args = parse_args(&input)?;
+    let empty = args.empty.ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "GridPuzzle requires a `grid_puzzle(empty = \"...\")` argument",
+        )
+    })?;
+    let goal = args.goal.ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "GridPuzzle requires a `grid_puzzle(goal = \"...\")` argument",
+        )
+    })?;
+    let movement = args.movement;
+    let fixed = match args.fixed {
+        Some(fixed) => quote! { #fixed },
+        None => quote! { |_| false },
+    };
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "GridPuzzle can only be derived for a struct",
+            ))
+        }
+    };
+    let mut grids = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| field.attrs.iter().any(|attr| attr.path.is_ident("grid")));
+    let grid = match (grids.next(), grids.next(), fields) {
+        (Some((_, field)), None, Fields::Named(_)) => {
+            let ident = field.ident.as_ref().unwrap();
+            quote! { #ident }
+        }
+        (Some((i, _)), None, _) => {
+            let index = syn::Index::from(i);
+            quote! { #index }
+        }
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "GridPuzzle requires exactly one field marked `#[grid]`",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::puzzle::Puzzle for #name #ty_generics #where_clause {
+            type Move = ::puzzle::GridMove;
+
+            fn is_goal(&self) -> bool {
+                #goal(self)
+            }
+
+            fn legal_moves(&self) -> ::std::vec::Vec<Self::Move> {
+                ::puzzle::grid_moves(&self.#grid, #movement, #empty, #fixed)
+            }
+
+            fn apply_move(&self, m: &Self::Move) -> Self {
+                let mut p = ::std::clone::Clone::clone(self);
+                ::puzzle::grid_apply(&mut p.#grid, m, #movement, #empty);
+                p
+            }
+        }
+    })
+}
+
+fn parse_args(input: &DeriveInput) -> syn::Result<Args> {
+    let mut args = Args {
+        empty: None,
+        goal: None,
+        movement: quote! { ::puzzle::Movement::Step },
+        fixed: None,
+    };
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("grid_puzzle"))
+    {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(Error::new_spanned(meta, "expected `grid_puzzle(...)`")),
+        };
+        for nested in list.nested {
+            let nv = match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) => nv,
+                nested => return Err(Error::new_spanned(nested, "expected `name = \"...\"`")),
+            };
+            let value = match &nv.lit {
+                Lit::Str(value) => value,
+                lit => return Err(Error::new_spanned(lit, "expected a string")),
+            };
+            if nv.path.is_ident("empty") {
+                args.empty = Some(value.parse()?);
+            } else if nv.path.is_ident("goal") {
+                args.goal = Some(value.parse()?);
+            } else if nv.path.is_ident("fixed") {
+                args.fixed = Some(value.parse()?);
+            } else if nv.path.is_ident("movement") {
+                args.movement = match value.value().as_str() {
+                    "step" => quote! { ::puzzle::Movement::Step },
+                    "slide" => quote! { ::puzzle::Movement::Slide },
+                    "jump" => quote! { ::puzzle::Movement::Jump },
+                    _ => {
+                        return Err(Error::new_spanned(
+                            value,
+                            "expected \"step\", \"slide\", or \"jump\"",
+                        ))
+                    }
+                };
+            } else {
+                return Err(Error::new_spanned(
+                    nv.path,
+                    "unknown `grid_puzzle` argument",
+                ));
+            }
+        }
+    }
+    Ok(args)
+}
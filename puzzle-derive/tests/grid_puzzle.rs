@@ -0,0 +1,142 @@
+use puzzle::{GridDirection, GridMove, GridPuzzle, Puzzle};
+
+fn is_blank(tile: &u8) -> bool {
+    *tile == 0
+}
+
+/// The 8-puzzle: tiles `1` to `8` slide into the blank (`0`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, GridPuzzle)]
+#[grid_puzzle(empty = "is_blank", goal = "Eight::is_sorted")]
+struct Eight {
+    #[grid]
+    tiles: [[u8; 3]; 3],
+}
+
+impl Eight {
+    fn is_sorted(&self) -> bool {
+        self.tiles == [[1, 2, 3], [4, 5, 6], [7, 8, 0]]
+    }
+}
+
+fn is_empty(cell: &char) -> bool {
+    *cell == '.'
+}
+
+fn is_wall(cell: &char) -> bool {
+    *cell == '#'
+}
+
+/// A tuple struct whose single piece (`o`) slides until blocked by a wall (`#`)
+/// or the edge of the grid, and must reach the bottom-left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, GridPuzzle)]
+#[grid_puzzle(
+    empty = "is_empty",
+    fixed = "is_wall",
+    movement = "slide",
+    goal = "Ice::at_corner"
+)]
+struct Ice(#[grid] [[char; 4]; 4]);
+
+impl Ice {
+    fn at_corner(&self) -> bool {
+        self.0[3][0] == 'o'
+    }
+}
+
+/// Pegs (`o`) jump over pegs to empty holes (`.`); the goal is a peg in the
+/// last hole.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, GridPuzzle)]
+#[grid_puzzle(empty = "is_empty", movement = "jump", goal = "Pegs::at_end")]
+struct Pegs {
+    #[grid]
+    holes: [[char; 5]; 1],
+}
+
+impl Pegs {
+    fn at_end(&self) -> bool {
+        self.holes[0][4] == 'o'
+    }
+}
+
+#[test]
+fn step_moves() {
+    let p = Eight {
+        tiles: [[1, 2, 3], [4, 0, 6], [7, 5, 8]],
+    };
+    let moves = p.legal_moves();
+    assert_eq!(
+        moves,
+        vec![
+            GridMove {
+                row: 0,
+                col: 1,
+                dir: GridDirection::South
+            },
+            GridMove {
+                row: 1,
+                col: 0,
+                dir: GridDirection::East
+            },
+            GridMove {
+                row: 1,
+                col: 2,
+                dir: GridDirection::West
+            },
+            GridMove {
+                row: 2,
+                col: 1,
+                dir: GridDirection::North
+            },
+        ]
+    );
+    let (mvs, goal) = puzzle::solve(p.clone()).unwrap();
+    assert_eq!(mvs.len(), 2);
+    assert!(goal.is_sorted());
+    assert_eq!(puzzle::check(p, &mvs), Some(goal));
+}
+
+#[test]
+fn slide_moves() {
+    let p = Ice([
+        ['o', '.', '.', '.'],
+        ['.', '.', '#', '.'],
+        ['#', '.', '.', '.'],
+        ['.', '.', '.', '.'],
+    ]);
+    // the piece slides South to the wall, so it needs to go around it
+    let q = p.apply_move(&GridMove {
+        row: 0,
+        col: 0,
+        dir: GridDirection::South,
+    });
+    assert_eq!(q.0[1][0], 'o');
+    let (mvs, goal) = puzzle::solve(p).unwrap();
+    assert!(goal.at_corner());
+    assert_eq!(mvs.len(), 3);
+}
+
+#[test]
+fn jump_moves() {
+    let p = Pegs {
+        holes: [['o', 'o', 'o', '.', '.']],
+    };
+    // the first two pegs can jump (over one or two pegs), but not the last
+    assert_eq!(
+        p.legal_moves(),
+        vec![
+            GridMove {
+                row: 0,
+                col: 0,
+                dir: GridDirection::East
+            },
+            GridMove {
+                row: 0,
+                col: 1,
+                dir: GridDirection::East
+            },
+        ]
+    );
+    let (mvs, goal) = puzzle::solve(p).unwrap();
+    assert_eq!(mvs.len(), 2);
+    assert!(goal.at_end());
+}
@@ -0,0 +1,172 @@
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Trait for puzzles with uncertain outcomes, whose moves may lead to any one
+/// of several successor puzzle states (e.g., because an opponent, or chance,
+/// reacts to each move), and that can be solved by AND/OR search (see
+/// `solve_and_or`).
+pub trait NondeterministicPuzzle {
+    /// The type of moves for this puzzle.
+    type Move;
+
+    /// Determines whether or not the puzzle state represents a solved puzzle.
+    fn is_goal(&self) -> bool;
+
+    /// Returns the moves that can be made from the puzzle state, each with the
+    /// successor puzzle states that may result from the move; which of them
+    /// actually results is not under the solver's control.
+    ///
+    /// A move without any possible successor puzzle states is ignored.
+    fn outcomes(&self) -> Vec<(Self::Move, Vec<Self>)>
+    where
+        Self: Sized;
+}
+
+/// A strategy (contingency plan) that solves a `NondeterministicPuzzle`, as
+/// returned by `solve_and_or`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Strategy<M, P> {
+    /// The puzzle state is a goal state.
+    Goal,
+    /// Make the move, then continue with the strategy for whichever of the
+    /// possible successor puzzle states results.
+    Move(M, Vec<(P, Strategy<M, P>)>),
+}
+
+impl<M, P> Strategy<M, P> {
+    /// The number of moves of the strategy in the worst case (i.e., the
+    /// largest number of moves to a goal state over all outcomes).
+    pub fn depth(&self) -> usize {
+        match self {
+            Strategy::Goal => 0,
+            Strategy::Move(_, outcomes) => {
+                1 + outcomes.iter().map(|(_, s)| s.depth()).max().unwrap_or(0)
+            }
+        }
+    }
+
+    /// The strategy to continue with after the move of the strategy resulted
+    /// in the puzzle state `q`, if `q` is one of its possible outcomes.
+    pub fn after(&self, q: &P) -> Option<&Self>
+    where
+        P: PartialEq,
+    {
+        match self {
+            Strategy::Goal => None,
+            Strategy::Move(_, outcomes) => outcomes.iter().find(|(p, _)| p == q).map(|(_, s)| s),
+        }
+    }
+}
+
+/// Solve a nondeterministic puzzle using AND/OR search.
+///
+/// Returns `Some(s)` if puzzle `p0` can be solved by the strategy `s`; that is,
+/// if there is a move from `p0` (an OR node) every possible outcome of which
+/// (an AND node) can in turn be solved, and so on, so that a goal state is
+/// reached whatever the outcomes of the moves.  The strategy `s` should be one
+/// with the fewest number of moves in the worst case; that is, for any strategy
+/// `t` that solves `p0`, `s.depth() <= t.depth()`.
+///
+/// Returns `None` if puzzle `p0` cannot be solved (with certainty).
+///
+/// First, every puzzle state reachable from `p0` (by any outcome of any move)
+/// is explored, without expanding goal states.  Then the puzzle states are
+/// solved one round at a time, backwards from the goal states: after round
+/// `k`, exactly the puzzle states that can be solved in at most `k` moves (in
+/// the worst case) have been solved, by a move all of whose outcomes were
+/// solved in an earlier round.  Cycles of outcomes are thus never part of a
+/// strategy.  Note that this requires memory proportional to the number of
+/// reachable puzzle states, and that the returned strategy is a tree, which
+/// repeats the strategy for a puzzle state at every outcome that reaches it.
+pub fn solve_and_or<P>(p0: P) -> Option<Strategy<P::Move, P>>
+where
+    P: NondeterministicPuzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    // The reachable puzzle states, with the moves (and the indices of their
+    // outcomes) of the puzzle states that are not goal states.
+    let mut index = HashMap::new();
+    let mut states = vec![];
+    let mut edges: Vec<Vec<(P::Move, Vec<usize>)>> = vec![];
+    index.insert(p0.clone(), 0);
+    states.push(p0);
+    while edges.len() < states.len() {
+        let p = &states[edges.len()];
+        let mut ms = vec![];
+        if !p.is_goal() {
+            for (m, qs) in p.outcomes() {
+                if qs.is_empty() {
+                    continue;
+                }
+                let mut is = Vec::with_capacity(qs.len());
+                for q in qs {
+                    let i = match index.entry(q) {
+                        Vacant(e) => {
+                            let i = states.len();
+                            states.push(e.key().clone());
+                            e.insert(i);
+                            i
+                        }
+                        Occupied(e) => *e.get(),
+                    };
+                    is.push(i);
+                }
+                ms.push((m, is));
+            }
+        }
+        edges.push(ms);
+    }
+
+    // The round in which each puzzle state was solved, with the index of its
+    // move for the puzzle states that are not goal states.
+    let mut solved: Vec<Option<(usize, usize)>> = states
+        .iter()
+        .map(|p| p.is_goal().then_some((0, usize::MAX)))
+        .collect();
+    let mut round = 0;
+    while solved[0].is_none() {
+        round += 1;
+        let mut progress = false;
+        for i in 0..states.len() {
+            if solved[i].is_some() {
+                continue;
+            }
+            let choice = edges[i].iter().position(|(_, is)| {
+                is.iter()
+                    .all(|&j| matches!(solved[j], Some((k, _)) if k < round))
+            });
+            if let Some(choice) = choice {
+                solved[i] = Some((round, choice));
+                progress = true;
+            }
+        }
+        if !progress {
+            return None;
+        }
+    }
+
+    Some(strategy(0, &states, &edges, &solved))
+}
+
+/// The strategy for the (solved) puzzle state of index `i` (see
+/// `solve_and_or`).
+fn strategy<M: Clone, P: Clone>(
+    i: usize,
+    states: &[P],
+    edges: &[Vec<(M, Vec<usize>)>],
+    solved: &[Option<(usize, usize)>],
+) -> Strategy<M, P> {
+    match solved[i] {
+        Some((0, _)) => Strategy::Goal,
+        Some((_, choice)) => {
+            let (m, is) = &edges[i][choice];
+            let outcomes = is
+                .iter()
+                .map(|&j| (states[j].clone(), strategy(j, states, edges, solved)))
+                .collect();
+            Strategy::Move(m.clone(), outcomes)
+        }
+        None => unreachable!("every outcome of a strategy is solved"),
+    }
+}
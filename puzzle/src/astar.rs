@@ -0,0 +1,615 @@
+use std::cmp::Ordering;
+use std::hash::{BuildHasher, Hash};
+use std::time::{Duration, Instant};
+
+use super::frontier::{search_by, search_by_config, solve_by, solve_by_config, Reached, Search};
+use super::{Frontier, Heap, Observer, Puzzle, Solution, SolveOutcome, SolverConfig, TimedOut};
+
+/// Trait for puzzles that can estimate the number of moves remaining to
+/// reach a goal state.
+pub trait Heuristic {
+    /// Estimates the number of moves from the puzzle state to a goal state.
+    ///
+    /// In order for `solve_astar` to return a shortest sequence of moves, the
+    /// estimate must be admissible; that is, it must never exceed the actual
+    /// number of moves from the puzzle state to the nearest goal state.  Goal
+    /// states should have an estimate of `0`.
+    fn estimate(&self) -> usize;
+
+    /// Estimates the number of moves from each of the puzzle states `ps` to a
+    /// goal state, at once; that is, `P::estimate_batch(ps)[i]` must equal
+    /// `ps[i].estimate()`.
+    ///
+    /// `solve_astar_batched` hands the heuristic all of the successor puzzle
+    /// states of an expansion in one batch, so that an implementation can
+    /// amortize work across the batch (e.g., by vectorizing, or by offloading
+    /// the batch to an accelerator).  The default implementation estimates
+    /// each puzzle state in turn.
+    fn estimate_batch(ps: &[Self]) -> Vec<usize>
+    where
+        Self: Sized,
+    {
+        ps.iter().map(Heuristic::estimate).collect()
+    }
+
+    /// Whether or not `estimate` is admissible (see above).  Solvers whose
+    /// optimality depends upon an admissible estimate (e.g.,
+    /// `solve_astar_certified`) report their results as optimal only if this
+    /// is `true`.  The default is `false`, making no claim.
+    const ADMISSIBLE: bool = false;
+
+    /// Whether or not `estimate` is consistent; that is, for every move from a
+    /// puzzle state `p` to `q`, `p.estimate() <= 1 + q.estimate()`, and goal
+    /// states have an estimate of `0`.  A consistent estimate is also
+    /// admissible, so `CONSISTENT` should imply `ADMISSIBLE`; moreover, with a
+    /// consistent estimate, `solve_astar` never re-expands a puzzle state.
+    /// The default is `false`, making no claim.
+    const CONSISTENT: bool = false;
+}
+
+/// Solve a puzzle using A* search with hashing of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  If `P::estimate` is admissible, then the sequence
+/// of moves `ms` is one of the shortest sequence of moves from `p0` to a goal
+/// state (as with `solve`).  Note that the goal state `p` need not be the same
+/// goal state as is returned by `solve`.  (See `solve_astar_certified` for a
+/// variant that also reports whether `P::estimate` is known to be admissible.)
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// A priority queue ordered by the number of moves so far plus
+/// `P::estimate` is used to select the next puzzle state to expand, so that
+/// puzzle states that appear to be closer to a goal state are expanded first.
+/// A hash table records the fewest number of moves found so far to each puzzle
+/// state, along with its predecessor; a puzzle state is re-expanded if a
+/// shorter sequence of moves to it is found (which can only happen if the
+/// estimate is admissible but not consistent).
+pub fn solve_astar<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    astar_by(p0, |_, _| 1, |g, p| (g + p.estimate()) as f64, true)
+}
+
+/// Solve a puzzle using A* search with hashing of states, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_astar(p0)`
+/// (or, with a `max_depth`, without expanding the puzzle states `max_depth`
+/// moves from `p0`) if the search finds it within the limits of `config`, and
+/// otherwise the outcome of the search as for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_with_config`; the
+/// `dedupe_scope` forgets the puzzle states of the layers (by number of moves
+/// from `p0`) beyond the scope of the layer of the puzzle state being
+/// expanded.
+pub fn solve_astar_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    astar_by_config(p0, |_, _| 1, |g, p| (g + p.estimate()) as f64, true, config)
+}
+
+/// Solve a puzzle using A* search with hashing of states, reporting whether the
+/// sequence of moves is known to be optimal.
+///
+/// Returns `Some((ms,p,optimal))` if puzzle `p0` can be solved by the sequence
+/// of moves `ms` to a goal state `p` (as with `solve_astar`).  If `optimal`,
+/// then the sequence of moves `ms` is one of the shortest sequence of moves
+/// from `p0` to a goal state; `optimal` is `P::ADMISSIBLE`, since
+/// `solve_astar` only guarantees a shortest sequence of moves for an
+/// admissible estimate.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// In debug builds, the claims of `P::ADMISSIBLE` and `P::CONSISTENT` are
+/// (partially) checked: it is asserted that the estimate of the goal state `p`
+/// is `0`, that the estimate of `p0` does not exceed the number of moves of
+/// `ms` and (if `P::CONSISTENT`) that `P::ADMISSIBLE`.
+pub fn solve_astar_certified<P>(p0: P) -> Option<(Vec<P::Move>, P, bool)>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    debug_assert!(
+        !P::CONSISTENT || P::ADMISSIBLE,
+        "a consistent estimate should be admissible"
+    );
+    let h0 = p0.estimate();
+    let (ms, p) = solve_astar(p0)?;
+    if P::ADMISSIBLE {
+        debug_assert_eq!(p.estimate(), 0, "the estimate of a goal state should be 0");
+        debug_assert!(
+            h0 <= ms.len(),
+            "the estimate ({}) should not exceed the number of moves ({})",
+            h0,
+            ms.len()
+        );
+    }
+    Some((ms, p, P::ADMISSIBLE))
+}
+
+/// Solve a puzzle using weighted A* search with hashing of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  If `P::estimate` is admissible and `weight` is at
+/// least `1.0`, then the sequence of moves `ms` is at most `weight` times as
+/// long as the shortest sequence of moves from `p0` to a goal state.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// As with `solve_astar`, but the priority of a puzzle state is the number of
+/// moves so far plus `weight` times `P::estimate`.  A `weight` of `1.0` is A*
+/// (and a `weight` of `0.0` is BFS); larger weights trust the estimate more,
+/// typically expanding many fewer puzzle states at the expense of longer
+/// sequences of moves.
+pub fn solve_weighted_astar<P>(p0: P, weight: f64) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    if weight.is_nan() || weight < 0.0 {
+        panic!(
+            "solve_weighted_astar weight (is {}) should be non-negative",
+            weight
+        )
+    }
+    astar_by(
+        p0,
+        |_, _| 1,
+        |g, p| g as f64 + weight * p.estimate() as f64,
+        true,
+    )
+}
+
+/// Solve a puzzle using weighted A* search with hashing of states, as
+/// configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_weighted_astar(p0, weight)` (or, with a `max_depth`, without
+/// expanding the puzzle states `max_depth` moves from `p0`) if the search finds
+/// it within the limits of `config`, and otherwise the outcome of the search
+/// as for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_astar_with_config`.
+pub fn solve_weighted_astar_with_config<P, S, O>(
+    p0: P,
+    weight: f64,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    if weight.is_nan() || weight < 0.0 {
+        panic!(
+            "solve_weighted_astar_with_config weight (is {}) should be non-negative",
+            weight
+        )
+    }
+    astar_by_config(
+        p0,
+        |_, _| 1,
+        |g, p| g as f64 + weight * p.estimate() as f64,
+        true,
+        config,
+    )
+}
+
+/// Solve a puzzle using A* search with hashing of states, estimating the
+/// successor puzzle states of each expansion in a batch.
+///
+/// Returns the same result as `solve_astar(p0)`, except that the goal state
+/// `p` (and sequence of moves `ms`) may differ among ties.
+///
+/// As with `solve_astar`, but the successor puzzle states of each expansion
+/// that are reached by a shorter sequence of moves than before are estimated
+/// together, by a single call of `P::estimate_batch` (see
+/// `Heuristic::estimate_batch`), before they are pushed onto the priority
+/// queue.
+///
+/// Panics if `P::estimate_batch` returns a different number of estimates than
+/// puzzle states.
+pub fn solve_astar_batched<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    solve_by(p0, &mut Batched, Heap::new(), &mut Reached::new())
+}
+
+/// Solve a puzzle using A* search with hashing of states, estimating the
+/// successor puzzle states of each expansion in a batch, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_astar_batched(p0)` (or, with a `max_depth`, without expanding the
+/// puzzle states `max_depth` moves from `p0`) if the search finds it within the
+/// limits of `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_astar_with_config`.
+pub fn solve_astar_batched_with_config<P, S, O>(
+    p0: P,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    solve_by_config(p0, &mut Batched, Heap::new(), config)
+}
+
+/// The hooks of `solve_astar_batched`.
+struct Batched;
+
+impl<P> Search<P> for Batched
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+{
+    const NAME: &'static str = "solve_astar_batched";
+    type Key = P;
+    type Frontier = Heap<(usize, P), Priority>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.clone()
+    }
+
+    fn priority(&mut self, g: usize, p: &P) -> Priority {
+        let h = P::estimate_batch(std::slice::from_ref(p))[0];
+        Priority {
+            f: (g + h) as f64,
+            g,
+        }
+    }
+
+    fn reopen(&self) -> bool {
+        true
+    }
+
+    fn push(&mut self, frontier: &mut Self::Frontier, batch: &mut Vec<(usize, usize, P)>) {
+        let (nodes, qs): (Vec<_>, Vec<_>) = batch.drain(..).map(|(g, i, q)| ((g, i), q)).unzip();
+        let hs = P::estimate_batch(&qs);
+        if hs.len() != qs.len() {
+            panic!(
+                "solve_astar_batched estimates (is {}) should be as many as puzzle states (is {})",
+                hs.len(),
+                qs.len()
+            )
+        }
+        for (((g, i), q), h) in nodes.into_iter().zip(qs).zip(hs) {
+            let f = (g + h) as f64;
+            frontier.push((i, q), Priority { f, g });
+        }
+    }
+}
+
+/// Solve a puzzle using greedy best-first search with hashing of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  Note that, unlike `solve_astar`, the sequence of
+/// moves `ms` need not be one of the shortest sequence of moves from `p0` to a
+/// goal state.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// As with `solve_astar`, but the priority of a puzzle state is `P::estimate`
+/// alone (ignoring the number of moves so far), so that the puzzle state that
+/// appears to be closest to a goal state is always expanded next.  Moreover,
+/// a puzzle state is never re-expanded when a shorter sequence of moves to it
+/// is found.  This typically finds some solution much faster than
+/// `solve_astar`.
+pub fn solve_greedy<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    astar_by(p0, |_, _| 1, |_, p| p.estimate() as f64, false)
+}
+
+/// Solve a puzzle using greedy best-first search with hashing of states, as
+/// configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_greedy(p0)`
+/// (or, with a `max_depth`, without expanding the puzzle states `max_depth`
+/// moves from `p0`) if the search finds it within the limits of `config`, and
+/// otherwise the outcome of the search as for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_astar_with_config`.
+pub fn solve_greedy_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    astar_by_config(p0, |_, _| 1, |_, p| p.estimate() as f64, false, config)
+}
+
+/// Solve a puzzle using anytime weighted A* search with hashing of states.
+///
+/// Returns `Some((ms,p,optimal))` if puzzle `p0` can be solved by the sequence
+/// of moves `ms` to a goal state `p`, where `ms` is the shortest sequence of
+/// moves found within the budget.  If `optimal` and `P::estimate` is
+/// admissible, then the sequence of moves `ms` is one of the shortest sequence
+/// of moves from `p0` to a goal state (as with `solve`); `optimal` is `false`
+/// if the budget expired before that could be proven.
+///
+/// Returns `None` if no goal state was found within the budget.
+///
+/// As with `solve_weighted_astar`, except that the search continues after a
+/// goal state is found, pruning every puzzle state whose number of moves so far
+/// plus `P::estimate` is not less than the length of the shortest sequence of
+/// moves found so far.  Each time a shorter sequence of moves `ms` to a goal
+/// state `p` is found, `on_solution(&ms, &p)` is called.  The search finishes
+/// when no puzzle states remain (proving that the shortest sequence of moves
+/// found is optimal) or when `budget` puzzle states have been expanded.
+pub fn solve_anytime<P, S>(
+    p0: P,
+    weight: f64,
+    budget: usize,
+    on_solution: S,
+) -> Option<(Vec<P::Move>, P, bool)>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: FnMut(&[P::Move], &P),
+{
+    let run = anytime("solve_anytime", p0, weight, budget, None, on_solution);
+    run.best.map(|(ms, p)| (ms, p, run.optimal))
+}
+
+/// Solve a puzzle using anytime weighted A* search with hashing of states,
+/// within a time limit.
+///
+/// Returns `Ok(soln)` if the search finishes within `time_limit`: `soln` is
+/// `Some((ms,p))`, where the sequence of moves `ms` is (if `P::estimate` is
+/// admissible) one of the shortest sequence of moves from `p0` to a goal state
+/// `p`, or `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// Returns `Err(timed_out)` once `time_limit` has expired (see `TimedOut`),
+/// with the shortest sequence of moves found so far as its incumbent, if any.
+///
+/// As with `solve_anytime`, but the budget is of time (checked before each
+/// expansion) rather than of expansions; so, with a small `weight`, the search
+/// returns a shortest sequence of moves when there is time to prove it, and,
+/// with a larger `weight`, it is more likely to have found some sequence of
+/// moves when the time expires.
+pub fn solve_anytime_timed<P, S>(
+    p0: P,
+    weight: f64,
+    time_limit: Duration,
+    on_solution: S,
+) -> Result<Solution<P>, TimedOut<P>>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: FnMut(&[P::Move], &P),
+{
+    let deadline = Instant::now().checked_add(time_limit);
+    let run = anytime(
+        "solve_anytime_timed",
+        p0,
+        weight,
+        usize::MAX,
+        deadline,
+        on_solution,
+    );
+    if run.optimal {
+        Ok(run.best)
+    } else {
+        Err(TimedOut {
+            states: run.expansions,
+            depth: run.depth,
+            incumbent: run.best,
+        })
+    }
+}
+
+/// The end of a run of `anytime`: the shortest sequence of moves found (and
+/// its goal state), whether or not the search finished (proving that it is
+/// optimal), and the number of expansions and the greatest number of moves of
+/// an expanded puzzle state.
+struct Anytime<P: Puzzle> {
+    best: Option<(Vec<P::Move>, P)>,
+    optimal: bool,
+    expansions: usize,
+    depth: usize,
+}
+
+/// Anytime weighted A* search (for the solver `name`), which stops once
+/// `budget` puzzle states have been expanded or once `deadline` has passed.
+fn anytime<P, S>(
+    name: &str,
+    p0: P,
+    weight: f64,
+    budget: usize,
+    deadline: Option<Instant>,
+    on_solution: S,
+) -> Anytime<P>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: FnMut(&[P::Move], &P),
+{
+    if weight.is_nan() || weight < 0.0 {
+        panic!("{} weight (is {}) should be non-negative", name, weight)
+    }
+    let mut incumbent = Incumbent {
+        weight,
+        budget,
+        deadline,
+        on_solution,
+        run: Anytime {
+            best: None,
+            optimal: true,
+            expansions: 0,
+            depth: 0,
+        },
+    };
+    solve_by(p0, &mut incumbent, Heap::new(), &mut Reached::new());
+    incumbent.run
+}
+
+/// The hooks of `anytime`, which records each shorter sequence of moves found
+/// in `run` (rather than stopping at it).
+struct Incumbent<P: Puzzle, S> {
+    weight: f64,
+    budget: usize,
+    deadline: Option<Instant>,
+    on_solution: S,
+    run: Anytime<P>,
+}
+
+impl<P: Puzzle, S> Incumbent<P, S> {
+    /// The length of the shortest sequence of moves found so far.
+    fn bound(&self) -> usize {
+        self.run
+            .best
+            .as_ref()
+            .map_or(usize::MAX, |(ms, _)| ms.len())
+    }
+}
+
+impl<P, S> Search<P> for Incumbent<P, S>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: FnMut(&[P::Move], &P),
+{
+    const NAME: &'static str = "anytime";
+    type Key = P;
+    type Frontier = Heap<(usize, P), Priority>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.clone()
+    }
+
+    fn priority(&mut self, g: usize, p: &P) -> Priority {
+        let f = g as f64 + self.weight * p.estimate() as f64;
+        Priority { f, g }
+    }
+
+    fn reopen(&self) -> bool {
+        true
+    }
+
+    fn successors(&mut self, p: &P, g: usize, next: &mut Vec<(P::Move, P)>) {
+        let bound = self.bound();
+        p.next_into(next);
+        next.retain(|(_, q)| g + 1 + q.estimate() < bound);
+    }
+
+    // prune puzzle states that cannot lead to a shorter solution
+    fn skip(&mut self, p: &P, g: usize) -> bool {
+        g + p.estimate() >= self.bound()
+    }
+
+    fn stop<H>(&mut self, reached: &Reached<P, P::Move, H>, i: usize, p: &P) -> bool {
+        let vec = reached.path(i);
+        (self.on_solution)(&vec, p);
+        self.run.best = Some((vec, p.clone()));
+        false
+    }
+
+    fn proceed(&mut self, _: &P, g: usize) -> bool {
+        let run = &mut self.run;
+        if run.expansions == self.budget
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            run.optimal = false;
+            return false;
+        }
+        run.expansions += 1;
+        run.depth = run.depth.max(g);
+        true
+    }
+}
+
+/// The priority of a puzzle state in the frontier of `astar_by` (and of the
+/// other best-first searches): its `f` (for A*, the cost of the path so far
+/// plus the estimate of the remaining cost), smallest first; ties are broken in
+/// favor of the larger `g` (the cost of the path so far; i.e., the puzzle state
+/// that is likely closer to a goal state).
+#[derive(Clone, Copy)]
+pub(crate) struct Priority {
+    pub(crate) f: f64,
+    pub(crate) g: usize,
+}
+impl PartialEq for Priority {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Priority {}
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Priority {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.f.total_cmp(&other.f).then(other.g.cmp(&self.g))
+    }
+}
+
+/// Best-first search with hashing of states, where the cost of a move `m` from
+/// a puzzle state `p` is `cost(p, m)` and the priority of a puzzle state `p`
+/// reached by a sequence of moves with total cost `g` is `f(g, p)` (smaller
+/// is better).  If `reopen`, then a puzzle state is re-expanded whenever a
+/// cheaper sequence of moves to it is found; otherwise, each puzzle state is
+/// only reached by the first sequence of moves found to it.
+///
+/// This is `search_by` with a `Heap` frontier.
+pub(crate) fn astar_by<P, C, F>(p0: P, cost: C, f: F, reopen: bool) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    C: Fn(&P, &P::Move) -> usize,
+    F: Fn(usize, &P) -> f64,
+{
+    search_by(
+        p0,
+        Heap::new(),
+        cost,
+        |g, p| Priority { f: f(g, p), g },
+        reopen,
+    )
+}
+
+/// As `astar_by`, but as configured by `config` (see `search_by_config`).
+pub(crate) fn astar_by_config<P, C, F, S, O>(
+    p0: P,
+    cost: C,
+    f: F,
+    reopen: bool,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    C: Fn(&P, &P::Move) -> usize,
+    F: Fn(usize, &P) -> f64,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    search_by_config(
+        p0,
+        Heap::new(),
+        cost,
+        |g, p| Priority { f: f(g, p), g },
+        reopen,
+        config,
+    )
+}
@@ -0,0 +1,146 @@
+use std::collections::VecDeque;
+use std::hash::{BuildHasher, Hash};
+
+use super::frontier::{solve_by, solve_by_config, Reached, Search};
+use super::{ordered_next, Frontier, Heuristic, Observer, Puzzle, SolveOutcome, SolverConfig};
+
+/// Solve a puzzle using beam search with hashing of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  Note that the sequence of moves `ms` need not be
+/// one of the shortest sequence of moves from `p0` to a goal state.
+///
+/// Returns `None` if no goal state was found; unlike `solve`, this does not
+/// imply that `p0` cannot be solved by any sequence of moves.
+///
+/// As with `solve`, the puzzle states are explored one layer (i.e., number of
+/// moves from `p0`) at a time.  However, only the `width` puzzle states of each
+/// layer with the smallest `P::estimate` are kept and expanded to form the next
+/// layer; the remaining puzzle states of the layer are discarded (and
+/// forgotten, so that a later layer may reach them again).  Thus, at most
+/// `width` puzzle states are kept per layer (and the kept puzzle states of
+/// every layer are used to avoid redundant puzzle states), at the expense of
+/// possibly discarding every puzzle state that leads to a goal state.  Note
+/// that every puzzle state reached (kept or not) still takes a node of the
+/// arena of the search, with its parent link (rather than the puzzle state
+/// itself); so, the memory grows with the number of successor puzzle states of
+/// the kept puzzle states, rather than with `width` alone.
+pub fn solve_beam<P>(p0: P, width: usize) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    solve_by(p0, &mut Beamed, Beam::new(width), &mut Reached::new())
+}
+
+/// Solve a puzzle using beam search with hashing of states, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_beam(p0,
+/// width)` (or, with a `max_depth`, without expanding the puzzle states
+/// `max_depth` moves from `p0`) if the search finds it within the limits of
+/// `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_with_config`.
+pub fn solve_beam_with_config<P, S, O>(
+    p0: P,
+    width: usize,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    solve_by_config(p0, &mut Beamed, Beam::new(width), config)
+}
+
+/// The frontier of `solve_beam`: the puzzle states of the layer being
+/// expanded, and those of the next layer with their estimates.
+struct Beam<T> {
+    width: usize,
+    layer: VecDeque<T>,
+    next: Vec<(usize, T)>,
+    /// The puzzle states of the next layer that were not kept.
+    discarded: Vec<T>,
+    /// Whether or not the first layer (of the initial puzzle state) was kept.
+    started: bool,
+}
+
+impl<T> Beam<T> {
+    /// An empty frontier, which keeps `width` puzzle states of each layer.
+    fn new(width: usize) -> Self {
+        Beam {
+            width,
+            layer: VecDeque::new(),
+            next: vec![],
+            discarded: vec![],
+            started: false,
+        }
+    }
+}
+
+impl<T> Frontier<T> for Beam<T> {
+    type Priority = usize;
+
+    fn push(&mut self, x: T, estimate: usize) {
+        self.next.push((estimate, x))
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.layer.is_empty() {
+            // keep the most promising puzzle states of the next layer (which,
+            // among equal estimates, are those of the most promising moves);
+            // the discarded puzzle states are forgotten, so that they may be
+            // reached again by later layers
+            self.next.sort_by_key(|(h, _)| *h);
+            let width = if self.started {
+                self.width
+            } else {
+                self.next.len()
+            };
+            let kept = width.min(self.next.len());
+            self.discarded
+                .extend(self.next.drain(kept..).map(|(_, x)| x));
+            self.layer.extend(self.next.drain(..).map(|(_, x)| x));
+            self.started = true;
+        }
+        self.layer.pop_front()
+    }
+
+    fn len(&self) -> usize {
+        self.layer.len() + self.next.len()
+    }
+
+    fn forget(&mut self) -> Option<T> {
+        self.discarded.pop()
+    }
+}
+
+/// The hooks of `solve_beam`.
+struct Beamed;
+
+impl<P> Search<P> for Beamed
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+{
+    const NAME: &'static str = "solve_beam";
+    type Key = P;
+    type Frontier = Beam<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.clone()
+    }
+
+    fn priority(&mut self, _: usize, p: &P) -> usize {
+        p.estimate()
+    }
+
+    // the most promising moves first, so that they are kept among puzzle
+    // states with equal estimates
+    fn successors(&mut self, p: &P, _: usize, next: &mut Vec<(P::Move, P)>) {
+        next.extend(ordered_next(p))
+    }
+}
@@ -0,0 +1,430 @@
+use std::collections::hash_map::Entry::Vacant;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::time::Instant;
+
+use super::frontier::{Ended, Solved};
+use super::{
+    BfsLimits, InvertiblePuzzle, Observer, Puzzle, SearchStats, SolveError, SolveOutcome,
+    SolverConfig,
+};
+
+/// A hash table recording, for each puzzle state reached by one direction of
+/// the search, the number of moves from the origin of that direction and the
+/// neighboring puzzle state (with the connecting move) through which it was
+/// reached.
+type Visited<P, M, S = RandomState> = HashMap<P, (usize, Option<(P, M)>), S>;
+
+/// Solve a puzzle using bidirectional BFS with hashing of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be moved to the goal state `p`
+/// (which is `goal`) by the sequence of moves `ms`.  The sequence of moves `ms`
+/// is one of the shortest sequence of moves from `p0` to `goal`.  Note that,
+/// unlike `solve`, the goal state is supplied by the caller; if `goal` is the
+/// goal state reached by `solve`, then `ms` is as short as the sequence of
+/// moves returned by `solve`.
+///
+/// Returns `None` if `goal` is not a goal state or if `p0` cannot be moved to
+/// `goal` by any sequence of moves.
+///
+/// One BFS proceeds forwards from `p0` (using `P::next`) and another proceeds
+/// backwards from `goal` (using `P::prev`); at each step, the whole layer of
+/// the direction with the smaller frontier is expanded.  The search finishes
+/// when the two searches meet at a common puzzle state, in which case the
+/// shortest sequence of moves through the puzzle states at which they meet is
+/// taken.  When the number of puzzle states grows exponentially with the number
+/// of moves, each direction need only reach (roughly) half the depth of
+/// `solve`.
+pub fn solve_bidirectional<P>(p0: P, goal: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: InvertiblePuzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    let limits = BfsLimits {
+        max_states: usize::MAX,
+        ..BfsLimits::new(&p0)
+    };
+    match bidirectional(
+        p0,
+        goal,
+        RandomState::new(),
+        &limits,
+        &mut SearchStats::default(),
+        &mut (),
+    ) {
+        Ok(ended) => ended.goal(),
+        Err(err) => unreachable!("solve_bidirectional has no limit for {:?}", err),
+    }
+}
+
+/// Solve a puzzle using bidirectional BFS with hashing of states, as
+/// configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_bidirectional(p0, goal)` (or, with a `max_depth`, a shortest sequence
+/// of at most `max_depth` moves) if the search finds it within the limits of
+/// `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// The puzzle states reached by both directions count towards the `max_nodes`
+/// (and the `memory_limit`) of `config`, both hash tables use its `hasher`, and
+/// the time limit (and the cancel token) is checked before each expansion.  The
+/// layers of both directions are needed to join them, so the `dedupe_scope` of
+/// `config` is ignored, as is its `parallelism`.  The depths of the backward
+/// direction are not moves from `p0`, so only the forward direction is reported
+/// to the observer of `config` (and the solution).
+pub fn solve_bidirectional_with_config<P, S, O>(
+    p0: P,
+    goal: P,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: InvertiblePuzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let start = Instant::now();
+    let limits = config.bfs_limits(&p0, start);
+    let (mut stats, observer) = (SearchStats::default(), &mut *config.observer.borrow_mut());
+    let hasher = config.hasher.clone();
+    let ended = bidirectional(p0, goal, hasher, &limits, &mut stats, observer);
+    let outcome = SolveOutcome::new(ended, stats.visited, |(vec, p)| {
+        observer.on_solution(&vec, &p);
+        stats.depth = Some(vec.len());
+        (vec, p)
+    });
+    stats.finish(start, observer);
+    outcome
+}
+
+/// Bidirectional BFS from `p0` and `goal`, within the `max_depth`,
+/// `max_states`, `max_memory_states`, `deadline` (and `cancel`) of `limits`,
+/// where both hash tables use the hasher built by `hash_builder`, counting the
+/// expansions and the puzzle states reached by both directions in `stats`;
+/// the forward direction is reported to `observer`.
+fn bidirectional<P, S>(
+    p0: P,
+    goal: P,
+    hash_builder: S,
+    limits: &BfsLimits,
+    stats: &mut SearchStats,
+    observer: &mut impl Observer<P>,
+) -> Solved<P>
+where
+    P: InvertiblePuzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+{
+    if !goal.is_goal() {
+        return Ok(Ended::Exhausted);
+    }
+    if p0 == goal {
+        stats.visited = 1;
+        return Ok(Ended::Goal((vec![], goal)));
+    }
+    // visit `p0` and `goal`
+    limits.visit(0)?;
+    limits.visit(1)?;
+
+    let mut fwd = Visited::with_hasher(hash_builder.clone());
+    let mut bwd = Visited::with_hasher(hash_builder);
+    fwd.insert(p0.clone(), (0, None));
+    bwd.insert(goal.clone(), (0, None));
+    let mut fwd_layer = vec![p0];
+    let mut bwd_layer = vec![goal.clone()];
+    // the number of moves of the last layer of each direction
+    let (mut fwd_depth, mut bwd_depth) = (0, 0);
+
+    // Loop till either direction is exhausted (or every sequence of at most
+    // `max_depth` moves has been joined)
+    while !fwd_layer.is_empty() && !bwd_layer.is_empty() {
+        stats.visited = fwd.len() + bwd.len();
+        if fwd_depth + bwd_depth >= limits.max_depth {
+            return Ok(Ended::Cut);
+        }
+        stats.expanded += fwd_layer.len().min(bwd_layer.len());
+        let meet = if fwd_layer.len() <= bwd_layer.len() {
+            let (layer, meet) =
+                expand_layer(&fwd_layer, &mut fwd, &bwd, unpruned_next, limits, observer)?;
+            observer.on_layer_complete(fwd_depth, fwd.len() + bwd.len());
+            fwd_layer = layer;
+            fwd_depth += 1;
+            meet
+        } else {
+            let (layer, meet) = expand_layer(&bwd_layer, &mut bwd, &fwd, P::prev, limits, &mut ())?;
+            bwd_layer = layer;
+            bwd_depth += 1;
+            meet
+        };
+
+        if let Some(meet) = meet {
+            stats.visited = fwd.len() + bwd.len();
+            return Ok(Ended::Goal((join(&fwd, &bwd, &meet), goal)));
+        }
+    }
+
+    stats.visited = fwd.len() + bwd.len();
+    Ok(Ended::Exhausted)
+}
+
+/// The successor puzzle states of `p` (see `Puzzle::next`), or none at all if
+/// `p` is pruned (see `Puzzle::prune`).
+fn unpruned_next<P: Puzzle>(p: &P) -> Vec<(P::Move, P)> {
+    if p.prune() {
+        vec![]
+    } else {
+        p.next()
+    }
+}
+
+/// Expand all of the puzzle states of `layer` (using `neighbors`), recording
+/// newly reached puzzle states in `this`, within the limits of `limits` (on the
+/// puzzle states of both directions), and reporting to `observer`.  Returns the
+/// next layer and the puzzle state (if any) that was also reached by the other
+/// direction (in `that`) with the fewest total number of moves.
+fn expand_layer<P, F, S>(
+    layer: &[P],
+    this: &mut Visited<P, P::Move, S>,
+    that: &Visited<P, P::Move, S>,
+    neighbors: F,
+    limits: &BfsLimits,
+    observer: &mut impl Observer<P>,
+) -> Result<(Vec<P>, Option<P>), SolveError>
+where
+    P: InvertiblePuzzle + Eq + Hash + Clone,
+    F: Fn(&P) -> Vec<(P::Move, P)>,
+    S: BuildHasher,
+{
+    let mut next_layer = vec![];
+    let mut meet: Option<(usize, P)> = None;
+    for p in layer {
+        let d = match this.get(p) {
+            Some((d, _)) => d + 1,
+            None => continue,
+        };
+        limits.expand(this.len() + that.len(), d - 1)?;
+        observer.on_expand(p, d - 1);
+        for (m, q) in neighbors(p) {
+            let states = this.len() + that.len();
+            if let Vacant(e) = this.entry(q.clone()) {
+                limits.visit(states)?;
+                observer.on_discover(&q, p, d);
+                e.insert((d, Some((p.clone(), m))));
+                if let Some((dq, _)) = that.get(&q) {
+                    if meet.as_ref().is_none_or(|(dm, _)| d + dq < *dm) {
+                        meet = Some((d + dq, q.clone()));
+                    }
+                }
+                next_layer.push(q);
+            }
+        }
+    }
+    Ok((next_layer, meet.map(|(_, q)| q)))
+}
+
+/// Solve a puzzle using meet-in-the-middle search with hashing of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be moved to the goal state `p`
+/// (which is `goal`) by the sequence of moves `ms`, where `ms.len() <=
+/// max_depth`.  The sequence of moves `ms` is one of the shortest sequence of
+/// moves from `p0` to `goal` (as with `solve_bidirectional`).
+///
+/// Returns `None` if `goal` is not a goal state or if `p0` cannot be moved to
+/// `goal` by any sequence of at most `max_depth` moves.
+///
+/// Unlike `solve_bidirectional`, which alternates between the two directions
+/// until they meet, the depth of each direction is fixed in advance: a BFS
+/// forwards from `p0` (using `P::next`) records every puzzle state within
+/// `max_depth.div_ceil(2)` moves, then a BFS backwards from `goal` (using
+/// `P::prev`) to within `max_depth / 2` moves joins on the recorded puzzle
+/// states.  Every sequence of at most `max_depth` moves from `p0` to `goal`
+/// passes through such a shared puzzle state, so taking the shared puzzle state
+/// with the fewest total number of moves yields a shortest sequence of moves.
+pub fn solve_meet_in_the_middle<P>(p0: P, goal: P, max_depth: usize) -> Option<(Vec<P::Move>, P)>
+where
+    P: InvertiblePuzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    let limits = BfsLimits {
+        max_states: usize::MAX,
+        ..BfsLimits::new(&p0)
+    };
+    let stats = &mut SearchStats::default();
+    let hasher = RandomState::new();
+    match meet_in_the_middle(p0, goal, max_depth, hasher, &limits, stats, &mut ()) {
+        Ok(ended) => ended.goal(),
+        Err(err) => unreachable!("solve_meet_in_the_middle has no limit for {:?}", err),
+    }
+}
+
+/// Solve a puzzle using meet-in-the-middle search with hashing of states, as
+/// configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_meet_in_the_middle(p0, goal, max_depth)` (or, with a `max_depth` of
+/// `config`, a shortest sequence of at most that many moves) if the search
+/// finds it within the limits of `config`, and otherwise the outcome of the
+/// search as for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_bidirectional_with_config`.
+pub fn solve_meet_in_the_middle_with_config<P, S, O>(
+    p0: P,
+    goal: P,
+    max_depth: usize,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: InvertiblePuzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let start = Instant::now();
+    let limits = config.bfs_limits(&p0, start);
+    let (mut stats, observer) = (SearchStats::default(), &mut *config.observer.borrow_mut());
+    let hasher = config.hasher.clone();
+    let ended = meet_in_the_middle(p0, goal, max_depth, hasher, &limits, &mut stats, observer);
+    let outcome = SolveOutcome::new(ended, stats.visited, |(vec, p)| {
+        observer.on_solution(&vec, &p);
+        stats.depth = Some(vec.len());
+        (vec, p)
+    });
+    stats.finish(start, observer);
+    outcome
+}
+
+/// Meet-in-the-middle search from `p0` and `goal` for sequences of at most
+/// `max_depth` moves, within the `max_depth`, `max_states`,
+/// `max_memory_states`, `deadline` (and `cancel`) of `limits`, where both hash
+/// tables use the hasher built by `hash_builder`, counting the expansions and
+/// the puzzle states reached by both directions in `stats`; the forward
+/// direction is reported to `observer`.
+fn meet_in_the_middle<P, S>(
+    p0: P,
+    goal: P,
+    max_depth: usize,
+    hash_builder: S,
+    limits: &BfsLimits,
+    stats: &mut SearchStats,
+    observer: &mut impl Observer<P>,
+) -> Solved<P>
+where
+    P: InvertiblePuzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+{
+    if !goal.is_goal() {
+        return Ok(Ended::Exhausted);
+    }
+    let max_depth = max_depth.min(limits.max_depth);
+
+    let fwd_depth = max_depth.div_ceil(2);
+    let (fwd, fwd_done) = bfs_to_depth(
+        p0,
+        fwd_depth,
+        unpruned_next,
+        hash_builder.clone(),
+        0,
+        limits,
+        stats,
+        observer,
+    )?;
+    let (bwd, bwd_done) = bfs_to_depth(
+        goal.clone(),
+        max_depth / 2,
+        P::prev,
+        hash_builder,
+        fwd.len(),
+        limits,
+        stats,
+        &mut (),
+    )?;
+    stats.visited = fwd.len() + bwd.len();
+    let meet = bwd
+        .iter()
+        .filter_map(|(p, (db, _))| fwd.get(p).map(|(df, _)| (df + db, p)))
+        .min_by_key(|(d, _)| *d);
+    Ok(match meet {
+        Some((_, meet)) => Ended::Goal((join(&fwd, &bwd, meet), goal)),
+        None if fwd_done || bwd_done => Ended::Exhausted,
+        None => Ended::Cut,
+    })
+}
+
+/// BFS (using `neighbors`) from `origin`, recording every puzzle state within
+/// `depth` moves of `origin` in a hash table with the hasher built by
+/// `hash_builder`, within the limits of `limits` (on these puzzle states and
+/// the `visited` puzzle states of the other direction), counting the
+/// expansions in `stats`, and reporting to `observer`.  Returns the hash table
+/// and whether or not every puzzle state reachable from `origin` is in it.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn bfs_to_depth<P, F, S>(
+    origin: P,
+    depth: usize,
+    neighbors: F,
+    hash_builder: S,
+    visited: usize,
+    limits: &BfsLimits,
+    stats: &mut SearchStats,
+    observer: &mut impl Observer<P>,
+) -> Result<(Visited<P, P::Move, S>, bool), SolveError>
+where
+    P: InvertiblePuzzle + Eq + Hash + Clone,
+    F: Fn(&P) -> Vec<(P::Move, P)>,
+    S: BuildHasher,
+{
+    limits.visit(visited)?;
+    let mut this = Visited::with_hasher(hash_builder);
+    this.insert(origin.clone(), (0, None));
+    let mut layer = vec![origin];
+    for d in 1..=depth {
+        let mut next_layer = vec![];
+        for p in layer {
+            limits.expand(visited + this.len(), d - 1)?;
+            observer.on_expand(&p, d - 1);
+            stats.expanded += 1;
+            for (m, q) in neighbors(&p) {
+                let states = visited + this.len();
+                if let Vacant(e) = this.entry(q.clone()) {
+                    limits.visit(states)?;
+                    observer.on_discover(&q, &p, d);
+                    e.insert((d, Some((p.clone(), m))));
+                    next_layer.push(q);
+                }
+            }
+        }
+        observer.on_layer_complete(d - 1, visited + this.len());
+        if next_layer.is_empty() {
+            return Ok((this, true));
+        }
+        layer = next_layer;
+    }
+    Ok((this, false))
+}
+
+/// Join the sequence of moves from the origin of `fwd` to `meet` with the
+/// sequence of moves from `meet` to the origin of `bwd`.
+fn join<P, S>(fwd: &Visited<P, P::Move, S>, bwd: &Visited<P, P::Move, S>, meet: &P) -> Vec<P::Move>
+where
+    P: InvertiblePuzzle + Eq + Hash,
+    P::Move: Clone,
+    S: BuildHasher,
+{
+    // backtrack using predecessors from the meeting puzzle state to `p0`
+    let mut vec = vec![];
+    let mut p = meet;
+    while let Some((_, Some((pred, m)))) = fwd.get(p) {
+        vec.push(m.clone());
+        p = pred;
+    }
+    vec.reverse();
+    // forwardtrack using successors from the meeting puzzle state to `goal`
+    let mut p = meet;
+    while let Some((_, Some((succ, m)))) = bwd.get(p) {
+        vec.push(m.clone());
+        p = succ;
+    }
+    vec
+}
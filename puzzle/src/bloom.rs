@@ -0,0 +1,237 @@
+use std::collections::hash_map::DefaultHasher;
+use std::convert::Infallible;
+use std::hash::{Hash, Hasher};
+
+use super::dfs::dfs_by_config;
+use super::frontier::{Ended, Solved};
+use super::{ClosedSet, Observer, Puzzle, SolveOutcome, SolverConfig, Successors};
+
+/// A Bloom filter: an approximate set, which may report that it contains an
+/// element that was never inserted (a false positive), but never the reverse.
+pub(crate) struct BloomFilter {
+    bits: Vec<u64>,
+    len: usize,
+    hashes: u32,
+}
+
+impl BloomFilter {
+    /// An empty Bloom filter of `len` bits, with `hashes` hash functions.
+    pub(crate) fn new(len: usize, hashes: u32) -> Self {
+        BloomFilter {
+            bits: vec![0; len.div_ceil(64)],
+            len,
+            hashes,
+        }
+    }
+
+    /// Insert `x`, returning whether or not `x` was (possibly) already in the
+    /// filter.
+    pub(crate) fn insert<T: Hash>(&mut self, x: &T) -> bool {
+        let hash = |seed: u64| {
+            let mut hasher = DefaultHasher::new();
+            seed.hash(&mut hasher);
+            x.hash(&mut hasher);
+            hasher.finish()
+        };
+        // the bit indices of `x`, by double hashing
+        let (h1, h2) = (hash(0), hash(1) | 1);
+        let mut present = true;
+        for k in 0..u64::from(self.hashes) {
+            let i = (h1.wrapping_add(k.wrapping_mul(h2)) % self.len as u64) as usize;
+            let (word, bit) = (i / 64, 1 << (i % 64));
+            present &= self.bits[word] & bit != 0;
+            self.bits[word] |= bit;
+        }
+        present
+    }
+}
+
+/// An approximate closed set of puzzle states (see `ClosedSet`), recorded in
+/// a Bloom filter, whose memory does not grow with the number of puzzle states.
+///
+/// A puzzle state may be wrongly reported as visited (a false positive), with
+/// a small probability while the number of inserted puzzle states is small
+/// relative to the number of bits (see `solve_bloom`).
+pub struct BloomSet {
+    filter: BloomFilter,
+    len: usize,
+}
+
+impl BloomSet {
+    /// An empty set, with a Bloom filter of `bits` bits and `hashes` hash
+    /// functions.
+    ///
+    /// Panics if `bits` or `hashes` is `0`.
+    pub fn new(bits: usize, hashes: u32) -> Self {
+        if bits == 0 {
+            panic!("BloomSet::new bits (is {}) should be positive", bits)
+        }
+        if hashes == 0 {
+            panic!("BloomSet::new hashes (is {}) should be positive", hashes)
+        }
+        BloomSet {
+            filter: BloomFilter::new(bits, hashes),
+            len: 0,
+        }
+    }
+}
+
+impl<P: Hash> ClosedSet<P> for BloomSet {
+    fn insert(&mut self, p: &P) -> bool {
+        let new = !self.filter.insert(p);
+        self.len += usize::from(new);
+        new
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Solve a puzzle using DFS with an approximate (Bloom filter) set of visited
+/// states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` was solved by the sequence of moves
+/// `ms` (of at most `max_depth` moves) to a goal state `p`.  Like `solve_any`,
+/// the sequence of moves `ms` need not be one of the shortest sequence of moves
+/// from `p0` to a goal state; it is the first sequence of moves found.
+///
+/// Returns `None` if no sequence of moves from `p0` to a goal state was found.
+/// Note that this does not imply that `p0` cannot be solved.
+///
+/// The visited puzzle states are recorded in a Bloom filter (a `BloomSet`) of
+/// `bits` bits with `hashes` hash functions, rather than in a hash table, so
+/// that the memory does not grow with the number of visited puzzle states (and
+/// puzzle states are neither cloned nor stored, except along the current path).
+/// A false positive of the Bloom filter (a puzzle state wrongly reported as
+/// visited) is not explored, which may miss a solution; the probability of a
+/// false positive is small while the number of visited puzzle states is small
+/// relative to `bits` (e.g., about `0.0002` for `bits / 20` visited puzzle
+/// states with `hashes` `7`).  Since the Bloom filter cannot record a path to
+/// each puzzle state, the current path is kept explicitly; its puzzle states
+/// are in the Bloom filter (which has no false negatives), so cycles are always
+/// detected.  A puzzle state first reached by a path of `max_depth` moves is
+/// never explored from a shorter path.  This is useful for exploratory runs on
+/// puzzles with too many puzzle states for `solve_any`.
+///
+/// Panics if `bits` or `hashes` is `0`.
+pub fn solve_bloom<P>(
+    p0: P,
+    max_depth: usize,
+    bits: usize,
+    hashes: u32,
+) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Hash,
+{
+    check_bloom("solve_bloom", bits, hashes);
+    let unlimited = &mut |_: &P, _| Ok::<_, Infallible>(true);
+    let Ok(ended) = bloom_by(p0, max_depth, bits, hashes, unlimited);
+    ended.goal()
+}
+
+/// Solve a puzzle using DFS with a Bloom filter of visited states, as
+/// configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_bloom(p0, max_depth, bits, hashes)` (or, with a `max_depth` of
+/// `config`, without expanding the puzzle states `max_depth` moves from `p0`)
+/// if the search finds it within the limits of `config`, and otherwise the
+/// outcome of the search as for `solve_with_config`, except that it is never
+/// `SolveOutcome::Unsolvable` (since a false positive may have missed a
+/// solution).
+///
+/// The tunables of `config` apply as for `solve_idastar_with_config`.
+///
+/// Panics if `bits` or `hashes` is `0`.
+pub fn solve_bloom_with_config<P, S, O>(
+    p0: P,
+    max_depth: usize,
+    bits: usize,
+    hashes: u32,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Hash,
+    O: Observer<P>,
+{
+    check_bloom("solve_bloom_with_config", bits, hashes);
+    dfs_by_config(p0, config, |p0, check| {
+        bloom_by(p0, max_depth, bits, hashes, check)
+    })
+}
+
+/// Panics (naming the solver `name`) if `bits` or `hashes` is `0`.
+fn check_bloom(name: &str, bits: usize, hashes: u32) {
+    if bits == 0 {
+        panic!("{} bits (is {}) should be positive", name, bits)
+    }
+    if hashes == 0 {
+        panic!("{} hashes (is {}) should be positive", name, hashes)
+    }
+}
+
+/// DFS with a Bloom filter of visited states (see `solve_bloom`), where
+/// `check` is called before each expansion (see `dfs::search`); the search
+/// ends as `Ended::Cut` if no goal state was found.
+fn bloom_by<P, C, E>(
+    p0: P,
+    max_depth: usize,
+    bits: usize,
+    hashes: u32,
+    check: &mut C,
+) -> Solved<P, E>
+where
+    P: Puzzle + Hash,
+    C: FnMut(&P, usize) -> Result<bool, E> + ?Sized,
+{
+    if p0.is_goal() {
+        return Ok(Ended::Goal((vec![], p0)));
+    }
+    let mut closed = BloomSet::new(bits, hashes);
+    closed.insert(&p0);
+    // the successor puzzle states of each puzzle state of the path that remain
+    // to be explored, with the most promising last
+    let expand = |p: &P| {
+        let mut next = if p.prune() {
+            Successors::new()
+        } else {
+            p.next_small()
+        };
+        next.sort_by_key(|(m, _)| p.order_hint(m));
+        next
+    };
+    let mut stack = if max_depth == 0 || !check(&p0, 0)? {
+        vec![]
+    } else {
+        vec![expand(&p0)]
+    };
+    let mut path = vec![p0];
+    let mut moves = vec![];
+    while let Some(next) = stack.last_mut() {
+        let (m, q) = match next.pop() {
+            Some(mq) => mq,
+            None => {
+                stack.pop();
+                path.pop();
+                moves.pop();
+                continue;
+            }
+        };
+        if !closed.insert(&q) {
+            continue;
+        }
+        moves.push(m);
+        if q.is_goal() {
+            return Ok(Ended::Goal((moves, q)));
+        }
+        if path.len() == max_depth || q.is_dead() || !check(&q, path.len())? {
+            moves.pop();
+            continue;
+        }
+        stack.push(expand(&q));
+        path.push(q);
+    }
+
+    Ok(Ended::Cut)
+}
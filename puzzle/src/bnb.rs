@@ -0,0 +1,193 @@
+use std::convert::Infallible;
+
+use super::dfs::dfs_by_config;
+use super::frontier::{Ended, Solved};
+use super::{Heuristic, Observer, Puzzle, SolveOutcome, SolverConfig};
+
+/// Solve a puzzle using depth-first branch-and-bound.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`, where `ms.len() <= upper_bound`.  The sequence of
+/// moves `ms` is one of the shortest such sequence of moves; in particular, if
+/// `p0` can be solved by a sequence of at most `upper_bound` moves, then `ms`
+/// is one of the shortest sequence of moves from `p0` to a goal state (as with
+/// `solve`).  To search for a solution that beats a known solution of `n`
+/// moves, use an `upper_bound` of `n - 1`.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of at most
+/// `upper_bound` moves.
+///
+/// A DFS is used to find sequences of moves from `p0` to a goal state,
+/// abandoning any sequence of moves that (together with `P::lower_bound`) must
+/// be longer than the bound.  Each time a goal state is found, the bound is
+/// tightened to one less than the number of moves to it, so that the remainder
+/// of the search only looks for shorter sequences of moves.  As with
+/// `solve_dfs`, only the puzzle states along the current path are kept in
+/// memory (and are used to avoid cycles).
+pub fn solve_bnb<P>(p0: P, upper_bound: usize) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Clone,
+    P::Move: Clone,
+{
+    bnb_by(p0, upper_bound, P::lower_bound)
+}
+
+/// Solve a puzzle using depth-first branch-and-bound, pruning with a heuristic.
+///
+/// As with `solve_bnb`, except that a sequence of moves is also abandoned when
+/// the number of moves so far plus `P::estimate` exceeds the bound.  If
+/// `P::estimate` is admissible, then the result is the same as `solve_bnb`
+/// (although the goal state reached may differ), but typically many fewer
+/// puzzle states are expanded.
+pub fn solve_bnb_with_heuristic<P>(p0: P, upper_bound: usize) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Heuristic + Eq + Clone,
+    P::Move: Clone,
+{
+    bnb_by(p0, upper_bound, P::estimate)
+}
+
+/// Solve a puzzle using depth-first branch-and-bound, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_bnb(p0, upper_bound)` (or, with a `max_depth`, without expanding the
+/// puzzle states `max_depth` moves from `p0`) if the search finds it within the
+/// limits of `config`, and otherwise the outcome of the search as for
+/// `solve_with_config` (in particular, `SolveOutcome::Inconclusive` if some
+/// sequence of moves was abandoned at `upper_bound`).
+///
+/// The tunables of `config` apply as for `solve_idastar_with_config`.
+pub fn solve_bnb_with_config<P, S, O>(
+    p0: P,
+    upper_bound: usize,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Clone,
+    P::Move: Clone,
+    O: Observer<P>,
+{
+    dfs_by_config(p0, config, |p0, check| {
+        bnb_by_checked(p0, upper_bound, P::lower_bound, check)
+    })
+}
+
+/// Solve a puzzle using depth-first branch-and-bound, pruning with a heuristic,
+/// as configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_bnb_with_heuristic(p0, upper_bound)` (or, with a `max_depth`, without
+/// expanding the puzzle states `max_depth` moves from `p0`) if the search finds
+/// it within the limits of `config`, and otherwise the outcome of the search as
+/// for `solve_bnb_with_config`.
+///
+/// The tunables of `config` apply as for `solve_idastar_with_config`.
+pub fn solve_bnb_with_heuristic_with_config<P, S, O>(
+    p0: P,
+    upper_bound: usize,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Heuristic + Eq + Clone,
+    P::Move: Clone,
+    O: Observer<P>,
+{
+    dfs_by_config(p0, config, |p0, check| {
+        bnb_by_checked(p0, upper_bound, P::estimate, check)
+    })
+}
+
+/// Depth-first branch-and-bound with the heuristic supplied as the function
+/// `h`.
+fn bnb_by<P, H>(p0: P, upper_bound: usize, h: H) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Clone,
+    P::Move: Clone,
+    H: Fn(&P) -> usize,
+{
+    let unlimited = &mut |_: &P, _| Ok::<_, Infallible>(true);
+    let Ok(ended) = bnb_by_checked(p0, upper_bound, h, unlimited);
+    ended.goal()
+}
+
+/// Depth-first branch-and-bound with the heuristic supplied as the function
+/// `h`, where `check` is called before each expansion (see `dfs::search`);
+/// the search ends as `Ended::Cut` if no goal state was found but some
+/// sequence of moves was abandoned at the bound.
+fn bnb_by_checked<P, H, C, E>(p0: P, upper_bound: usize, h: H, check: &mut C) -> Solved<P, E>
+where
+    P: Puzzle + Eq + Clone,
+    P::Move: Clone,
+    H: Fn(&P) -> usize,
+    C: FnMut(&P, usize) -> Result<bool, E> + ?Sized,
+{
+    let mut path = vec![p0];
+    let mut moves = Vec::new();
+    let mut bound = Some(upper_bound);
+    let mut best = None;
+    let mut cut = false;
+    search(
+        &mut path, &mut moves, &mut bound, &h, &mut best, &mut cut, check,
+    )?;
+    Ok(match best {
+        Some(best) => Ended::Goal(best),
+        None if cut => Ended::Cut,
+        None => Ended::Exhausted,
+    })
+}
+
+/// Depth-first search from the last puzzle state of `path`; `moves` is the
+/// sequence of moves leading from the first to the last puzzle state of
+/// `path`, and `path` and `moves` are restored to their initial contents on
+/// return (unless `check` abandons the search).  The shortest solution found
+/// so far is recorded in `best`, `bound` is the maximum number of moves of a
+/// shorter solution (or `None` if no shorter solution is possible), and `cut`
+/// records whether or not a sequence of moves was abandoned at the bound.
+fn search<P, H, C, E>(
+    path: &mut Vec<P>,
+    moves: &mut Vec<P::Move>,
+    bound: &mut Option<usize>,
+    h: &H,
+    best: &mut Option<(Vec<P::Move>, P)>,
+    cut: &mut bool,
+    check: &mut C,
+) -> Result<(), E>
+where
+    P: Puzzle + Eq + Clone,
+    P::Move: Clone,
+    H: Fn(&P) -> usize,
+    C: FnMut(&P, usize) -> Result<bool, E> + ?Sized,
+{
+    let p = match path.last() {
+        Some(p) => p,
+        None => return Ok(()),
+    };
+    match bound {
+        Some(bound) if moves.len() + h(p) <= *bound => {}
+        _ => {
+            *cut = true;
+            return Ok(());
+        }
+    }
+    if p.is_goal() {
+        *bound = moves.len().checked_sub(1);
+        *best = Some((moves.clone(), p.clone()));
+        return Ok(());
+    }
+    if p.prune() || !check(p, moves.len())? {
+        return Ok(());
+    }
+    for (m, q) in p.next() {
+        // avoid cycles along the current path
+        if path.contains(&q) {
+            continue;
+        }
+        path.push(q);
+        moves.push(m);
+        search(path, moves, bound, h, best, cut, check)?;
+        moves.pop();
+        path.pop();
+    }
+    Ok(())
+}
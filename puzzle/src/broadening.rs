@@ -0,0 +1,141 @@
+use std::hash::{BuildHasher, Hash};
+use std::time::Instant;
+
+use super::frontier::{search, solve_by, Ended, Reached, Scoped, Search};
+use super::{Fifo, Observer, Puzzle, SearchStats, SolveOutcome, SolverConfig};
+
+/// Solve a puzzle using iterative broadening.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  Note that, unlike `solve`, the sequence of moves
+/// `ms` need not be one of the shortest sequence of moves from `p0` to a goal
+/// state.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// A sequence of BFSs with hashing of states (as with `solve`) is performed,
+/// with breadths `1`, `2`, `3`, ...; the BFS with breadth `b` only considers
+/// the first `b` successor puzzle states (in the order returned by `P::next`)
+/// of each puzzle state.  The breadth is increased when a BFS fails to reach a
+/// goal state, until a BFS in which no successor puzzle state was skipped
+/// (which, therefore, considered every reachable puzzle state).  When the
+/// first few moves returned by `P::next` usually lead towards a goal state, the
+/// early (narrow) searches visit far fewer puzzle states than `solve`.
+pub fn solve_broadening<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    let mut breadth = 1;
+    loop {
+        match broadening_by(p0.clone(), breadth) {
+            Ok(soln) => return soln,
+            Err(()) => breadth += 1,
+        }
+    }
+}
+
+/// Solve a puzzle using iterative broadening, as configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_broadening(p0)` (or, with a `max_depth`, without expanding the puzzle
+/// states `max_depth` moves from `p0`) if the search finds it within the limits
+/// of `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// The tunables of `config` apply to each BFS as for `solve_with_config`,
+/// except that the time limit (and the cancellation) applies to the whole
+/// sequence of BFSs; the observer of `config` is told of the events of every
+/// BFS, and of the statistics of the last one.
+pub fn solve_broadening_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let start = Instant::now();
+    let limits = config.bfs_limits(&p0, start);
+    let stats = &mut SearchStats::default();
+    let observer = &mut *config.observer.borrow_mut();
+    let mut breadth = 1;
+    let outcome = loop {
+        let mut broad = Broad {
+            breadth,
+            skipped: false,
+        };
+        let index = Scoped::new(config.hasher.clone(), config.dedupe_scope);
+        let mut reached = Reached::with_index(index);
+        let frontier = Fifo::new();
+        let limits = limits.clone();
+        *stats = SearchStats::default();
+        match search(
+            p0.clone(),
+            &mut broad,
+            frontier,
+            &mut reached,
+            limits,
+            stats,
+            observer,
+        ) {
+            Ok(Ended::Exhausted | Ended::Cut) if broad.skipped => breadth += 1,
+            ended => {
+                break SolveOutcome::new(ended, stats.visited, |(i, p)| {
+                    let vec = reached.take_path(i);
+                    observer.on_solution(&vec, &p);
+                    (vec, p)
+                })
+            }
+        }
+    };
+    stats.finish(start, observer);
+    outcome
+}
+
+/// BFS from `p0` considering only the first `breadth` successor puzzle states
+/// of each puzzle state.  Returns `Ok(Some(..))` if a goal state was reached,
+/// `Ok(None)` if no goal state was reached and no successor puzzle state was
+/// skipped, and `Err(())` if no goal state was reached but some successor
+/// puzzle state was skipped.
+#[allow(clippy::type_complexity)]
+fn broadening_by<P>(p0: P, breadth: usize) -> Result<Option<(Vec<P::Move>, P)>, ()>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    let mut broad = Broad {
+        breadth,
+        skipped: false,
+    };
+    match solve_by(p0, &mut broad, Fifo::new(), &mut Reached::new()) {
+        None if broad.skipped => Err(()),
+        soln => Ok(soln),
+    }
+}
+
+/// The hooks of `broadening_by`, which record whether or not a successor
+/// puzzle state was skipped.
+struct Broad {
+    breadth: usize,
+    skipped: bool,
+}
+
+impl<P: Puzzle + Eq + Hash + Clone> Search<P> for Broad {
+    const NAME: &'static str = "solve_broadening";
+    type Key = P;
+    type Frontier = Fifo<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.clone()
+    }
+
+    fn priority(&mut self, _: usize, _: &P) {}
+
+    fn successors(&mut self, p: &P, _: usize, next: &mut Vec<(P::Move, P)>) {
+        next.extend(p.successors().take(self.breadth + 1));
+        if next.len() > self.breadth {
+            self.skipped = true;
+            next.truncate(self.breadth);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+
+/// A fixed-size array of `N` bytes, as the (wide) puzzle state of a puzzle
+/// whose board is an array of bytes (e.g., one byte per cell of a 7x7 board),
+/// which compares and hashes eight bytes at a time.
+///
+/// Puzzle states are compared and hashed on every probe of the hash table of
+/// `solve` (and the other solvers that record visited puzzle states); for a
+/// `[u8; N]` (or a `Vec<u8>`) both are done (one) byte at a time, which can
+/// dominate the search of a wide board.  `ByteArray<N>` is equal (and ordered)
+/// exactly as its array of bytes, but compares (and hashes) the bytes as `u64`
+/// words (with any remaining `N % 8` bytes compared and hashed individually).
+/// A puzzle with a `Vec<u8>` board of a known size can switch to a
+/// `ByteArray<N>` board (which also avoids a heap allocation per puzzle
+/// state).
+#[derive(Debug, Clone, Copy, PartialOrd, Ord)]
+pub struct ByteArray<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> ByteArray<N> {
+    /// The `u64` words of the bytes (in native byte order), followed by the
+    /// remaining bytes.
+    fn words(&self) -> (impl Iterator<Item = u64> + '_, &[u8]) {
+        let chunks = self.0.chunks_exact(8);
+        let rest = chunks.remainder();
+        let words = chunks.map(|chunk| u64::from_ne_bytes(chunk.try_into().unwrap()));
+        (words, rest)
+    }
+}
+
+impl<const N: usize> Default for ByteArray<N> {
+    fn default() -> Self {
+        ByteArray([0; N])
+    }
+}
+
+impl<const N: usize> PartialEq for ByteArray<N> {
+    fn eq(&self, other: &Self) -> bool {
+        let ((ws, rest), (vs, other_rest)) = (self.words(), other.words());
+        ws.zip(vs).all(|(w, v)| w == v) && rest == other_rest
+    }
+}
+
+impl<const N: usize> Eq for ByteArray<N> {}
+
+impl<const N: usize> Hash for ByteArray<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let (words, rest) = self.words();
+        for w in words {
+            state.write_u64(w);
+        }
+        state.write(rest);
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for ByteArray<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        ByteArray(bytes)
+    }
+}
+
+impl<const N: usize> Deref for ByteArray<N> {
+    type Target = [u8; N];
+
+    fn deref(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for ByteArray<N> {
+    fn deref_mut(&mut self) -> &mut [u8; N] {
+        &mut self.0
+    }
+}
@@ -0,0 +1,31 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A token with which a search (configured by `SolverConfig::cancel_token`) can
+/// be cancelled from another thread, e.g., by a GUI or a server whose client
+/// has gone away.
+///
+/// Clones of a token share its state, so cancelling any clone cancels every
+/// search given a clone.  Cancellation is cooperative: a search checks the
+/// token between expansions (or layers, for a parallel search), and then fails
+/// with `SolveError::Cancelled`, releasing its memory; `solve_with_stats` still
+/// returns the statistics of the work done until then.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// A token that is not cancelled.
+    pub fn new() -> Self {
+        CancelToken::default()
+    }
+
+    /// Cancel the searches given this token (or a clone of it).
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed)
+    }
+
+    /// Determines whether or not the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
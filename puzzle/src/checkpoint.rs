@@ -0,0 +1,183 @@
+use std::ffi::OsString;
+use std::fmt::{self, Debug, Formatter};
+use std::fs::{self, File};
+use std::hash::Hash;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::persist::{read_compressed, write_compressed};
+use super::{PersistentPuzzle, Puzzle, Solution, Solver};
+
+/// The state of a BFS with hashing of states (as of `solve`) between runs, so
+/// that it can be saved to disk and resumed later (e.g., after a reboot).
+///
+/// A checkpoint is the whole state of the search (see `Solver`): the initial
+/// puzzle state, the arena of parent indices and moves of the visited puzzle
+/// states, the hash table from (the representatives of) the visited puzzle
+/// states to their nodes, and the queue of puzzle states to expand.  So, a
+/// saved checkpoint is about as large as the memory of the search (before
+/// compression).
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "P: PersistentPuzzle + Eq + Hash")]
+pub struct BfsCheckpoint<P: Puzzle> {
+    // boxed, so that a checkpoint is cheap to move between runs
+    solver: Box<Solver<P>>,
+}
+
+impl<P: Puzzle> Clone for BfsCheckpoint<P>
+where
+    Solver<P>: Clone,
+{
+    fn clone(&self) -> Self {
+        BfsCheckpoint {
+            solver: self.solver.clone(),
+        }
+    }
+}
+
+impl<P: Puzzle> Debug for BfsCheckpoint<P>
+where
+    Solver<P>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("BfsCheckpoint")
+            .field("solver", &self.solver)
+            .finish()
+    }
+}
+
+impl<P> BfsCheckpoint<P>
+where
+    P: PersistentPuzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    /// The checkpoint of a search from `p0` that has not yet expanded any
+    /// puzzle state.
+    pub fn new(p0: P) -> Self {
+        BfsCheckpoint {
+            solver: Box::new(Solver::new(p0)),
+        }
+    }
+
+    /// Read a checkpoint, as written by `save`, from the file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> bincode::Result<Self> {
+        read_compressed(BufReader::new(File::open(path)?))
+    }
+
+    /// Write the checkpoint to the file at `path`.
+    ///
+    /// The checkpoint is written to a temporary file next to `path`, which is
+    /// then renamed to `path`; so, a save that is interrupted (e.g., by a
+    /// crash) leaves any previous checkpoint at `path` intact.
+    pub fn save(&self, path: impl AsRef<Path>) -> bincode::Result<()> {
+        let path = path.as_ref();
+        let mut tmp = OsString::from(path.as_os_str());
+        tmp.push(".tmp");
+        let file = File::create(&tmp)?;
+        let mut w = BufWriter::new(&file);
+        write_compressed(&mut w, self)?;
+        w.flush()?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// The initial puzzle state of the search.
+    pub fn initial(&self) -> &P {
+        self.solver.initial()
+    }
+
+    /// The number of puzzle states visited so far.
+    pub fn states(&self) -> usize {
+        self.solver.states()
+    }
+
+    /// The number of puzzle states still to be expanded.
+    pub fn frontier_len(&self) -> usize {
+        self.solver.frontier().len()
+    }
+
+    /// Continue the search, expanding at most `expansions` puzzle states.
+    ///
+    /// Returns `Ok(soln)` if the search finishes, where `soln` is the same
+    /// result as `solve(p0)` (i.e., `Some((ms,p))` for one of the shortest
+    /// sequence of moves `ms` from `p0` to a goal state `p`, or `None` if `p0`
+    /// cannot be solved by any sequence of moves).
+    ///
+    /// Returns `Err(checkpoint)` if `expansions` puzzle states were expanded
+    /// without finishing, so that the search can be saved and then continued.
+    ///
+    /// Panics if more than `u32::MAX` puzzle states would be visited.
+    pub fn run(mut self, expansions: usize) -> Result<Solution<P>, Self> {
+        if self.solver.step_n(expansions) {
+            Ok(self.solver.into_solution().unwrap())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+/// Solve a puzzle using BFS with hashing of states, saving a checkpoint of the
+/// search to `path` every `interval` expansions, and resuming from the
+/// checkpoint at `path`, if any.
+///
+/// Returns `Ok(soln)`, where `soln` is the same result as `solve(p0)`, once the
+/// search finishes; the checkpoint at `path` is then removed.
+///
+/// Returns `Err(err)` if the checkpoint at `path` cannot be read or written,
+/// or is of a search from another initial puzzle state than `p0`.
+///
+/// So, a long search that is killed (e.g., by a reboot) loses at most the
+/// work of `interval` expansions: calling `solve_checkpointed` again with the
+/// same arguments resumes it from its last checkpoint (see `BfsCheckpoint`).
+///
+/// Panics if `interval` is `0`.
+pub fn solve_checkpointed<P>(
+    p0: P,
+    path: impl AsRef<Path>,
+    interval: usize,
+) -> bincode::Result<Solution<P>>
+where
+    P: PersistentPuzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    if interval == 0 {
+        panic!(
+            "solve_checkpointed interval (is {}) should be positive",
+            interval
+        )
+    }
+    let path = path.as_ref();
+    let mut checkpoint = match BfsCheckpoint::load(path) {
+        Ok(checkpoint) if *checkpoint.initial() == p0 => checkpoint,
+        Ok(_) => {
+            return Err(Box::new(bincode::ErrorKind::Custom(format!(
+                "the checkpoint {:?} is of another initial puzzle state",
+                path
+            ))))
+        }
+        Err(err) => match *err {
+            bincode::ErrorKind::Io(ref io) if io.kind() == io::ErrorKind::NotFound => {
+                BfsCheckpoint::new(p0)
+            }
+            _ => return Err(err),
+        },
+    };
+    loop {
+        match checkpoint.run(interval) {
+            Ok(soln) => {
+                fs::remove_file(path).or_else(|err| match err.kind() {
+                    io::ErrorKind::NotFound => Ok(()),
+                    _ => Err(err),
+                })?;
+                return Ok(soln);
+            }
+            Err(rest) => {
+                rest.save(path)?;
+                checkpoint = rest;
+            }
+        }
+    }
+}
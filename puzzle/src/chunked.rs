@@ -0,0 +1,171 @@
+use std::collections::{vec_deque, VecDeque};
+use std::iter::{Chain, Flatten};
+use std::mem;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::Frontier;
+
+/// The number of elements of each chunk of a `ChunkedQueue`.
+pub const CHUNK_LEN: usize = 1024;
+
+/// A FIFO queue stored as a sequence of fixed-size chunks of `CHUNK_LEN`
+/// elements, as the frontier (the BFS queue) of `solve`.
+///
+/// Unlike a `VecDeque`, which reallocates (and moves) all of its elements
+/// whenever it grows, a chunked queue only allocates a new chunk whenever the
+/// last chunk is full, and frees a chunk whenever the first chunk is empty; so,
+/// a long search has no (long) reallocation pauses, and the elements of a
+/// chunk are contiguous (which also makes a chunk a natural unit to spill to
+/// disk).
+#[derive(Debug, Clone)]
+pub struct ChunkedQueue<T> {
+    /// The full chunks, oldest first, each with its remaining elements.
+    full: VecDeque<std::vec::IntoIter<T>>,
+    /// The chunk being filled, after the full chunks.
+    back: Vec<T>,
+    len: usize,
+}
+
+impl<T> ChunkedQueue<T> {
+    /// An empty queue.
+    pub fn new() -> Self {
+        ChunkedQueue {
+            full: VecDeque::new(),
+            back: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// The number of elements of the queue.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Determines whether or not the queue has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Append `x` to the back of the queue.
+    pub fn push_back(&mut self, x: T) {
+        if self.back.len() == CHUNK_LEN {
+            self.seal();
+        }
+        if self.back.capacity() == 0 {
+            self.back.reserve_exact(CHUNK_LEN);
+        }
+        self.back.push(x);
+        self.len += 1;
+    }
+
+    /// Remove the element at the front of the queue, if any.
+    pub fn pop_front(&mut self) -> Option<T> {
+        loop {
+            if let Some(chunk) = self.full.front_mut() {
+                if let Some(x) = chunk.next() {
+                    self.len -= 1;
+                    return Some(x);
+                }
+                self.full.pop_front();
+            } else if self.back.is_empty() {
+                return None;
+            } else {
+                self.seal();
+            }
+        }
+    }
+
+    /// The element at the front of the queue, if any.
+    pub fn front(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    /// An iterator over the elements of the queue, from front to back.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &T> + '_ {
+        let inner = self.full.iter().flat_map(|chunk| chunk.as_slice());
+        Iter {
+            inner: inner.chain(&self.back),
+            len: self.len,
+        }
+    }
+
+    /// Move the chunk being filled after the full chunks.
+    fn seal(&mut self) {
+        let back = mem::take(&mut self.back);
+        self.full.push_back(back.into_iter());
+    }
+}
+
+impl<T> Default for ChunkedQueue<T> {
+    fn default() -> Self {
+        ChunkedQueue::new()
+    }
+}
+
+impl<T> Frontier<T> for ChunkedQueue<T> {
+    type Priority = ();
+
+    fn push(&mut self, x: T, (): ()) {
+        self.push_back(x)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T> IntoIterator for ChunkedQueue<T> {
+    type Item = T;
+    type IntoIter =
+        Chain<Flatten<vec_deque::IntoIter<std::vec::IntoIter<T>>>, std::vec::IntoIter<T>>;
+
+    /// An iterator over the elements of the queue, from front to back.
+    fn into_iter(self) -> Self::IntoIter {
+        self.full.into_iter().flatten().chain(self.back)
+    }
+}
+
+/// An iterator over the elements of a `ChunkedQueue` (see
+/// `ChunkedQueue::iter`), which knows how many elements remain.
+struct Iter<I> {
+    inner: I,
+    len: usize,
+}
+
+impl<I: Iterator> Iterator for Iter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let x = self.inner.next()?;
+        self.len -= 1;
+        Some(x)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<I: Iterator> ExactSizeIterator for Iter<I> {}
+
+// a queue is serialized as the sequence of its elements, from front to back
+impl<T: Serialize> Serialize for ChunkedQueue<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for ChunkedQueue<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut queue = ChunkedQueue::new();
+        for x in Vec::deserialize(deserializer)? {
+            queue.push_back(x);
+        }
+        Ok(queue)
+    }
+}
@@ -0,0 +1,307 @@
+use std::cmp;
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::hash::{BuildHasher, Hash};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::frontier::{solve_by, solve_by_index, Closed, Reached, Search};
+use super::table::Mapping;
+use super::{Fifo, Observer, PackState, Puzzle, Solution, SolveOutcome, SolverConfig};
+
+/// Trait for the closed sets of searches: the sets of puzzle states that have
+/// been visited, so that each puzzle state is explored (at most) once.
+///
+/// A closed set need only record whether or not a puzzle state was visited
+/// (not the path to it), so that exact sets (e.g., a `HashSet`, a `RankSet`,
+/// or a `DiskSet`) and approximate sets (e.g., a `BloomSet`) can be used
+/// interchangeably by `solve_with_closed_set`.
+pub trait ClosedSet<P> {
+    /// Insert the puzzle state `p`, returning whether or not `p` was newly
+    /// inserted; that is, `false` if `p` was (possibly, for an approximate
+    /// set) already visited.
+    fn insert(&mut self, p: &P) -> bool;
+
+    /// The number of puzzle states newly inserted into the set.
+    fn len(&self) -> usize;
+
+    /// Determines whether or not no puzzle state was inserted into the set.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<P, S> ClosedSet<P> for HashSet<P, S>
+where
+    P: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    fn insert(&mut self, p: &P) -> bool {
+        // only clone puzzle states that are newly inserted
+        !self.contains(p) && HashSet::insert(self, p.clone())
+    }
+
+    fn len(&self) -> usize {
+        HashSet::len(self)
+    }
+}
+
+/// The number of distinct `DiskSet`s created, so that the runs of distinct
+/// sets have distinct file names.
+static DISK_SETS: AtomicUsize = AtomicUsize::new(0);
+
+/// A sorted run of packed puzzle states (as little-endian `u64`s) in a file.
+struct Run {
+    path: PathBuf,
+    map: Mapping,
+}
+
+impl Run {
+    /// Write the sorted packed puzzle states `keys` to a run at `path`.
+    fn create(path: PathBuf, keys: impl IntoIterator<Item = u64>) -> io::Result<Self> {
+        let mut w = BufWriter::new(File::create(&path)?);
+        for key in keys {
+            w.write_all(&key.to_le_bytes())?;
+        }
+        w.into_inner().map_err(io::Error::from)?.sync_all()?;
+        let map = Mapping::new(&File::open(&path)?)?;
+        Ok(Run { path, map })
+    }
+
+    /// The number of packed puzzle states of the run.
+    fn len(&self) -> usize {
+        self.map.bytes().len() / 8
+    }
+
+    /// The packed puzzle states of the run, in order.
+    fn keys(&self) -> impl Iterator<Item = u64> + '_ {
+        self.map
+            .bytes()
+            .chunks_exact(8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    /// The `i`th packed puzzle state of the run.
+    fn key(&self, i: usize) -> u64 {
+        let bytes = &self.map.bytes()[8 * i..8 * i + 8];
+        u64::from_le_bytes(bytes.try_into().unwrap())
+    }
+
+    /// Determines whether or not the packed puzzle state `key` is in the run,
+    /// by binary search.
+    fn contains(&self, key: u64) -> bool {
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self.key(mid).cmp(&key) {
+                cmp::Ordering::Less => lo = mid + 1,
+                cmp::Ordering::Equal => return true,
+                cmp::Ordering::Greater => hi = mid,
+            }
+        }
+        false
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// A closed set of packed puzzle states (see `PackState`, here with `u64`
+/// packed forms) that is mostly on disk.
+///
+/// The most recently inserted packed puzzle states (at most `capacity`) are
+/// kept in memory; whenever they fill up, they are sorted and spilled to a
+/// run (a file in the directory `dir`), which is memory-mapped and searched by
+/// binary search.  Runs of similar lengths are merged, so that there are only
+/// logarithmically many runs to search.  Thus, the memory of the set is
+/// bounded by `capacity` (plus the pages of the runs that the operating system
+/// chooses to cache), while its runs hold every other packed puzzle state.
+/// The runs are removed when the set is dropped.
+pub struct DiskSet {
+    dir: PathBuf,
+    id: usize,
+    capacity: usize,
+    recent: HashSet<u64>,
+    runs: Vec<Run>,
+    files: usize,
+    len: usize,
+}
+
+impl DiskSet {
+    /// An empty set, which spills its packed puzzle states to runs in `dir`
+    /// whenever `capacity` of them are in memory.
+    ///
+    /// Panics if `capacity` is `0`.
+    pub fn new(dir: impl AsRef<Path>, capacity: usize) -> Self {
+        if capacity == 0 {
+            panic!("DiskSet::new capacity (is {}) should be positive", capacity)
+        }
+        DiskSet {
+            dir: dir.as_ref().to_path_buf(),
+            id: DISK_SETS.fetch_add(1, Ordering::Relaxed),
+            capacity,
+            recent: HashSet::with_capacity(capacity),
+            runs: vec![],
+            files: 0,
+            len: 0,
+        }
+    }
+
+    /// The number of runs on disk.
+    pub fn runs(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// The path of a new run.
+    fn run_path(&mut self) -> PathBuf {
+        self.files += 1;
+        self.dir.join(format!(
+            "closed-{}-{}-{}.run",
+            std::process::id(),
+            self.id,
+            self.files
+        ))
+    }
+
+    /// Spill the packed puzzle states in memory to a new run, and then merge
+    /// the last two runs while the last run is at least half as long as the
+    /// run before it.
+    fn spill(&mut self) -> io::Result<()> {
+        let mut keys: Vec<u64> = self.recent.drain().collect();
+        keys.sort_unstable();
+        let path = self.run_path();
+        self.runs.push(Run::create(path, keys)?);
+        loop {
+            let n = self.runs.len();
+            if n < 2 || 2 * self.runs[n - 1].len() < self.runs[n - 2].len() {
+                break;
+            }
+            let path = self.run_path();
+            let keys = merge(self.runs[n - 2].keys(), self.runs[n - 1].keys());
+            let merged = Run::create(path, keys)?;
+            self.runs.truncate(n - 2);
+            self.runs.push(merged);
+        }
+        Ok(())
+    }
+}
+
+/// Merge the sorted sequences `a` and `b`.
+fn merge(a: impl Iterator<Item = u64>, b: impl Iterator<Item = u64>) -> impl Iterator<Item = u64> {
+    let (mut a, mut b) = (a.peekable(), b.peekable());
+    std::iter::from_fn(move || match (a.peek(), b.peek()) {
+        (Some(x), Some(y)) if x <= y => a.next(),
+        (Some(_), Some(_)) => b.next(),
+        (Some(_), None) => a.next(),
+        (None, _) => b.next(),
+    })
+}
+
+impl<P> ClosedSet<P> for DiskSet
+where
+    P: PackState<Packed = u64>,
+{
+    /// Panics if a run cannot be written to (or mapped from) the directory of
+    /// the set.
+    fn insert(&mut self, p: &P) -> bool {
+        let key = p.pack();
+        if self.recent.contains(&key) || self.runs.iter().any(|run| run.contains(key)) {
+            return false;
+        }
+        self.recent.insert(key);
+        self.len += 1;
+        if self.recent.len() == self.capacity {
+            if let Err(err) = self.spill() {
+                panic!(
+                    "DiskSet::insert failed to spill a run to {:?}: {}",
+                    self.dir, err
+                )
+            }
+        }
+        true
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Solve a puzzle using BFS with the closed set `closed` of visited states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  If `closed` is exact, then the sequence of moves
+/// `ms` is one of the shortest sequence of moves from `p0` to a goal state (as
+/// for `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves (or, if
+/// `closed` is approximate, if every solution was wrongly skipped).
+///
+/// Like `solve`, but the visited puzzle states are recorded in `closed` (see
+/// `ClosedSet`), which is chosen per call (e.g., a `HashSet` for speed, a
+/// `RankSet` or `BloomSet` for less memory, or a `DiskSet` for state spaces
+/// that do not fit in memory); the path to each visited puzzle state is
+/// recorded in an arena of parent indices and moves (as for the other searches
+/// with hashing of states, see `solve_with_frontier`).  A successor puzzle
+/// state that `closed` reports as visited is not explored, so a false positive
+/// of an approximate set may miss a (shortest) solution.  The representatives
+/// of puzzle states (see `Puzzle::canonicalize`) and the scores of goal states
+/// (see `Puzzle::goal_score`) are ignored.
+pub fn solve_with_closed_set<P, C>(p0: P, closed: C) -> Solution<P>
+where
+    P: Puzzle,
+    C: ClosedSet<P>,
+{
+    let mut reached = Reached::with_index(Closed(closed));
+    solve_by(p0, &mut Visited, Fifo::new(), &mut reached)
+}
+
+/// Solve a puzzle using BFS with the closed set `closed` of visited states, as
+/// configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_with_closed_set(p0, closed)` (or, with a `max_depth`, without
+/// expanding the puzzle states `max_depth` moves from `p0`) if the search finds
+/// it within the limits of `config`, and otherwise the outcome of the search as
+/// for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_with_config`, except that the
+/// visited puzzle states are recorded in `closed`: the hasher and the
+/// `dedupe_scope` of `config` are ignored.
+pub fn solve_with_closed_set_with_config<P, C, S, O>(
+    p0: P,
+    closed: C,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle,
+    C: ClosedSet<P>,
+    O: Observer<P>,
+{
+    let observer = &mut *config.observer.borrow_mut();
+    solve_by_index(
+        p0,
+        &mut Visited,
+        Fifo::new(),
+        Closed(closed),
+        config,
+        observer,
+    )
+}
+
+/// The hooks of `solve_with_closed_set`, whose closed set records the puzzle
+/// states themselves (so that they need no keys).
+struct Visited;
+
+impl<P: Puzzle> Search<P> for Visited {
+    const NAME: &'static str = "solve_with_closed_set";
+    type Key = ();
+    type Frontier = Fifo<(usize, P)>;
+
+    fn key(&mut self, _: &P) {}
+
+    fn priority(&mut self, _: usize, _: &P) {}
+}
@@ -0,0 +1,302 @@
+use std::cell::RefCell;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
+use std::time::{Duration, Instant};
+
+use super::{
+    bounded_bfs, limit, BfsLimits, CancelToken, MemoryLimit, MemoryLimitExceeded, Observer, Puzzle,
+    SearchStats, SolveOutcome, MAX_NODES,
+};
+
+/// The tunables of a search, as given to `solve_with_config` (and to the
+/// `_with_config` variants of the other solvers, such as
+/// `solve_astar_with_config`, `solve_idastar_with_config` or
+/// `solve_dfs_with_config`), built from `SolverConfig::new()` by chaining the
+/// setters; e.g.,
+/// `SolverConfig::new().max_depth(20).time_limit(Duration::from_secs(2))`.
+/// (The solvers with limits of their own or with results other than a solution,
+/// such as `solve_k`, `solve_all`, `solve_and_or`, `solve_anytime`,
+/// `solve_mcts` or `solve_with_graph`, and the shorthands for a single tunable
+/// of `solve_with_config`, such as `solve_within` or `solve_with_hasher`, have
+/// no `_with_config` variant.)
+///
+/// By default, there are no limits, the hasher is the default SipHash hasher
+/// of `HashMap`, every duplicate puzzle state is detected, parallel solvers
+/// use the default number of threads, the search cannot be cancelled, and
+/// nothing observes it.
+#[derive(Debug, Clone)]
+pub struct SolverConfig<S = RandomState, O = ()> {
+    pub(crate) max_nodes: usize,
+    pub(crate) max_depth: usize,
+    pub(crate) time_limit: Option<Duration>,
+    pub(crate) memory_limit: Option<MemoryLimit>,
+    pub(crate) hasher: S,
+    pub(crate) dedupe_scope: usize,
+    pub(crate) parallelism: usize,
+    pub(crate) cancel: Option<CancelToken>,
+    pub(crate) layer_stats: bool,
+    pub(crate) observer: RefCell<O>,
+}
+
+impl SolverConfig {
+    /// The default configuration (see above).
+    pub fn new() -> Self {
+        SolverConfig {
+            max_nodes: MAX_NODES,
+            max_depth: usize::MAX,
+            time_limit: None,
+            memory_limit: None,
+            hasher: RandomState::new(),
+            dedupe_scope: usize::MAX,
+            parallelism: 0,
+            cancel: None,
+            layer_stats: false,
+            observer: RefCell::new(()),
+        }
+    }
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        SolverConfig::new()
+    }
+}
+
+impl<S, O> SolverConfig<S, O> {
+    /// Fail (with `SolveError::NodeLimit`) once more than `max_nodes` puzzle
+    /// states would be visited.
+    pub fn max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = max_nodes;
+        self
+    }
+
+    /// Only search for sequences of at most `max_depth` moves; the puzzle
+    /// states `max_depth` moves from the initial puzzle state are not expanded
+    /// (as for `solve_within`).
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Fail (with `SolveError::TimeLimit`) once the search has run for
+    /// `time_limit`.
+    pub fn time_limit(mut self, time_limit: Duration) -> Self {
+        self.time_limit = Some(time_limit);
+        self
+    }
+
+    /// Fail (with `SolveError::MemoryLimit`) once the search would use more
+    /// memory than allowed by `memory_limit` (as for `solve_bounded`).
+    pub fn memory_limit(mut self, memory_limit: MemoryLimit) -> Self {
+        self.memory_limit = Some(memory_limit);
+        self
+    }
+
+    /// Hash the (representatives of) puzzle states with the hasher built by
+    /// `hasher` (as for `solve_with_hasher`).
+    pub fn hasher<T>(self, hasher: T) -> SolverConfig<T, O> {
+        SolverConfig {
+            max_nodes: self.max_nodes,
+            max_depth: self.max_depth,
+            time_limit: self.time_limit,
+            memory_limit: self.memory_limit,
+            hasher,
+            dedupe_scope: self.dedupe_scope,
+            parallelism: self.parallelism,
+            cancel: self.cancel,
+            layer_stats: self.layer_stats,
+            observer: self.observer,
+        }
+    }
+
+    /// Only detect duplicate puzzle states within the last `layers` layers
+    /// (the current layer included), forgetting the puzzle states of older
+    /// layers (as for `solve_layered_scoped`, whose caveats apply).
+    ///
+    /// Panics if `layers` is `0`.
+    pub fn dedupe_scope(mut self, layers: usize) -> Self {
+        if layers == 0 {
+            panic!(
+                "SolverConfig::dedupe_scope layers (is {}) should be positive",
+                layers
+            )
+        }
+        self.dedupe_scope = layers;
+        self
+    }
+
+    /// Use `threads` threads in parallel solvers (e.g.,
+    /// `solve_parallel_with_config`), or the default number of threads (one
+    /// per CPU) if `threads` is `0`.  Sequential solvers ignore it.
+    pub fn parallelism(mut self, threads: usize) -> Self {
+        self.parallelism = threads;
+        self
+    }
+
+    /// Fail (with `SolveError::Cancelled`) once `token` is cancelled (see
+    /// `CancelToken`).
+    pub fn cancel_token(mut self, token: CancelToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Record the statistics of each layer of the search in the
+    /// `SearchStats::layers` returned by `solve_with_stats` (if `record`).
+    pub fn layer_stats(mut self, record: bool) -> Self {
+        self.layer_stats = record;
+        self
+    }
+
+    /// Report the progress of the search to `observer` (see `Observer`); e.g.,
+    /// `SolverConfig::new().observer(&mut observer)`, so that `observer` can
+    /// be inspected once the search is done.
+    ///
+    /// A solver panics if `observer` runs a search configured by the same
+    /// configuration.
+    pub fn observer<T>(self, observer: T) -> SolverConfig<S, T> {
+        SolverConfig {
+            max_nodes: self.max_nodes,
+            max_depth: self.max_depth,
+            time_limit: self.time_limit,
+            memory_limit: self.memory_limit,
+            hasher: self.hasher,
+            dedupe_scope: self.dedupe_scope,
+            parallelism: self.parallelism,
+            cancel: self.cancel,
+            layer_stats: self.layer_stats,
+            observer: RefCell::new(observer),
+        }
+    }
+
+    /// The limits of the BFS of `solve` for this configuration.
+    pub(crate) fn bfs_limits<P: Puzzle>(&self, p0: &P, start: Instant) -> BfsLimits {
+        BfsLimits {
+            max_states: self.max_nodes,
+            max_memory_states: self.memory_limit.map_or(usize::MAX, |limit| {
+                limit.max_states(limit::solve_bytes_per_state::<P, P::Move>())
+            }),
+            max_depth: self.max_depth,
+            deadline: self.time_limit.and_then(|limit| start.checked_add(limit)),
+            scope: self.dedupe_scope,
+            cancel: self.cancel.clone(),
+            layer_stats: self.layer_stats,
+            ..BfsLimits::new(p0)
+        }
+    }
+}
+
+impl BfsLimits {
+    /// `Ok(())` if one more puzzle state may be visited after `states` puzzle
+    /// states, and the error of the exceeded limit otherwise; for searches
+    /// that do not run on `search` (which checks its limits itself).
+    pub(crate) fn visit(&self, states: usize) -> Result<(), SolveError> {
+        if states >= self.max_memory_states {
+            Err(SolveError::MemoryLimit(MemoryLimitExceeded { states }))
+        } else if states >= self.max_states {
+            Err(SolveError::NodeLimit { states })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// `Ok(())` if a puzzle state `depth` moves from the initial puzzle state
+    /// may be expanded, after `states` puzzle states were visited, and the
+    /// error of the expired time limit (or of the cancellation) otherwise.
+    pub(crate) fn expand(&self, states: usize, depth: usize) -> Result<(), SolveError> {
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            Err(SolveError::TimeLimit { states, depth })
+        } else if self.cancel.as_ref().is_some_and(CancelToken::is_cancelled) {
+            Err(SolveError::Cancelled { states })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The reason that a search configured by a `SolverConfig` was abandoned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveError {
+    /// More than the `max_nodes` puzzle states would have been visited.
+    NodeLimit { states: usize },
+    /// More memory than the `memory_limit` would have been used.
+    MemoryLimit(MemoryLimitExceeded),
+    /// The `time_limit` expired, after `states` puzzle states were visited,
+    /// while the puzzle states `depth` moves from the initial puzzle state
+    /// (the deepest layer reached) were being expanded.
+    TimeLimit { states: usize, depth: usize },
+    /// The `cancel_token` was cancelled, after `states` puzzle states were
+    /// visited.
+    Cancelled { states: usize },
+}
+
+impl std::fmt::Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SolveError::NodeLimit { states } => {
+                write!(f, "node limit exceeded after {} visited states", states)
+            }
+            SolveError::MemoryLimit(err) => err.fmt(f),
+            SolveError::TimeLimit { states, depth } => write!(
+                f,
+                "time limit exceeded after {} visited states, at depth {}",
+                states, depth
+            ),
+            SolveError::Cancelled { states } => {
+                write!(f, "cancelled after {} visited states", states)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// The best partial result of a search whose time limit expired, as returned
+/// by `solve_anytime_timed`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedOut<P: Puzzle> {
+    /// The number of puzzle states expanded before the time limit expired.
+    pub states: usize,
+    /// The greatest number of moves from the initial puzzle state of an
+    /// expanded puzzle state (the deepest layer reached).
+    pub depth: usize,
+    /// The shortest sequence of moves to a goal state found (and the goal
+    /// state), if any, which need not be a shortest sequence of moves.
+    pub incumbent: Option<(Vec<P::Move>, P)>,
+}
+
+/// Solve a puzzle using BFS with hashing of states, as configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve(p0)` (or,
+/// with a `max_depth`, as `solve_within(p0, max_depth)`) if the search finds
+/// it within the limits of `config`.
+///
+/// Otherwise, returns `SolveOutcome::Unsolvable` if the search proves that no
+/// sequence of moves solves the puzzle, `SolveOutcome::Inconclusive` if it
+/// ends without proving that (at the `max_depth`, or after forgetting puzzle
+/// states beyond the `dedupe_scope`), and `SolveOutcome::Limit(err)` as soon
+/// as a limit of `config` is exceeded (see `SolveError`).
+///
+/// The BFS is that of `solve`, with each tunable of `config` (see
+/// `SolverConfig`) applied; the time limit (and the cancel token) is checked
+/// before each expansion, and the observer of `config` is told of every event
+/// of `Observer`.
+/// The `parallelism` of `config` is ignored (see `solve_parallel_with_config`).
+pub fn solve_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let limits = config.bfs_limits(&p0, Instant::now());
+    bounded_bfs(
+        p0,
+        config.hasher.clone(),
+        limits,
+        &mut SearchStats::default(),
+        &mut *config.observer.borrow_mut(),
+    )
+}
@@ -0,0 +1,150 @@
+use std::hash::{BuildHasher, Hash};
+use std::time::Instant;
+
+use super::observer::Mapped;
+use super::{
+    bounded_bfs, solve, Heuristic, Observer, Puzzle, SearchStats, Solution, SolveOutcome,
+    SolverConfig,
+};
+
+/// A puzzle state whose moves and successor puzzle states are enumerated in
+/// the order of the moves (by `Ord`), regardless of the order in which the
+/// puzzle state `P` enumerates them.
+///
+/// Solvers break ties among equally good sequences of moves by the order of
+/// the successor puzzle states, so a puzzle whose order of moves varies (e.g.,
+/// because it enumerates a `HashMap`, whose iteration order varies from run
+/// to run) may yield a different one of several shortest sequences of moves
+/// from run to run (or platform to platform).  Sorting the successor puzzle
+/// states pins down which one: a sequential solver (e.g., `solve`) then returns
+/// the same sequence of moves for the same `p0` on every run, as is needed for
+/// golden tests.  Parallel solvers remain nondeterministic, since their
+/// threads race to reach each puzzle state first.
+///
+/// The goal states, representatives (see `Puzzle::canonicalize`), costs, and
+/// estimates are those of `P`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Deterministic<P>(pub P);
+
+impl<P> Puzzle for Deterministic<P>
+where
+    P: Puzzle + Clone,
+    P::Move: Ord,
+{
+    type Move = P::Move;
+
+    fn is_goal(&self) -> bool {
+        self.0.is_goal()
+    }
+
+    fn legal_moves(&self) -> Vec<Self::Move> {
+        let mut ms = self.0.legal_moves();
+        ms.sort();
+        ms
+    }
+
+    fn apply_move(&self, m: &Self::Move) -> Self {
+        Deterministic(self.0.apply_move(m))
+    }
+
+    fn next(&self) -> Vec<(Self::Move, Self)> {
+        let mut next: Vec<_> = self
+            .0
+            .successors()
+            .map(|(m, p)| (m, Deterministic(p)))
+            .collect();
+        next.sort_by(|(m, _), (n, _)| m.cmp(n));
+        next
+    }
+
+    fn canonicalize(&self) -> Self {
+        Deterministic(self.0.canonicalize())
+    }
+
+    fn move_cost(&self, m: &Self::Move) -> u32 {
+        self.0.move_cost(m)
+    }
+
+    fn is_dead(&self) -> bool {
+        self.0.is_dead()
+    }
+
+    fn prune(&self) -> bool {
+        self.0.prune()
+    }
+
+    fn lower_bound(&self) -> usize {
+        self.0.lower_bound()
+    }
+
+    fn goal_score(&self) -> i64 {
+        self.0.goal_score()
+    }
+
+    fn order_hint(&self, m: &Self::Move) -> i32 {
+        self.0.order_hint(m)
+    }
+
+    fn state_count_hint(&self) -> usize {
+        self.0.state_count_hint()
+    }
+}
+
+impl<P: Heuristic> Heuristic for Deterministic<P> {
+    fn estimate(&self) -> usize {
+        self.0.estimate()
+    }
+}
+
+/// Solve a puzzle using BFS with hashing of states, expanding the successor
+/// puzzle states in the order of their moves.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`, which should be one of the shortest sequence of
+/// moves from `p0` to a goal state (as with `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// That is, `solve(Deterministic(p0))` (see `Deterministic`); so, of the
+/// shortest sequences of moves, the same one is returned on every run.
+pub fn solve_deterministic<P>(p0: P) -> Solution<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Ord + Clone,
+{
+    solve(Deterministic(p0)).map(|(ms, Deterministic(p))| (ms, p))
+}
+
+/// Solve a puzzle using BFS with hashing of states, expanding the successor
+/// puzzle states in the order of their moves, as configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_deterministic(p0)` (or, with a `max_depth`, without expanding the
+/// puzzle states `max_depth` moves from `p0`) if the search finds it within
+/// the limits of `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// That is, `solve_with_config(Deterministic(p0), config)`, but observed (by
+/// the observer of `config`) as a search of the puzzle states `P`.
+pub fn solve_deterministic_with_config<P, S, O>(
+    p0: P,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Ord + Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let p0 = Deterministic(p0);
+    let limits = config.bfs_limits(&p0, Instant::now());
+    let stats = &mut SearchStats::default();
+    let observer = &mut Mapped(&mut *config.observer.borrow_mut(), inner);
+    let outcome = bounded_bfs(p0, config.hasher.clone(), limits, stats, observer);
+    outcome.map_goal(|Deterministic(p)| p)
+}
+
+/// The puzzle state of `p` (without the order of its moves).
+fn inner<P>(p: &Deterministic<P>) -> &P {
+    &p.0
+}
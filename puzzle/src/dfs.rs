@@ -0,0 +1,275 @@
+use std::convert::Infallible;
+use std::hash::{BuildHasher, Hash};
+use std::time::Instant;
+
+use super::frontier::{solve_by, solve_by_config, Ended, Reached, Search as Hooks, Solved};
+use super::{
+    ordered_next, Frontier, Lifo, Observer, Puzzle, SearchStats, Solution, SolveError,
+    SolveOutcome, SolverConfig,
+};
+
+/// The outcome of a depth-first search bounded by a threshold.
+pub(crate) enum Search<P> {
+    /// A goal state was reached.
+    Found(P),
+    /// No goal state was reached; carries the smallest `f` of the puzzle
+    /// states that exceeded the threshold (or `None` if no puzzle state
+    /// exceeded the threshold, in which case the search space is exhausted).
+    Cutoff(Option<usize>),
+}
+
+/// Solve a puzzle using depth-limited DFS.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`, where `ms.len() <= max_depth`.  Note that, unlike
+/// `solve`, the sequence of moves `ms` need not be one of the shortest sequence
+/// of moves from `p0` to a goal state; it is the first sequence of moves found.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of at most
+/// `max_depth` moves.
+///
+/// A DFS is used to find a sequence of moves from `p0` to a goal state,
+/// abandoning any sequence of moves that (together with `P::lower_bound`) must
+/// be longer than `max_depth`.  Only the puzzle states along the current path
+/// are kept in memory, and a successor puzzle state is skipped if it occurs on
+/// the current path (i.e., would form a cycle).  Because puzzle states are not
+/// hashed, different sequences of moves that lead to the same puzzle state are
+/// explored independently.
+pub fn solve_dfs<P>(p0: P, max_depth: usize) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq,
+{
+    let mut path = vec![p0];
+    let mut moves = Vec::new();
+    let unlimited = &mut |_: &P, _| Ok::<_, Infallible>(true);
+    let Ok(found) = search(&mut path, &mut moves, max_depth, &P::lower_bound, unlimited);
+    match found {
+        Search::Found(p) => Some((moves, p)),
+        Search::Cutoff(_) => None,
+    }
+}
+
+/// Solve a puzzle using depth-limited DFS, as configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_dfs(p0, max_depth)` (or, with a `max_depth` of `config`, without
+/// expanding the puzzle states `max_depth` moves from `p0`) if the search finds
+/// it within the limits of `config`, and otherwise the outcome of the search as
+/// for `solve_with_config` (in particular, `SolveOutcome::Inconclusive` if some
+/// sequence of moves was abandoned at `max_depth`).
+///
+/// The tunables of `config` apply as for `solve_idastar_with_config`.
+pub fn solve_dfs_with_config<P, S, O>(
+    p0: P,
+    max_depth: usize,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq,
+    O: Observer<P>,
+{
+    dfs_by_config(p0, config, |p0, check| {
+        let mut path = vec![p0];
+        let mut moves = Vec::new();
+        Ok(
+            match search(&mut path, &mut moves, max_depth, &P::lower_bound, check)? {
+                Search::Found(p) => Ended::Goal((moves, p)),
+                Search::Cutoff(None) => Ended::Exhausted,
+                Search::Cutoff(Some(_)) => Ended::Cut,
+            },
+        )
+    })
+}
+
+/// Solve a puzzle using DFS with hashing of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  Note that, unlike `solve`, the sequence of moves
+/// `ms` need not be one of the shortest sequence of moves from `p0` to a goal
+/// state (and may be much longer); it is the first sequence of moves found.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// A DFS (with an explicit stack, rather than recursion) is used to find a
+/// sequence of moves from `p0` to a goal state, with a hash table (as with
+/// `solve`) to avoid revisiting puzzle states.  Each successor puzzle state is
+/// checked for being a goal state as soon as it is generated, rather than when
+/// it is expanded.  This is useful to decide quickly whether or not a puzzle
+/// can be solved at all.
+pub fn solve_any<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    solve_by(p0, &mut AnyGoal, Lifo::new(), &mut Reached::new())
+}
+
+/// Solve a puzzle using DFS with hashing of states, as configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_any(p0)`
+/// (or, with a `max_depth`, without expanding the puzzle states `max_depth`
+/// moves from `p0`) if the search finds it within the limits of `config`, and
+/// otherwise the outcome of the search as for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_with_config`.
+pub fn solve_any_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    solve_by_config(p0, &mut AnyGoal, Lifo::new(), config)
+}
+
+/// The hooks of `solve_any`: a DFS (see `solve_with_frontier`) that pushes
+/// the successor puzzle states of each expansion in the order of their hints,
+/// so that the most promising is popped first, up to the first goal state (if
+/// any), which is pushed last, so that it is popped (and solves the search)
+/// next.
+struct AnyGoal;
+
+impl<P: Puzzle + Eq + Hash + Clone> Hooks<P> for AnyGoal {
+    const NAME: &'static str = "solve_any";
+    type Key = P;
+    type Frontier = Lifo<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.clone()
+    }
+
+    fn priority(&mut self, _: usize, _: &P) {}
+
+    fn successors(&mut self, p: &P, _: usize, next: &mut Vec<(P::Move, P)>) {
+        p.next_into(next);
+        next.sort_by_key(|(m, _)| p.order_hint(m));
+    }
+
+    fn push(&mut self, frontier: &mut Lifo<(usize, P)>, batch: &mut Vec<(usize, usize, P)>) {
+        if let Some(k) = batch.iter().position(|(_, _, q)| q.is_goal()) {
+            batch.truncate(k + 1);
+        }
+        for (_, i, q) in batch.drain(..) {
+            frontier.push((i, q), ());
+        }
+    }
+}
+
+/// Depth-first search from the last puzzle state of `path`, bounded by
+/// `bound` on the number of moves so far plus `h`; `moves` is the
+/// sequence of moves leading from the first to the last puzzle state of
+/// `path`.  On `Search::Found`, `moves` is the sequence of moves leading to the
+/// goal state; otherwise, `path` and `moves` are restored to their initial
+/// contents.
+///
+/// Before each puzzle state is expanded, `check` is called with it and the
+/// number of moves to it: `Ok(false)` skips its successor puzzle states
+/// (without a cutoff) and `Err(err)` abandons the search (with `path` and
+/// `moves` left as they are).
+pub(crate) fn search<P, H, C, E>(
+    path: &mut Vec<P>,
+    moves: &mut Vec<P::Move>,
+    bound: usize,
+    h: &H,
+    check: &mut C,
+) -> Result<Search<P>, E>
+where
+    P: Puzzle + Eq,
+    H: Fn(&P) -> usize,
+    C: FnMut(&P, usize) -> Result<bool, E> + ?Sized,
+{
+    let p = match path.last() {
+        Some(p) => p,
+        None => return Ok(Search::Cutoff(None)),
+    };
+    let f = moves.len() + h(p);
+    if f > bound {
+        return Ok(Search::Cutoff(Some(f)));
+    }
+    if p.is_goal() {
+        return Ok(Search::Found(path.pop().unwrap()));
+    }
+    if p.prune() || !check(p, moves.len())? {
+        return Ok(Search::Cutoff(None));
+    }
+    let mut min = None;
+    for (m, q) in ordered_next(p) {
+        // avoid cycles along the current path
+        if path.contains(&q) {
+            continue;
+        }
+        path.push(q);
+        moves.push(m);
+        match search(path, moves, bound, h, check)? {
+            Search::Found(p) => return Ok(Search::Found(p)),
+            Search::Cutoff(Some(f)) => min = Some(min.map_or(f, |min: usize| min.min(f))),
+            Search::Cutoff(None) => {}
+        }
+        moves.pop();
+        path.pop();
+    }
+    Ok(Search::Cutoff(min))
+}
+
+/// The check of a depth-first search (see `search`), which fails with the
+/// error of a limit of a `SolverConfig`.
+pub(crate) type Check<'a, P> = dyn FnMut(&P, usize) -> Result<bool, SolveError> + 'a;
+
+/// Search depth-first by `run`, as configured by `config`: `run` is given
+/// `p0` and a check (see `search`) to call before each expansion, which
+/// enforces the limits of `config` and reports the expansion to its observer,
+/// and returns how its search ended.
+///
+/// Every expansion counts as a visit of a puzzle state towards the
+/// `max_nodes` and the `memory_limit` of `config`, the puzzle states
+/// `max_depth` moves from `p0` are not expanded (and the search is then
+/// inconclusive, rather than exhausted), and the observer of `config` is told
+/// of each expansion, of the solution, and of the number of expansions (as
+/// the statistics of the search).
+pub(crate) fn dfs_by_config<P, S, O, R>(
+    p0: P,
+    config: &SolverConfig<S, O>,
+    run: R,
+) -> SolveOutcome<P>
+where
+    P: Puzzle,
+    O: Observer<P>,
+    R: FnOnce(P, &mut Check<P>) -> Solved<P>,
+{
+    let start = Instant::now();
+    let limits = config.bfs_limits(&p0, start);
+    let (mut stats, mut cut) = (SearchStats::default(), false);
+    let observer = &mut *config.observer.borrow_mut();
+    let ended = run(p0, &mut |p, depth| {
+        if depth >= limits.max_depth {
+            cut = true;
+            return Ok(false);
+        }
+        limits.visit(stats.expanded)?;
+        limits.expand(stats.expanded, depth)?;
+        observer.on_expand(p, depth);
+        stats.expanded += 1;
+        Ok(true)
+    });
+    let ended = ended.map(|ended| match ended {
+        Ended::Exhausted if cut => Ended::Cut,
+        ended => ended,
+    });
+    stats.visited = stats.expanded;
+    let outcome = SolveOutcome::new(ended, stats.visited, |(vec, p)| {
+        observer.on_solution(&vec, &p);
+        stats.depth = Some(vec.len());
+        (vec, p)
+    });
+    stats.finish(start, observer);
+    outcome
+}
+
+/// How a depth-first search that found the solution `soln` (if any) after
+/// exploring every puzzle state within its bounds ended.
+pub(crate) fn ended<P: Puzzle>(soln: Solution<P>) -> Ended<(Vec<P::Move>, P)> {
+    match soln {
+        Some(soln) => Ended::Goal(soln),
+        None => Ended::Exhausted,
+    }
+}
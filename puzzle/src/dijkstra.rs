@@ -0,0 +1,51 @@
+use std::hash::{BuildHasher, Hash};
+
+use super::astar::{astar_by, astar_by_config};
+use super::{Observer, Puzzle, SolveOutcome, SolverConfig};
+
+/// Solve a puzzle using Dijkstra's algorithm with hashing of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  The sequence of moves `ms` should be one of the
+/// cheapest sequence of moves from `p0` to a goal state, where the cost of a
+/// sequence of moves is the sum of the `P::move_cost` of each move; that is,
+/// for any sequence of moves `ns` from `p0` to a goal state, the cost of `ms`
+/// is at most the cost of `ns`.  Note that `ms` need not be one of the shortest
+/// sequence of moves from `p0` to a goal state.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// Whereas `solve` uses a FIFO queue, so that puzzle states are expanded in
+/// order of the number of moves from `p0`, a priority queue is used, so that
+/// puzzle states are expanded in order of the cost of the cheapest sequence of
+/// moves (found so far) from `p0`.
+pub fn solve_min_cost<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    astar_by(p0, |p, m| p.move_cost(m) as usize, |g, _| g as f64, true)
+}
+
+/// Solve a puzzle using Dijkstra's algorithm with hashing of states, as
+/// configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_min_cost(p0)` (or, with a `max_depth`, without expanding the puzzle
+/// states whose cost from `p0` reaches `max_depth`) if the search finds it
+/// within the limits of `config`, and otherwise the outcome of the search as
+/// for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_astar_with_config`, except
+/// that `max_depth` bounds the cost of the moves (see `Puzzle::move_cost`)
+/// rather than their number.
+pub fn solve_min_cost_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let cost = |p: &P, m: &P::Move| p.move_cost(m) as usize;
+    astar_by_config(p0, cost, |g, _| g as f64, true, config)
+}
@@ -0,0 +1,310 @@
+use std::any::Any;
+use std::fmt::{self, Debug};
+use std::hash::{Hash, Hasher};
+
+use super::Puzzle;
+
+/// Object-safe counterpart of the moves of a `Puzzle`, so that moves of
+/// different types can be held behind a `Box<dyn DynMove>`.
+///
+/// Every `'static` type that implements `Debug`, `Eq`, and `Clone` implements
+/// `DynMove`.
+pub trait DynMove: Debug {
+    /// The move, as a value of an unknown type (for downcasting).
+    fn as_any(&self) -> &dyn Any;
+
+    /// Determines whether or not the move is equal to `other`; moves of
+    /// different types are never equal.
+    fn dyn_eq(&self, other: &dyn DynMove) -> bool;
+
+    /// Clones the move into a new box.
+    fn clone_box(&self) -> Box<dyn DynMove>;
+}
+
+impl<M> DynMove for M
+where
+    M: Any + Debug + Eq + Clone,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn DynMove) -> bool {
+        other.as_any().downcast_ref::<M>() == Some(self)
+    }
+
+    fn clone_box(&self) -> Box<dyn DynMove> {
+        Box::new(self.clone())
+    }
+}
+
+/// Object-safe counterpart of `Puzzle`, so that puzzle states of different
+/// types can be held behind a `Box<dyn DynPuzzle>` (e.g., by a registry of
+/// puzzles that is only known at runtime) and solved by the same solvers (see
+/// `BoxedPuzzle`).
+///
+/// Every `'static` type that implements `Puzzle`, `Debug`, `Eq`, `Hash`, and
+/// `Clone` (and whose moves implement `DynMove`) implements `DynPuzzle`, by
+/// boxing its moves and successor puzzle states.
+pub trait DynPuzzle: Debug {
+    /// The puzzle state, as a value of an unknown type (for downcasting).
+    fn as_any(&self) -> &dyn Any;
+
+    /// See `Puzzle::is_goal`.
+    fn is_goal(&self) -> bool;
+
+    /// See `Puzzle::legal_moves`.
+    fn legal_moves(&self) -> Vec<Box<dyn DynMove>>;
+
+    /// See `Puzzle::apply_move`.
+    ///
+    /// Panics if `m` is not a move of this puzzle's type.
+    fn apply_move(&self, m: &dyn DynMove) -> Box<dyn DynPuzzle>;
+
+    /// See `Puzzle::next`.
+    fn next(&self) -> Vec<(Box<dyn DynMove>, Box<dyn DynPuzzle>)>;
+
+    /// See `Puzzle::move_cost`.
+    ///
+    /// Panics if `m` is not a move of this puzzle's type.
+    fn move_cost(&self, m: &dyn DynMove) -> u32;
+
+    /// See `Puzzle::is_dead`.
+    fn is_dead(&self) -> bool;
+
+    /// See `Puzzle::prune`.
+    fn prune(&self) -> bool;
+
+    /// See `Puzzle::lower_bound`.
+    fn lower_bound(&self) -> usize;
+
+    /// See `Puzzle::goal_score`.
+    fn goal_score(&self) -> i64;
+
+    /// See `Puzzle::order_hint`.
+    ///
+    /// Panics if `m` is not a move of this puzzle's type.
+    fn order_hint(&self, m: &dyn DynMove) -> i32;
+
+    /// Determines whether or not the puzzle state is equal to `other`; puzzle
+    /// states of different types are never equal.
+    fn dyn_eq(&self, other: &dyn DynPuzzle) -> bool;
+
+    /// Feeds the puzzle state into `state`.
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+
+    /// Clones the puzzle state into a new box.
+    fn clone_box(&self) -> Box<dyn DynPuzzle>;
+}
+
+/// Downcast a move to a move of the puzzle type `P`, or panic.
+fn downcast_move<'a, P: Puzzle>(fn_name: &str, m: &'a dyn DynMove) -> &'a P::Move
+where
+    P::Move: Any,
+{
+    match m.as_any().downcast_ref() {
+        Some(m) => m,
+        None => panic!("{} m (is {:?}) should be a move of the puzzle", fn_name, m),
+    }
+}
+
+impl<P> DynPuzzle for P
+where
+    P: Puzzle + Any + Debug + Eq + Hash + Clone,
+    P::Move: DynMove + Any,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_goal(&self) -> bool {
+        Puzzle::is_goal(self)
+    }
+
+    fn legal_moves(&self) -> Vec<Box<dyn DynMove>> {
+        Puzzle::legal_moves(self)
+            .into_iter()
+            .map(|m| Box::new(m) as Box<dyn DynMove>)
+            .collect()
+    }
+
+    fn apply_move(&self, m: &dyn DynMove) -> Box<dyn DynPuzzle> {
+        let m = downcast_move::<P>("DynPuzzle::apply_move", m);
+        Box::new(Puzzle::apply_move(self, m))
+    }
+
+    fn next(&self) -> Vec<(Box<dyn DynMove>, Box<dyn DynPuzzle>)> {
+        self.successors()
+            .map(|(m, p)| {
+                (
+                    Box::new(m) as Box<dyn DynMove>,
+                    Box::new(p) as Box<dyn DynPuzzle>,
+                )
+            })
+            .collect()
+    }
+
+    fn move_cost(&self, m: &dyn DynMove) -> u32 {
+        let m = downcast_move::<P>("DynPuzzle::move_cost", m);
+        Puzzle::move_cost(self, m)
+    }
+
+    fn is_dead(&self) -> bool {
+        Puzzle::is_dead(self)
+    }
+
+    fn prune(&self) -> bool {
+        Puzzle::prune(self)
+    }
+
+    fn lower_bound(&self) -> usize {
+        Puzzle::lower_bound(self)
+    }
+
+    fn goal_score(&self) -> i64 {
+        Puzzle::goal_score(self)
+    }
+
+    fn order_hint(&self, m: &dyn DynMove) -> i32 {
+        let m = downcast_move::<P>("DynPuzzle::order_hint", m);
+        Puzzle::order_hint(self, m)
+    }
+
+    fn dyn_eq(&self, other: &dyn DynPuzzle) -> bool {
+        other.as_any().downcast_ref::<P>() == Some(self)
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        self.hash(&mut state)
+    }
+
+    fn clone_box(&self) -> Box<dyn DynPuzzle> {
+        Box::new(self.clone())
+    }
+}
+
+/// A boxed move of a `BoxedPuzzle`.
+pub struct BoxedMove(pub Box<dyn DynMove>);
+
+impl BoxedMove {
+    /// The move, if it is a move of type `M`.
+    pub fn downcast_ref<M: Any>(&self) -> Option<&M> {
+        self.0.as_any().downcast_ref()
+    }
+}
+
+impl Clone for BoxedMove {
+    fn clone(&self) -> Self {
+        BoxedMove(self.0.clone_box())
+    }
+}
+
+impl PartialEq for BoxedMove {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.dyn_eq(&*other.0)
+    }
+}
+
+impl Eq for BoxedMove {}
+
+impl Debug for BoxedMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A boxed puzzle state of an arbitrary `DynPuzzle`, which implements
+/// `Puzzle` (with moves of type `BoxedMove`), `Eq`, `Hash`, and `Clone`, so
+/// that it can be solved by any solver that does not require further traits
+/// (e.g., `solve`, `solve_dfs`, or `solve_min_cost`).
+///
+/// Every move and successor puzzle state is boxed, so solving a `BoxedPuzzle`
+/// is slower than solving the underlying puzzle directly; the solution can be
+/// recovered with `BoxedMove::downcast_ref` and `BoxedPuzzle::downcast_ref`.
+pub struct BoxedPuzzle(pub Box<dyn DynPuzzle>);
+
+impl BoxedPuzzle {
+    /// Box the puzzle state `p`.
+    pub fn new<P: DynPuzzle + 'static>(p: P) -> Self {
+        BoxedPuzzle(Box::new(p))
+    }
+
+    /// The puzzle state, if it is a puzzle state of type `P`.
+    pub fn downcast_ref<P: Any>(&self) -> Option<&P> {
+        self.0.as_any().downcast_ref()
+    }
+}
+
+impl Puzzle for BoxedPuzzle {
+    type Move = BoxedMove;
+
+    fn is_goal(&self) -> bool {
+        self.0.is_goal()
+    }
+
+    fn legal_moves(&self) -> Vec<Self::Move> {
+        self.0.legal_moves().into_iter().map(BoxedMove).collect()
+    }
+
+    fn apply_move(&self, m: &Self::Move) -> Self {
+        BoxedPuzzle(self.0.apply_move(&*m.0))
+    }
+
+    fn next(&self) -> Vec<(Self::Move, Self)> {
+        self.0
+            .next()
+            .into_iter()
+            .map(|(m, p)| (BoxedMove(m), BoxedPuzzle(p)))
+            .collect()
+    }
+
+    fn move_cost(&self, m: &Self::Move) -> u32 {
+        self.0.move_cost(&*m.0)
+    }
+
+    fn is_dead(&self) -> bool {
+        self.0.is_dead()
+    }
+
+    fn prune(&self) -> bool {
+        self.0.prune()
+    }
+
+    fn lower_bound(&self) -> usize {
+        self.0.lower_bound()
+    }
+
+    fn goal_score(&self) -> i64 {
+        self.0.goal_score()
+    }
+
+    fn order_hint(&self, m: &Self::Move) -> i32 {
+        self.0.order_hint(&*m.0)
+    }
+}
+
+impl Clone for BoxedPuzzle {
+    fn clone(&self) -> Self {
+        BoxedPuzzle(self.0.clone_box())
+    }
+}
+
+impl PartialEq for BoxedPuzzle {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.dyn_eq(&*other.0)
+    }
+}
+
+impl Eq for BoxedPuzzle {}
+
+impl Hash for BoxedPuzzle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.dyn_hash(state)
+    }
+}
+
+impl Debug for BoxedPuzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,99 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::Puzzle;
+
+/// An estimate of the size of the search tree of a puzzle, as returned by
+/// `estimate_tree_size`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeSizeEstimate {
+    /// The estimated number of nodes of the search tree (i.e., sequences of
+    /// moves from the initial puzzle state) of each depth from `0` to the
+    /// maximum depth, starting with the (single) node of depth `0`.
+    pub layers: Vec<f64>,
+}
+
+impl TreeSizeEstimate {
+    /// The estimated number of nodes of the search tree (of every depth).
+    pub fn nodes(&self) -> f64 {
+        self.layers.iter().sum()
+    }
+}
+
+/// Estimate the size of the search tree of a puzzle using Knuth's method of
+/// random probes.
+///
+/// Returns an estimate of the number of sequences of at most `max_depth` moves
+/// from `p0` that pass through no goal state (except possibly at the end) and
+/// no puzzle state pruned by `Puzzle::prune`; that is, of the number of nodes
+/// of the search tree explored by a depth-limited DFS from `p0` (without
+/// detection of cycles or redundant puzzle states).
+///
+/// Each of the `probes` probes is a random walk from `p0`, which chooses one
+/// successor puzzle state uniformly at random at each step, until it reaches a
+/// goal state, a puzzle state without successor puzzle states, or `max_depth`
+/// moves.  For a walk whose first `d` puzzle states have `b_0, b_1, ...,
+/// b_(d-1)` successor puzzle states, `b_0 * b_1 * ... * b_(d-1)` is an unbiased
+/// estimate of the number of nodes of depth `d`; these estimates are averaged
+/// over the probes.  The random number generator is seeded with `seed`, so the
+/// same `seed` always returns the same estimate.
+///
+/// Because every puzzle state reached by `solve` is reached by at least one
+/// sequence of moves, the number of nodes also bounds the number of puzzle
+/// states (and so, the memory) that `solve` needs to search to depth
+/// `max_depth`; for puzzles with many transpositions, the bound can be very
+/// loose.  The estimate is cheap (at most `probes * max_depth` expansions), but
+/// has a high variance for unbalanced search trees.
+pub fn estimate_tree_size<P>(p0: &P, max_depth: usize, probes: usize, seed: u64) -> TreeSizeEstimate
+where
+    P: Puzzle + Clone,
+{
+    let mut rng = StdRng::seed_from_u64(seed);
+    estimate_tree_size_with_rng(p0, max_depth, probes, &mut rng)
+}
+
+/// Estimate the size of the search tree of a puzzle using Knuth's method of
+/// random probes, whose random walks draw from the random number generator
+/// `rng`.
+///
+/// Returns the same estimate as `estimate_tree_size`, but of the probes drawn
+/// from `rng` (so, e.g., repeated calls with the same `rng` take different
+/// probes, and their estimates can be combined).
+pub fn estimate_tree_size_with_rng<P, R>(
+    p0: &P,
+    max_depth: usize,
+    probes: usize,
+    rng: &mut R,
+) -> TreeSizeEstimate
+where
+    P: Puzzle + Clone,
+    R: Rng + ?Sized,
+{
+    if probes == 0 {
+        panic!(
+            "estimate_tree_size probes (is {}) should be positive",
+            probes
+        )
+    }
+    let mut layers = vec![0.0; max_depth + 1];
+    for _ in 0..probes {
+        let mut p = p0.clone();
+        let mut width = 1.0;
+        for (depth, layer) in layers.iter_mut().enumerate() {
+            *layer += width;
+            if depth == max_depth || p.is_goal() || p.prune() {
+                break;
+            }
+            let mut next = p.next();
+            if next.is_empty() {
+                break;
+            }
+            width *= next.len() as f64;
+            p = next.swap_remove(rng.gen_range(0..next.len())).1;
+        }
+    }
+    for layer in layers.iter_mut() {
+        *layer /= probes as f64;
+    }
+    TreeSizeEstimate { layers }
+}
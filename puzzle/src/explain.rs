@@ -0,0 +1,142 @@
+use std::collections::hash_map::RandomState;
+use std::hash::Hash;
+
+use super::{bounded_bfs, BfsLimits, Observer, Puzzle, SearchStats, SolveError};
+
+/// A report of why a puzzle cannot be solved, as returned by
+/// `solve_or_explain`: the BFS visited every reachable puzzle state without
+/// reaching a goal state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsolvableReport<P> {
+    /// The number of visited puzzle states (i.e., the puzzle states reachable
+    /// from the initial puzzle state, up to representatives, see
+    /// `Puzzle::canonicalize`, and short of dead ends and pruned puzzle
+    /// states, see `Puzzle::is_dead` and `Puzzle::prune`).
+    pub states: usize,
+    /// The greatest number of moves from the initial puzzle state of any
+    /// visited puzzle state.
+    pub max_depth: usize,
+    /// The visited puzzle state closest to a goal state, if measured (see
+    /// `solve_or_explain_by`).
+    pub closest: Option<ClosestState<P>>,
+}
+
+/// The visited puzzle state closest to a goal state (see `UnsolvableReport`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClosestState<P> {
+    /// The puzzle state.
+    pub state: P,
+    /// Its number of moves from the initial puzzle state.
+    pub depth: usize,
+    /// Its distance to a goal state, by the metric of `solve_or_explain_by`.
+    pub distance: usize,
+}
+
+/// The observer of the BFS of `solve_or_explain_by`, which tracks the
+/// greatest depth and the closest puzzle state.
+struct Explainer<P, F> {
+    metric: Option<F>,
+    max_depth: usize,
+    closest: Option<ClosestState<P>>,
+}
+
+impl<P: Clone, F: FnMut(&P) -> usize> Explainer<P, F> {
+    /// Measure the puzzle state `p`, `depth` moves from the initial puzzle
+    /// state; of equally close puzzle states, the first reached is kept.
+    fn visit(&mut self, p: &P, depth: usize) {
+        self.max_depth = self.max_depth.max(depth);
+        if let Some(metric) = &mut self.metric {
+            let distance = metric(p);
+            if self.closest.as_ref().is_none_or(|c| distance < c.distance) {
+                self.closest = Some(ClosestState {
+                    state: p.clone(),
+                    depth,
+                    distance,
+                });
+            }
+        }
+    }
+}
+
+impl<P, F> Observer<P> for Explainer<P, F>
+where
+    P: Puzzle + Clone,
+    F: FnMut(&P) -> usize,
+{
+    fn on_discover(&mut self, p: &P, _: &P, depth: usize) {
+        self.visit(p, depth)
+    }
+}
+
+/// Solve a puzzle using BFS with hashing of states, or explain why it cannot
+/// be solved.
+///
+/// Returns `Ok((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`, which should be one of the shortest sequence of
+/// moves from `p0` to a goal state (as for `solve`).
+///
+/// Returns `Err(report)` if `p0` cannot be solved by any sequence of moves,
+/// where `report` counts the puzzle states reachable from `p0` and their
+/// greatest number of moves from `p0` (see `UnsolvableReport`); so, a claim
+/// that `p0` is solvable can be answered with more than `None`.
+///
+/// Panics if more than `u32::MAX` puzzle states would be visited.
+pub fn solve_or_explain<P>(p0: P) -> Result<(Vec<P::Move>, P), UnsolvableReport<P>>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    explain(p0, None::<fn(&P) -> usize>)
+}
+
+/// Solve a puzzle using BFS with hashing of states, or explain why it cannot
+/// be solved, measuring how close the reachable puzzle states come to a goal
+/// state by `metric`.
+///
+/// Returns the same result as `solve_or_explain(p0)`, except that the report of
+/// an unsolvable `p0` also has the reachable puzzle state closest to a goal
+/// state (see `ClosestState`): the one of least `metric` (e.g., the number of
+/// pieces out of place, such as `Heuristic::estimate`), and of those, the one
+/// with the fewest moves from `p0`.
+///
+/// Panics if more than `u32::MAX` puzzle states would be visited.
+pub fn solve_or_explain_by<P, F>(p0: P, metric: F) -> Result<(Vec<P::Move>, P), UnsolvableReport<P>>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    F: FnMut(&P) -> usize,
+{
+    explain(p0, Some(metric))
+}
+
+/// The BFS of `solve_or_explain_by`, which measures the puzzle states by
+/// `metric`, if any.
+fn explain<P, F>(p0: P, metric: Option<F>) -> Result<(Vec<P::Move>, P), UnsolvableReport<P>>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    F: FnMut(&P) -> usize,
+{
+    let mut explainer = Explainer {
+        metric,
+        max_depth: 0,
+        closest: None,
+    };
+    explainer.visit(&p0, 0);
+    let limits = BfsLimits::new(&p0);
+    let mut stats = SearchStats::default();
+    let outcome = bounded_bfs(p0, RandomState::new(), limits, &mut stats, &mut explainer);
+    match outcome.into_result() {
+        Ok(Some(soln)) => Ok(soln),
+        Ok(None) => Err(UnsolvableReport {
+            states: stats.visited,
+            max_depth: explainer.max_depth,
+            closest: explainer.closest,
+        }),
+        Err(SolveError::NodeLimit { states }) => panic!(
+            "solve_or_explain states (is {}) should not exceed u32::MAX",
+            states
+        ),
+        Err(err) => unreachable!("solve_or_explain has no limit for {:?}", err),
+    }
+}
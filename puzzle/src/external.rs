@@ -0,0 +1,438 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use super::frontier::{Ended, Solved};
+use super::{
+    BfsLimits, Observer, PersistentPuzzle, SearchStats, Solution, SolveOutcome, SolverConfig,
+};
+
+/// The number of directories created for external-memory searches, so that
+/// concurrent searches in the same directory have distinct directories.
+static SEARCHES: AtomicUsize = AtomicUsize::new(0);
+
+/// The files of an external-memory search, in a directory of their own, which
+/// is removed (with the files) when dropped.
+struct Files(PathBuf);
+
+impl Files {
+    /// A new, empty directory in the directory `dir`, whose name is distinct
+    /// from those of the other entries of `dir`.
+    fn new(dir: &Path) -> io::Result<Self> {
+        loop {
+            let path = dir.join(format!(
+                "solve_external-{}-{}",
+                std::process::id(),
+                SEARCHES.fetch_add(1, Ordering::Relaxed)
+            ));
+            match fs::create_dir(&path) {
+                Ok(()) => return Ok(Files(path)),
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Create a file named `name` in the directory of the search.
+    fn create(&mut self, name: String) -> bincode::Result<(PathBuf, BufWriter<File>)> {
+        let path = self.0.join(name);
+        Ok((path.clone(), BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl Drop for Files {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// A sorted sequence of puzzle states in a file, read one at a time.
+struct Run<P> {
+    reader: BufReader<File>,
+    head: Option<P>,
+}
+
+impl<P: PersistentPuzzle> Run<P> {
+    fn open(path: &Path) -> bincode::Result<Self> {
+        let mut run = Run {
+            reader: BufReader::new(File::open(path)?),
+            head: None,
+        };
+        run.advance()?;
+        Ok(run)
+    }
+
+    /// Replace the head of the run by the next puzzle state (if any).
+    fn advance(&mut self) -> bincode::Result<()> {
+        self.head = if self.reader.fill_buf()?.is_empty() {
+            None
+        } else {
+            Some(bincode::deserialize_from(&mut self.reader)?)
+        };
+        Ok(())
+    }
+
+    /// Call `f` on each of the (remaining) puzzle states of the run.
+    fn for_each(mut self, mut f: impl FnMut(P)) -> bincode::Result<()> {
+        while let Some(p) = self.head.take() {
+            self.advance()?;
+            f(p);
+        }
+        Ok(())
+    }
+}
+
+/// The maximum number of runs merged at once, so that a layer spilled in many
+/// runs does not open too many files at once.
+const FAN_IN: usize = 64;
+
+/// A merge of sorted runs into one sorted sequence of distinct puzzle states,
+/// by a heap of the heads of the runs.
+struct Merge<P> {
+    runs: Vec<Run<P>>,
+    heap: BinaryHeap<Reverse<(P, usize)>>,
+}
+
+impl<P: PersistentPuzzle + Ord> Merge<P> {
+    fn open(paths: &[PathBuf]) -> bincode::Result<Self> {
+        let mut merge = Merge {
+            runs: Vec::with_capacity(paths.len()),
+            heap: BinaryHeap::with_capacity(paths.len()),
+        };
+        for path in paths {
+            let mut run = Run::open(path)?;
+            if let Some(p) = run.head.take() {
+                merge.heap.push(Reverse((p, merge.runs.len())));
+            }
+            merge.runs.push(run);
+        }
+        Ok(merge)
+    }
+
+    /// The least remaining puzzle state of the runs (if any), skipping its
+    /// duplicates.
+    fn next(&mut self) -> bincode::Result<Option<P>> {
+        let Some(Reverse((p, i))) = self.heap.pop() else {
+            return Ok(None);
+        };
+        self.refill(i)?;
+        while self.heap.peek().is_some_and(|Reverse((q, _))| *q == p) {
+            let Reverse((_, j)) = self.heap.pop().unwrap();
+            self.refill(j)?;
+        }
+        Ok(Some(p))
+    }
+
+    /// Push the next puzzle state of the run `i` (if any) onto the heap.
+    fn refill(&mut self, i: usize) -> bincode::Result<()> {
+        self.runs[i].advance()?;
+        if let Some(p) = self.runs[i].head.take() {
+            self.heap.push(Reverse((p, i)));
+        }
+        Ok(())
+    }
+}
+
+/// Merge the sorted runs `runs` in passes of (at most) `FAN_IN` runs, until
+/// at most `FAN_IN` runs remain; the merged runs are removed.
+fn reduce_runs<P>(
+    files: &mut Files,
+    name: &str,
+    mut runs: Vec<PathBuf>,
+) -> bincode::Result<Vec<PathBuf>>
+where
+    P: PersistentPuzzle + Ord,
+{
+    let mut pass = 0;
+    while runs.len() > FAN_IN {
+        pass += 1;
+        let mut merged = vec![];
+        for group in runs.chunks(FAN_IN) {
+            let (path, mut w) =
+                files.create(format!("{}-pass{}-run{}.bin", name, pass, merged.len()))?;
+            let mut merge = Merge::<P>::open(group)?;
+            while let Some(p) = merge.next()? {
+                bincode::serialize_into(&mut w, &p)?;
+            }
+            w.flush()?;
+            merged.push(path);
+            for path in group {
+                let _ = fs::remove_file(path);
+            }
+        }
+        runs = merged;
+    }
+    Ok(runs)
+}
+
+/// Write the puzzle states `ps` to `w`, and flush it.
+fn write_all<'a, P: PersistentPuzzle + 'a>(
+    mut w: BufWriter<File>,
+    ps: impl IntoIterator<Item = &'a P>,
+) -> bincode::Result<()> {
+    for p in ps {
+        bincode::serialize_into(&mut w, p)?;
+    }
+    w.flush()?;
+    Ok(())
+}
+
+/// Solve a puzzle using external-memory BFS, with the layers of puzzle states
+/// stored in sorted files in the directory `dir`.
+///
+/// Returns `Ok(Some((ms,p)))` if puzzle `p0` can be solved by the sequence of
+/// moves `ms` to a goal state `p`, which should be one of the shortest
+/// sequence of moves from `p0` to a goal state (as for `solve`); `Ok(None)` if
+/// `p0` cannot be solved by any sequence of moves; and `Err(err)` if reading or
+/// writing a file failed.
+///
+/// Each layer of the BFS (the puzzle states with the same number of moves from
+/// `p0`) is a file of sorted, distinct puzzle states (encoded with `bincode`),
+/// so that only (at most) `chunk` puzzle states are in memory at once.  The
+/// successor puzzle states of a layer are collected in chunks of `chunk`
+/// puzzle states, each of which is sorted and written to a file; the files of
+/// the chunks are then merged (by a heap of their heads, in passes of at most
+/// 64 files, as in an external sort), which detects duplicates, and at the
+/// same time compared with the files of all of the previous layers (the
+/// visited set), which are also sorted.  The moves of the solution are
+/// recovered by scanning each previous layer (backwards) for a predecessor
+/// puzzle state.  Thus, the disk space (rather than memory) is proportional to
+/// the number of reached puzzle states, and the previous layers are all read
+/// again for each new layer.  Unlike `solve`, the representatives of puzzle
+/// states (see `Puzzle::canonicalize`) are ignored.
+///
+/// The files are written to a new subdirectory of `dir` (named after the
+/// process and the search, so that no existing file of `dir` is touched, and
+/// concurrent searches in `dir` do not collide), which is removed (with its
+/// files) before returning.
+///
+/// Panics if `chunk` is `0`.
+pub fn solve_external<P>(p0: P, dir: &Path, chunk: usize) -> bincode::Result<Solution<P>>
+where
+    P: PersistentPuzzle + Ord + Clone,
+{
+    if chunk == 0 {
+        panic!("solve_external chunk (is {}) should be positive", chunk)
+    }
+    let limits = BfsLimits {
+        max_states: usize::MAX,
+        ..BfsLimits::new(&p0)
+    };
+    let stats = &mut SearchStats::default();
+    match external(p0, dir, chunk, &limits, stats, &mut ())? {
+        Ok(ended) => Ok(ended.goal()),
+        Err(err) => unreachable!("solve_external has no limit for {:?}", err),
+    }
+}
+
+/// Solve a puzzle using external-memory BFS, with the layers of puzzle states
+/// stored in sorted files in the directory `dir`, as configured by `config`.
+///
+/// Returns `Ok(SolveOutcome::Solved(..))` with the same solution as
+/// `solve_external(p0, dir, chunk)` (or, with a `max_depth`, without expanding
+/// the puzzle states `max_depth` moves from `p0`) if the search finds it
+/// within the limits of `config`, `Ok(outcome)` with the outcome of the search
+/// as for `solve_with_config` otherwise, and `Err(err)` if reading or writing a
+/// file failed.
+///
+/// The puzzle states of every layer count towards the `max_nodes` (and the
+/// `memory_limit`, although they are on disk) of `config`, as the layer is
+/// written, and the time limit (and the cancel token) is checked before each
+/// layer is expanded.  No puzzle states are hashed, so the `hasher` of
+/// `config` is ignored, as are its `dedupe_scope` (every previous layer is
+/// read to detect duplicates) and its `parallelism`.  The predecessors of
+/// puzzle states are only recovered for the solution, so the observer of
+/// `config` is told of each expansion, of each completed layer, of the
+/// solution, and of the statistics of the search, but not of each discovered
+/// puzzle state.
+///
+/// Panics if `chunk` is `0`.
+pub fn solve_external_with_config<P, S, O>(
+    p0: P,
+    dir: &Path,
+    chunk: usize,
+    config: &SolverConfig<S, O>,
+) -> bincode::Result<SolveOutcome<P>>
+where
+    P: PersistentPuzzle + Ord + Clone,
+    O: Observer<P>,
+{
+    if chunk == 0 {
+        panic!(
+            "solve_external_with_config chunk (is {}) should be positive",
+            chunk
+        )
+    }
+    let start = Instant::now();
+    let limits = config.bfs_limits(&p0, start);
+    let (mut stats, observer) = (SearchStats::default(), &mut *config.observer.borrow_mut());
+    let ended = external(p0, dir, chunk, &limits, &mut stats, observer)?;
+    let outcome = SolveOutcome::new(ended, stats.visited, |(vec, p)| {
+        observer.on_solution(&vec, &p);
+        stats.depth = Some(vec.len());
+        (vec, p)
+    });
+    stats.finish(start, observer);
+    Ok(outcome)
+}
+
+/// External-memory BFS (see `solve_external`) within the `max_depth`,
+/// `max_states`, `max_memory_states`, `deadline` (and `cancel`) of `limits`,
+/// counting its expansions and the puzzle states of every layer in `stats`,
+/// and reporting to `observer`; fails with `Err(err)` if reading or writing a
+/// file failed.
+fn external<P>(
+    p0: P,
+    dir: &Path,
+    chunk: usize,
+    limits: &BfsLimits,
+    stats: &mut SearchStats,
+    observer: &mut impl Observer<P>,
+) -> bincode::Result<Solved<P>>
+where
+    P: PersistentPuzzle + Ord + Clone,
+{
+    if let Err(err) = limits.visit(0) {
+        return Ok(Err(err));
+    }
+    let mut files = Files::new(dir)?;
+    let (path, w) = files.create("layer0.bin".to_string())?;
+    write_all(w, [&p0])?;
+    stats.visited = 1;
+    let mut layers = vec![path];
+    loop {
+        let depth = layers.len() - 1;
+        // the successor puzzle states of the horizon would be beyond it
+        let horizon = depth >= limits.max_depth;
+        if !horizon {
+            if let Err(err) = limits.expand(stats.visited, depth) {
+                return Ok(Err(err));
+            }
+        }
+        // expand the last layer into sorted runs of successor puzzle states,
+        // looking for the goal state with the least score
+        let mut goal: Option<P> = None;
+        let mut runs = vec![];
+        let mut buf = Vec::with_capacity(chunk);
+        let mut spill = |buf: &mut Vec<P>, files: &mut Files| -> bincode::Result<()> {
+            buf.sort();
+            buf.dedup();
+            let (path, w) = files.create(format!("layer{}-run{}.bin", depth + 1, runs.len()))?;
+            write_all(w, buf.iter())?;
+            buf.clear();
+            runs.push(path);
+            Ok(())
+        };
+        let mut err = Ok(());
+        Run::<P>::open(&layers[depth])?.for_each(|p| {
+            if err.is_err() {
+                return;
+            }
+            if p.is_goal() {
+                if goal
+                    .as_ref()
+                    .is_none_or(|g| p.goal_score() < g.goal_score())
+                {
+                    goal = Some(p);
+                }
+                return;
+            }
+            if goal.is_some() || horizon || p.prune() {
+                return;
+            }
+            observer.on_expand(&p, depth);
+            stats.expanded += 1;
+            for (_, q) in p.successors() {
+                // do not explore dead ends
+                if !q.is_dead() {
+                    stats.generated += 1;
+                    buf.push(q);
+                }
+                if buf.len() == chunk {
+                    err = spill(&mut buf, &mut files);
+                }
+            }
+        })?;
+        err?;
+        if let Some(goal) = goal {
+            return backtrack(&layers, goal).map(|soln| Ok(Ended::Goal(soln)));
+        }
+        if horizon {
+            return Ok(Ok(Ended::Cut));
+        }
+        if !buf.is_empty() {
+            spill(&mut buf, &mut files)?;
+        }
+        if runs.is_empty() {
+            observer.on_layer_complete(depth, stats.visited);
+            return Ok(Ok(Ended::Exhausted));
+        }
+
+        // merge the runs into the next layer, without the puzzle states of the
+        // previous layers
+        let runs = reduce_runs::<P>(&mut files, &format!("layer{}", depth + 1), runs)?;
+        let mut merge = Merge::<P>::open(&runs)?;
+        let mut visited = layers
+            .iter()
+            .map(|path| Run::open(path))
+            .collect::<bincode::Result<Vec<Run<P>>>>()?;
+        let (path, mut w) = files.create(format!("layer{}.bin", depth + 1))?;
+        let mut empty = true;
+        while let Some(p) = merge.next()? {
+            let mut seen = false;
+            for run in visited.iter_mut() {
+                while run.head.as_ref().is_some_and(|q| *q < p) {
+                    run.advance()?;
+                }
+                seen |= run.head.as_ref() == Some(&p);
+            }
+            if !seen {
+                if let Err(err) = limits.visit(stats.visited) {
+                    return Ok(Err(err));
+                }
+                bincode::serialize_into(&mut w, &p)?;
+                stats.visited += 1;
+                empty = false;
+            }
+        }
+        w.flush()?;
+        stats.duplicates = stats.generated - (stats.visited - 1);
+        observer.on_layer_complete(depth, stats.visited);
+        if empty {
+            return Ok(Ok(Ended::Exhausted));
+        }
+        layers.push(path);
+    }
+}
+
+/// Recover the moves to `goal` (in the last layer) by scanning each previous
+/// layer for a predecessor puzzle state.
+fn backtrack<P>(layers: &[PathBuf], goal: P) -> bincode::Result<(Vec<P::Move>, P)>
+where
+    P: PersistentPuzzle + Ord + Clone,
+{
+    let mut ms = vec![];
+    let mut q = goal.clone();
+    for layer in layers[..layers.len() - 1].iter().rev() {
+        let mut pred = None;
+        Run::<P>::open(layer)?.for_each(|p| {
+            if pred.is_none() && !p.is_goal() && !p.prune() {
+                let m = p.successors().find_map(|(m, r)| (r == q).then_some(m));
+                pred = m.map(|m| (m, p));
+            }
+        })?;
+        match pred {
+            Some((m, p)) => {
+                ms.push(m);
+                q = p;
+            }
+            None => unreachable!("every puzzle state of a layer has a predecessor"),
+        }
+    }
+    ms.reverse();
+    Ok((ms, goal))
+}
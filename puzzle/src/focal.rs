@@ -0,0 +1,201 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::{BuildHasher, Hash};
+
+use super::astar::Priority;
+use super::frontier::{solve_by, solve_by_config, Reached, Search};
+use super::{Frontier, Heuristic, Observer, Puzzle, SolveOutcome, SolverConfig};
+
+/// Solve a puzzle using focal search with hashing of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  If `P::estimate` is admissible, then the sequence
+/// of moves `ms` is at most `1.0 + epsilon` times as long as the shortest
+/// sequence of moves from `p0` to a goal state.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// As with `solve_astar`, the open list is ordered by `f`, the number of moves
+/// so far plus `P::estimate`.  However, rather than always expanding a puzzle
+/// state with the smallest `f`, the puzzle state to expand is chosen from the
+/// focal list, the puzzle states of the open list whose `f` is at most
+/// `1.0 + epsilon` times the smallest `f`, as the one with the smallest
+/// `P::estimate` (i.e., that appears to be closest to a goal state).  Because
+/// the smallest `f` never exceeds the length of the shortest sequence of moves,
+/// neither does the length of the sequence of moves to any goal state chosen
+/// from the focal list exceed `1.0 + epsilon` times it.  An `epsilon` of `0.0`
+/// is A* (with ties broken in favor of the smaller estimate).
+pub fn solve_focal<P>(p0: P, epsilon: f64) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    if epsilon.is_nan() || epsilon < 0.0 {
+        panic!(
+            "solve_focal epsilon (is {}) should be non-negative",
+            epsilon
+        )
+    }
+    solve_by(p0, &mut Focused, Focal::new(epsilon), &mut Reached::new())
+}
+
+/// Solve a puzzle using focal search with hashing of states, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_focal(p0, epsilon)` (or, with a `max_depth`, without expanding the
+/// puzzle states `max_depth` moves from `p0`) if the search finds it within the
+/// limits of `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_astar_with_config`.
+pub fn solve_focal_with_config<P, S, O>(
+    p0: P,
+    epsilon: f64,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    if epsilon.is_nan() || epsilon < 0.0 {
+        panic!(
+            "solve_focal_with_config epsilon (is {}) should be non-negative",
+            epsilon
+        )
+    }
+    solve_by_config(p0, &mut Focused, Focal::new(epsilon), config)
+}
+
+/// The hooks of `solve_focal`: A* (see `astar_by`) with a `Focal` frontier.
+struct Focused;
+
+impl<P> Search<P> for Focused
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+{
+    const NAME: &'static str = "solve_focal";
+    type Key = P;
+    type Frontier = Focal<P>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.clone()
+    }
+
+    fn priority(&mut self, g: usize, p: &P) -> Priority {
+        let f = (g + p.estimate()) as f64;
+        Priority { f, g }
+    }
+
+    fn reopen(&self) -> bool {
+        true
+    }
+}
+
+/// The open list of focal search (see `solve_focal`), of puzzle states with
+/// the indices of their nodes, pushed with the priorities of A*.
+///
+/// Every puzzle state is in the open list, ordered by `f`, and also in either
+/// the focal list (those whose `f` is within the bound, `1.0 + epsilon` times
+/// the smallest `f`), ordered by the estimate `f - g`, or the waiting list
+/// (the others), ordered by `f`; it is popped from the focal list.  Each
+/// element is identified by the order in which it was pushed, so that the
+/// lists can skip the elements that were popped (or replaced by an element of
+/// the same node, pushed when a cheaper sequence of moves to it was found).
+struct Focal<P> {
+    epsilon: f64,
+    /// Each element that remains (not popped or replaced), by its order.
+    elements: Vec<Option<(usize, P)>>,
+    /// The last element pushed for each node, by index.
+    latest: Vec<usize>,
+    open: BinaryHeap<Reverse<(Priority, usize)>>,
+    waiting: BinaryHeap<Reverse<(Priority, usize)>>,
+    /// Ordered by the estimate (and then, as for `Priority`, the larger `g`).
+    focal: BinaryHeap<Reverse<(Priority, Reverse<usize>)>>,
+    len: usize,
+}
+
+impl<P> Focal<P> {
+    /// An empty open list, whose bound is `1.0 + epsilon` times its smallest
+    /// `f`.
+    fn new(epsilon: f64) -> Self {
+        Focal {
+            epsilon,
+            elements: vec![],
+            latest: vec![],
+            open: BinaryHeap::new(),
+            waiting: BinaryHeap::new(),
+            focal: BinaryHeap::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<P> Frontier<(usize, P)> for Focal<P> {
+    type Priority = Priority;
+
+    fn push(&mut self, (i, p): (usize, P), priority: Priority) {
+        let id = self.elements.len();
+        if i >= self.latest.len() {
+            self.latest.resize(i + 1, usize::MAX);
+        }
+        // replace the last element of the node, if any
+        if let Some(old) = self.elements.get_mut(self.latest[i]) {
+            if old.take().is_some() {
+                self.len -= 1;
+            }
+        }
+        self.latest[i] = id;
+        self.elements.push(Some((i, p)));
+        self.len += 1;
+        self.open.push(Reverse((priority, id)));
+        self.waiting.push(Reverse((priority, id)));
+    }
+
+    fn pop(&mut self) -> Option<(usize, P)> {
+        loop {
+            // skip the elements of the open list that are gone
+            while let Some(Reverse((_, id))) = self.open.peek() {
+                if self.elements[*id].is_some() {
+                    break;
+                }
+                self.open.pop();
+            }
+            let Reverse((min, _)) = self.open.peek()?;
+            let bound = min.f * (1.0 + self.epsilon);
+            // move the elements within the bound into the focal list
+            while let Some(Reverse((priority, _))) = self.waiting.peek() {
+                if priority.f > bound {
+                    break;
+                }
+                let Reverse((Priority { f, g }, id)) = self.waiting.pop().unwrap();
+                if self.elements[id].is_some() {
+                    let h = f - g as f64;
+                    self.focal
+                        .push(Reverse((Priority { f: h, g }, Reverse(id))));
+                }
+            }
+
+            let Some(Reverse((Priority { f: h, g }, Reverse(id)))) = self.focal.pop() else {
+                continue;
+            };
+            if self.elements[id].is_none() {
+                continue;
+            }
+            let f = h + g as f64;
+            if f > bound {
+                // the bound has decreased (for an inconsistent estimate)
+                self.waiting.push(Reverse((Priority { f, g }, id)));
+                continue;
+            }
+            self.len -= 1;
+            return self.elements[id].take();
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
@@ -0,0 +1,1307 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::hash_map::RandomState;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    BfsLimits, CancelToken, ClosedSet, LayerStats, MemoryLimitExceeded, Observer, Puzzle,
+    SearchStats, Solution, SolveError, SolveOutcome, SolverConfig, MAX_NODES,
+};
+
+/// Trait for the open lists of searches: the collections of puzzle states (or
+/// rather, elements `T`) that have been reached but not yet expanded.
+///
+/// The order in which the elements are popped determines the search; e.g.,
+/// with `solve_with_frontier`, a `Fifo` frontier is a BFS, a `Lifo` frontier a
+/// DFS, and a `Heap` (or `BucketQueue`) frontier ordered by the cost so far
+/// (plus an estimate) is Dijkstra's algorithm (or A*).
+pub trait Frontier<T> {
+    /// The priority with which an element is pushed (`()`, for frontiers that
+    /// ignore priorities).
+    type Priority;
+
+    /// Push the element `x`, with priority `priority`.
+    fn push(&mut self, x: T, priority: Self::Priority);
+
+    /// Pop the next element, if any.
+    fn pop(&mut self) -> Option<T>;
+
+    /// The number of elements of the frontier.
+    fn len(&self) -> usize;
+
+    /// Determines whether or not the frontier has no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove an element that the frontier has dropped without popping it
+    /// (e.g., beyond the width of a beam), so that the search forgets that it
+    /// was reached; the default implementation never drops an element.
+    fn forget(&mut self) -> Option<T> {
+        None
+    }
+}
+
+/// A first-in first-out frontier (a queue), which ignores priorities.
+#[derive(Debug, Clone)]
+pub struct Fifo<T>(VecDeque<T>);
+
+impl<T> Fifo<T> {
+    /// An empty frontier.
+    pub fn new() -> Self {
+        Fifo(VecDeque::new())
+    }
+}
+
+impl<T> Default for Fifo<T> {
+    fn default() -> Self {
+        Fifo::new()
+    }
+}
+
+impl<T> Frontier<T> for Fifo<T> {
+    type Priority = ();
+
+    fn push(&mut self, x: T, (): ()) {
+        self.0.push_back(x)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// A last-in first-out frontier (a stack), which ignores priorities.
+#[derive(Debug, Clone)]
+pub struct Lifo<T>(Vec<T>);
+
+impl<T> Lifo<T> {
+    /// An empty frontier.
+    pub fn new() -> Self {
+        Lifo(Vec::new())
+    }
+}
+
+impl<T> Default for Lifo<T> {
+    fn default() -> Self {
+        Lifo::new()
+    }
+}
+
+impl<T> Frontier<T> for Lifo<T> {
+    type Priority = ();
+
+    fn push(&mut self, x: T, (): ()) {
+        self.0.push(x)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// An element of a `Heap`, ordered (only) by its priority, reversed, so that
+/// the `BinaryHeap` (a max-heap) pops the element with the least priority.
+#[derive(Debug, Clone)]
+struct Entry<T, K> {
+    priority: K,
+    x: T,
+}
+impl<T, K: Ord> PartialEq for Entry<T, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<T, K: Ord> Eq for Entry<T, K> {}
+impl<T, K: Ord> PartialOrd for Entry<T, K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<T, K: Ord> Ord for Entry<T, K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// A frontier (a binary heap) that pops an element with the least priority
+/// `K`; ties are popped in no particular order.
+#[derive(Debug, Clone)]
+pub struct Heap<T, K>(BinaryHeap<Entry<T, K>>);
+
+impl<T, K: Ord> Heap<T, K> {
+    /// An empty frontier.
+    pub fn new() -> Self {
+        Heap(BinaryHeap::new())
+    }
+}
+
+impl<T, K: Ord> Default for Heap<T, K> {
+    fn default() -> Self {
+        Heap::new()
+    }
+}
+
+impl<T, K: Ord> Frontier<T> for Heap<T, K> {
+    type Priority = K;
+
+    fn push(&mut self, x: T, priority: K) {
+        self.0.push(Entry { priority, x })
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.0.pop().map(|entry| entry.x)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// A frontier of small integer priorities: one bucket of elements for each
+/// priority, which pops an element with the least priority; ties are popped
+/// first-in first-out.
+///
+/// Pushing and popping take constant (amortized) time when the priorities
+/// are small and popped in (nearly) nondecreasing order, as are the costs so
+/// far of Dijkstra's algorithm with small move costs; a binary heap takes
+/// logarithmic time.  The buckets are indexed by priority, so the memory of
+/// the frontier is proportional to the largest priority pushed.
+#[derive(Debug, Clone)]
+pub struct BucketQueue<T> {
+    buckets: Vec<VecDeque<T>>,
+    /// No bucket of a lesser priority has an element.
+    min: usize,
+    len: usize,
+}
+
+impl<T> BucketQueue<T> {
+    /// An empty frontier.
+    pub fn new() -> Self {
+        BucketQueue {
+            buckets: Vec::new(),
+            min: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<T> Default for BucketQueue<T> {
+    fn default() -> Self {
+        BucketQueue::new()
+    }
+}
+
+impl<T> Frontier<T> for BucketQueue<T> {
+    type Priority = usize;
+
+    fn push(&mut self, x: T, priority: usize) {
+        if priority >= self.buckets.len() {
+            self.buckets.resize_with(priority + 1, VecDeque::new);
+        }
+        self.buckets[priority].push_back(x);
+        self.min = self.min.min(priority);
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        while let Some(bucket) = self.buckets.get_mut(self.min) {
+            if let Some(x) = bucket.pop_front() {
+                self.len -= 1;
+                return Some(x);
+            }
+            self.min += 1;
+        }
+        None
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Solve a puzzle using a search with hashing of states, expanding the puzzle
+/// states in the order of the frontier `frontier`.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`, the first goal state popped from the frontier.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// Every successor puzzle state `q` reached by a sequence of moves of cost `g`
+/// (the sum of the `P::move_cost` of each move) is pushed onto the frontier
+/// with priority `priority(g, &q)`, unless it was already reached by a sequence
+/// of moves of cost at most `g`; a puzzle state is re-expanded whenever a
+/// cheaper sequence of moves to it is found.  So, the frontier determines the
+/// search: e.g., `Fifo::new()` (with `|_, _| ()`) is a BFS, whose `ms` (for
+/// unit move costs) is one of the shortest sequence of moves; `Lifo::new()` is
+/// a DFS; and `Heap::new()` or `BucketQueue::new()` with `|g, q| g +
+/// q.estimate()` is A*.  (The specialized solvers, such as `solve` and
+/// `solve_astar`, are typically faster.)  The representatives of puzzle states
+/// (see `Puzzle::canonicalize`) and the scores of goal states (see
+/// `Puzzle::goal_score`) are ignored.
+pub fn solve_with_frontier<P, F, K>(p0: P, frontier: F, priority: K) -> Solution<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    F: Frontier<(usize, P)>,
+    K: Fn(usize, &P) -> F::Priority,
+{
+    search_by(p0, frontier, |p, m| p.move_cost(m) as usize, priority, true)
+}
+
+/// Solve a puzzle using best-first search with hashing of states, expanding the
+/// puzzle states in the order of `frontier`, as configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_with_frontier(p0, frontier, priority)` (or, with a `max_depth`,
+/// without expanding the puzzle states whose cost from `p0` reaches
+/// `max_depth`) if the search finds it within the limits of `config`, and
+/// otherwise the outcome of the search as for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_min_cost_with_config`.
+pub fn solve_with_frontier_with_config<P, F, K, S, O>(
+    p0: P,
+    frontier: F,
+    priority: K,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    F: Frontier<(usize, P)>,
+    K: Fn(usize, &P) -> F::Priority,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let cost = |p: &P, m: &P::Move| p.move_cost(m) as usize;
+    search_by_config(p0, frontier, cost, priority, true, config)
+}
+
+/// Search with hashing of states, expanding the puzzle states in the order of
+/// `frontier`, where the cost of a move `m` from a puzzle state `p` is
+/// `cost(p, m)` and a puzzle state `p` reached by a sequence of moves with
+/// total cost `g` is pushed with priority `priority(g, p)`.  If `reopen`, then
+/// a puzzle state is re-expanded whenever a cheaper sequence of moves to it is
+/// found; otherwise, each puzzle state is only reached by the first sequence
+/// of moves found to it.
+pub(crate) fn search_by<P, F, C, K>(
+    p0: P,
+    frontier: F,
+    cost: C,
+    priority: K,
+    reopen: bool,
+) -> Solution<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    F: Frontier<(usize, P)>,
+    C: Fn(&P, &P::Move) -> usize,
+    K: Fn(usize, &P) -> F::Priority,
+{
+    let mut by = By {
+        cost,
+        priority,
+        reopen,
+        frontier: PhantomData,
+    };
+    solve_by(p0, &mut by, frontier, &mut Reached::new())
+}
+
+/// As `search_by`, but as configured by `config` (see `solve_by_config`).
+pub(crate) fn search_by_config<P, F, C, K, H, O>(
+    p0: P,
+    frontier: F,
+    cost: C,
+    priority: K,
+    reopen: bool,
+    config: &SolverConfig<H, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    F: Frontier<(usize, P)>,
+    C: Fn(&P, &P::Move) -> usize,
+    K: Fn(usize, &P) -> F::Priority,
+    H: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let mut by = By {
+        cost,
+        priority,
+        reopen,
+        frontier: PhantomData,
+    };
+    solve_by_config(p0, &mut by, frontier, config)
+}
+
+/// The hooks of `search_by`.
+struct By<C, K, F> {
+    cost: C,
+    priority: K,
+    reopen: bool,
+    frontier: PhantomData<F>,
+}
+
+impl<P, F, C, K> Search<P> for By<C, K, F>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    F: Frontier<(usize, P)>,
+    C: Fn(&P, &P::Move) -> usize,
+    K: Fn(usize, &P) -> F::Priority,
+{
+    type Key = P;
+    type Frontier = F;
+
+    fn key(&mut self, p: &P) -> P {
+        p.clone()
+    }
+
+    fn priority(&mut self, g: usize, p: &P) -> F::Priority {
+        (self.priority)(g, p)
+    }
+
+    fn cost(&mut self, p: &P, m: &P::Move) -> usize {
+        (self.cost)(p, m)
+    }
+
+    fn reopen(&self) -> bool {
+        self.reopen
+    }
+}
+
+/// The priority with which a puzzle state is pushed onto the frontier of a
+/// `Search`.
+pub(crate) type PriorityOf<P, S> = <<S as Search<P>>::Frontier as Frontier<(usize, P)>>::Priority;
+
+/// Trait for the hooks of a search with hashing of states (see `search`),
+/// which determine how its puzzle states are identified, expanded and
+/// ordered.  Every hook but `key` and `priority` has a default, which is that
+/// of a BFS.
+pub(crate) trait Search<P: Puzzle> {
+    /// The name of the search, for its events and panics.
+    const NAME: &'static str = "search";
+
+    /// Whether or not, once a goal state is popped, the goal state with the
+    /// least score (see `Puzzle::goal_score`) among it and those of the same
+    /// cost that are popped next is preferred.
+    const PREFER_GOAL_SCORE: bool = false;
+
+    /// The keys by which the reached puzzle states are identified.
+    type Key: Eq + Hash;
+
+    /// The frontier of the search, of puzzle states with the indices of their
+    /// nodes.
+    type Frontier: Frontier<(usize, P)>;
+
+    /// The key of the puzzle state `p`; puzzle states with the same key are
+    /// the same puzzle state, as far as the search is concerned.
+    fn key(&mut self, p: &P) -> Self::Key;
+
+    /// The priority of the puzzle state `p`, reached by a sequence of moves of
+    /// cost `g`.
+    fn priority(&mut self, g: usize, p: &P) -> PriorityOf<P, Self>;
+
+    /// The cost of the move `m` from the puzzle state `p`.
+    fn cost(&mut self, p: &P, m: &P::Move) -> usize {
+        let _ = (p, m);
+        1
+    }
+
+    /// Whether or not a puzzle state is re-expanded whenever a cheaper
+    /// sequence of moves to it is found.
+    fn reopen(&self) -> bool {
+        false
+    }
+
+    /// Whether or not the puzzle state `p` is a goal state (see
+    /// `Puzzle::is_goal`).
+    fn is_goal(&mut self, p: &P) -> bool {
+        p.is_goal()
+    }
+
+    /// Whether or not the puzzle state `p`, which is not a goal state, is not
+    /// expanded (see `Puzzle::prune`).
+    fn prune(&mut self, p: &P) -> bool {
+        p.prune()
+    }
+
+    /// Whether or not the successor puzzle state `q` is a dead end, which is
+    /// recorded as reached, but never pushed onto the frontier (see
+    /// `Puzzle::is_dead`).
+    fn is_dead(&mut self, q: &P) -> bool {
+        q.is_dead()
+    }
+
+    /// Appends the successor puzzle states of `p`, reached by a sequence of
+    /// moves of cost `g`, to `next` (see `Puzzle::next_into`).
+    fn successors(&mut self, p: &P, g: usize, next: &mut Vec<(P::Move, P)>) {
+        let _ = g;
+        p.next_into(next)
+    }
+
+    /// Pushes the successor puzzle states `batch` of an expansion (each with
+    /// the cost of the sequence of moves to it and the index of its node) onto
+    /// `frontier`, draining `batch`.
+    fn push(&mut self, frontier: &mut Self::Frontier, batch: &mut Vec<(usize, usize, P)>) {
+        for (g, i, q) in batch.drain(..) {
+            let priority = self.priority(g, &q);
+            frontier.push((i, q), priority);
+        }
+    }
+
+    /// Called whenever a successor puzzle state (of node `j`) is reached from
+    /// node `i` by the move `m`, by a sequence of moves of cost `g`: whether
+    /// it is newly reached, reached by a cheaper sequence of moves (in both
+    /// cases, with `reached.parent(j)` now node `i`), or reached again by a
+    /// sequence of moves that is no cheaper than `reached.cost(j)` (unless its
+    /// node is not recorded; see `Closed`).
+    fn reach<I>(
+        &mut self,
+        reached: &Reached<Self::Key, P::Move, I>,
+        i: usize,
+        m: &P::Move,
+        j: usize,
+        g: usize,
+    ) {
+        let _ = (reached, i, m, j, g);
+    }
+
+    /// Whether or not the popped puzzle state `p`, reached by a sequence of
+    /// moves of cost `g`, is skipped (neither checked for a goal state nor
+    /// expanded).
+    fn skip(&mut self, p: &P, g: usize) -> bool {
+        let _ = (p, g);
+        false
+    }
+
+    /// Whether or not the search stops at the goal state `p` of node `i`; if
+    /// not, `p` is not expanded, and the search goes on.
+    fn stop<I>(&mut self, reached: &Reached<Self::Key, P::Move, I>, i: usize, p: &P) -> bool {
+        let _ = (reached, i, p);
+        true
+    }
+
+    /// Whether or not the search pauses before popping the next puzzle state
+    /// of `frontier` (see `resume`).
+    fn pause<I>(
+        &mut self,
+        frontier: &Self::Frontier,
+        reached: &Reached<Self::Key, P::Move, I>,
+    ) -> bool {
+        let _ = (frontier, reached);
+        false
+    }
+
+    /// Whether or not the search may expand the puzzle state `p`, reached by a
+    /// sequence of moves of cost `g`; if not, the search is abandoned (without
+    /// a goal state).
+    fn proceed(&mut self, p: &P, g: usize) -> bool {
+        let _ = (p, g);
+        true
+    }
+}
+
+/// A node of the arena of a search: the cost of the cheapest sequence of moves
+/// found to its puzzle state, whether or not its puzzle state has been
+/// expanded (or skipped) since, and the index of its parent node (with the
+/// connecting move), or `None` for the initial puzzle state.
+///
+/// The indices (and costs) are `u32`s (rather than `usize`s), which halves
+/// their size in the nodes and in the hash table; so, the arena holds at most
+/// `MAX_NODES` nodes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Node<M> {
+    cost: u32,
+    closed: bool,
+    parent: Option<(u32, M)>,
+}
+
+/// The result of recording a reached puzzle state in an `Index`.
+pub(crate) enum Indexed {
+    /// The puzzle state was newly reached, and is recorded at the given node.
+    New,
+    /// The puzzle state was already reached, and is recorded at node `i`.
+    Node(usize),
+    /// The puzzle state was (possibly, for an approximate closed set) already
+    /// reached, but its node is not recorded.
+    Visited,
+}
+
+/// Trait for the indices of the puzzle states reached by a search (see
+/// `Reached`): from each reached puzzle state (or its key) to its node.
+///
+/// A hash table (`HashMap`) from the key of each reached puzzle state to the
+/// index of its node records it all; a closed set (see `Closed`) only records
+/// whether or not each puzzle state was reached.
+pub(crate) trait Index<P, K> {
+    /// Record the puzzle state `p` (of key `key()`), reached by a sequence of
+    /// moves of cost `g`, at node `j`, unless it was already reached.
+    fn entry(&mut self, p: &P, key: impl FnOnce() -> K, j: usize, g: usize) -> Indexed;
+
+    /// Forget the puzzle state `p` (of key `key()`), so that it may be reached
+    /// again.
+    fn remove(&mut self, p: &P, key: impl FnOnce() -> K);
+
+    /// Called whenever the search starts expanding the puzzle states of cost
+    /// `layer` (see `Scoped`); returns whether or not puzzle states were
+    /// forgotten.
+    fn layer(&mut self, layer: usize) -> bool {
+        let _ = layer;
+        false
+    }
+
+    /// The number of recorded puzzle states.
+    fn len(&self) -> usize;
+
+    /// Reserve room for at least `additional` more puzzle states.
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+}
+
+impl<P, K, S> Index<P, K> for HashMap<K, u32, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn entry(&mut self, _: &P, key: impl FnOnce() -> K, j: usize, _: usize) -> Indexed {
+        match HashMap::entry(self, key()) {
+            Vacant(e) => {
+                e.insert(j as u32);
+                Indexed::New
+            }
+            Occupied(e) => Indexed::Node(*e.get() as usize),
+        }
+    }
+
+    fn remove(&mut self, _: &P, key: impl FnOnce() -> K) {
+        HashMap::remove(self, &key());
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        HashMap::reserve(self, additional)
+    }
+}
+
+/// The index of a search that records the reached puzzle states in the closed
+/// set `C` (see `ClosedSet`), rather than the node of each puzzle state (or its
+/// key, which is not used).
+///
+/// So, a puzzle state that is reached again is never re-expanded (even by a
+/// cheaper sequence of moves), a puzzle state that the frontier drops is never
+/// forgotten, and `Search::reach` is not called for a puzzle state that is
+/// reached again.
+pub(crate) struct Closed<C>(pub(crate) C);
+
+impl<P, K, C: ClosedSet<P>> Index<P, K> for Closed<C> {
+    fn entry(&mut self, p: &P, _: impl FnOnce() -> K, _: usize, _: usize) -> Indexed {
+        if self.0.insert(p) {
+            Indexed::New
+        } else {
+            Indexed::Visited
+        }
+    }
+
+    fn remove(&mut self, _: &P, _: impl FnOnce() -> K) {}
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// The index of a search that only detects duplicates within the last `scope`
+/// layers (see `BfsLimits::scope`; the layer being expanded included): a hash
+/// table (as for `HashMap`) for each layer of puzzle states of the same cost,
+/// of which the oldest is dropped (as a whole) once the search starts
+/// expanding the layer `scope` layers on.  So, as for `solve_layered_scoped`,
+/// only the parent links (the nodes) of older puzzle states are kept; with an
+/// unbounded `scope`, the puzzle states of every layer are kept in one table.
+pub(crate) struct Scoped<K, S> {
+    scope: usize,
+    hash_builder: SharedHasher<S>,
+    /// The hash table of each layer, by increasing cost.
+    layers: VecDeque<(usize, Layer<K, S>)>,
+}
+
+/// The hash table of a layer of a `Scoped` index.
+type Layer<K, S> = HashMap<K, u32, SharedHasher<S>>;
+
+/// The hasher builder of a `Scoped` index, shared by its hash tables.
+struct SharedHasher<S>(Rc<S>);
+
+impl<S> Clone for SharedHasher<S> {
+    fn clone(&self) -> Self {
+        SharedHasher(Rc::clone(&self.0))
+    }
+}
+
+impl<S: BuildHasher> BuildHasher for SharedHasher<S> {
+    type Hasher = S::Hasher;
+
+    fn build_hasher(&self) -> S::Hasher {
+        self.0.build_hasher()
+    }
+}
+
+impl<K, S> Scoped<K, S> {
+    /// No puzzle states recorded, within the last `scope` layers, where the
+    /// hash tables use the hasher built by `hash_builder`.
+    pub(crate) fn new(hash_builder: S, scope: usize) -> Self {
+        Scoped {
+            scope,
+            hash_builder: SharedHasher(Rc::new(hash_builder)),
+            layers: VecDeque::new(),
+        }
+    }
+}
+
+impl<P, K, S> Index<P, K> for Scoped<K, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn entry(&mut self, _: &P, key: impl FnOnce() -> K, j: usize, g: usize) -> Indexed {
+        let g = if self.scope == usize::MAX { 0 } else { g };
+        let key = key();
+        // the table of the layer of cost `g`, and those of the other layers
+        let k = self.layers.partition_point(|(layer, _)| *layer < g);
+        if self.layers.get(k).is_none_or(|(layer, _)| *layer != g) {
+            let table = HashMap::with_hasher(self.hash_builder.clone());
+            self.layers.insert(k, (g, table));
+        }
+        let others = self.layers.iter().enumerate().filter(|(l, _)| *l != k);
+        for (_, (_, table)) in others {
+            if let Some(i) = table.get(&key) {
+                return Indexed::Node(*i as usize);
+            }
+        }
+        match self.layers[k].1.entry(key) {
+            Vacant(e) => {
+                e.insert(j as u32);
+                Indexed::New
+            }
+            Occupied(e) => Indexed::Node(*e.get() as usize),
+        }
+    }
+
+    fn remove(&mut self, _: &P, key: impl FnOnce() -> K) {
+        let key = key();
+        for (_, table) in &mut self.layers {
+            table.remove(&key);
+        }
+    }
+
+    fn layer(&mut self, layer: usize) -> bool {
+        // forget the puzzle states of the layers beyond the scope
+        let mut forgot = false;
+        if self.scope <= layer {
+            while self
+                .layers
+                .front()
+                .is_some_and(|(g, _)| *g <= layer - self.scope)
+            {
+                self.layers.pop_front();
+                forgot = true;
+            }
+        }
+        forgot
+    }
+
+    fn len(&self) -> usize {
+        self.layers.iter().map(|(_, table)| table.len()).sum()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        // only the one table of an unbounded scope is known to grow that much
+        if self.scope == usize::MAX {
+            if self.layers.is_empty() {
+                let table = HashMap::with_hasher(self.hash_builder.clone());
+                self.layers.push_back((0, table));
+            }
+            self.layers[0].1.reserve(additional);
+        }
+    }
+}
+
+/// The puzzle states reached by a search (see `search`): an index (see
+/// `Index`; by default, a hash table from the key of each puzzle state) to the
+/// node of each puzzle state in an arena.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "I: Serialize, M: Serialize",
+    deserialize = "I: Deserialize<'de>, M: Deserialize<'de>"
+))]
+pub(crate) struct Reached<K, M, I = HashMap<K, u32, RandomState>> {
+    index: I,
+    nodes: Vec<Node<M>>,
+    #[serde(skip)]
+    keys: PhantomData<K>,
+}
+
+impl<K, M> Reached<K, M> {
+    /// No puzzle states reached, with the default hasher of `HashMap`.
+    pub(crate) fn new() -> Self {
+        Reached::with_hasher(RandomState::new())
+    }
+}
+
+impl<K, M, S> Reached<K, M, HashMap<K, u32, S>> {
+    /// No puzzle states reached, where the hash table uses the hasher built by
+    /// `hash_builder`.
+    pub(crate) fn with_hasher(hash_builder: S) -> Self {
+        Reached::with_index(HashMap::with_hasher(hash_builder))
+    }
+
+    /// The key of the predecessor of each reached puzzle state (with the
+    /// connecting move), by key, or `None` for the initial puzzle state.
+    pub(crate) fn into_parents(self) -> HashMap<K, Option<(K, M)>>
+    where
+        K: Eq + Hash + Clone,
+    {
+        let mut keys = vec![None; self.nodes.len()];
+        for (k, i) in &self.index {
+            keys[*i as usize] = Some(k.clone());
+        }
+        let mut nodes = self.nodes;
+        self.index
+            .into_iter()
+            .map(|(k, i)| {
+                let parent = nodes[i as usize].parent.take();
+                let parent = parent.and_then(|(j, m)| Some((keys[j as usize].clone()?, m)));
+                (k, parent)
+            })
+            .collect()
+    }
+}
+
+impl<K, M, I> Reached<K, M, I> {
+    /// No puzzle states reached, recorded in the (empty) index `index`.
+    pub(crate) fn with_index(index: I) -> Self {
+        Reached {
+            index,
+            nodes: Vec::new(),
+            keys: PhantomData,
+        }
+    }
+
+    /// The number of reached puzzle states (the initial puzzle state included).
+    pub(crate) fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// The cost of the cheapest sequence of moves found to node `i`.
+    pub(crate) fn cost(&self, i: usize) -> usize {
+        self.nodes[i].cost as usize
+    }
+
+    /// The number of moves from the initial puzzle state to node `i`.
+    pub(crate) fn depth(&self, i: usize) -> usize {
+        let mut depth = 0;
+        let mut i = i;
+        while let Some((parent, _)) = &self.nodes[i].parent {
+            depth += 1;
+            i = *parent as usize;
+        }
+        depth
+    }
+
+    /// The index of the parent node of node `i` (and the connecting move).
+    pub(crate) fn parent(&self, i: usize) -> Option<(usize, &M)> {
+        self.nodes[i].parent.as_ref().map(|(j, m)| (*j as usize, m))
+    }
+
+    /// The moves from the initial puzzle state to node `i`, by walking the
+    /// parent indices of the nodes.
+    pub(crate) fn path(&self, i: usize) -> Vec<M>
+    where
+        M: Clone,
+    {
+        let mut vec = vec![];
+        let mut i = i;
+        while let Some((parent, m)) = self.parent(i) {
+            vec.push(m.clone());
+            i = parent;
+        }
+        vec.reverse();
+        vec
+    }
+
+    /// The moves from the initial puzzle state to node `i`, taken from the
+    /// nodes (so that the moves need not be cloned).
+    pub(crate) fn take_path(&mut self, i: usize) -> Vec<M> {
+        let mut vec = vec![];
+        let mut i = i;
+        while let Some((parent, m)) = self.nodes[i].parent.take() {
+            vec.push(m);
+            i = parent as usize;
+        }
+        vec.reverse();
+        vec
+    }
+}
+
+/// How a search (see `search`) ended, unless a limit was exceeded.
+#[derive(Debug)]
+pub(crate) enum Ended<T> {
+    /// At the goal state (and the index of its node) at which the search
+    /// stopped (see `Search::stop`).
+    Goal(T),
+    /// Without a goal state, once every reached puzzle state was expanded (or
+    /// pruned, or a dead end), which proves that no goal state can be reached.
+    Exhausted,
+    /// Without a goal state, but without expanding every reachable puzzle
+    /// state: some were at the horizon, were forgotten (by the frontier, or
+    /// beyond the scope of the index), or the search was abandoned by
+    /// `Search::proceed`.
+    Cut,
+    /// Paused by `Search::pause`, to be continued by `resume`.
+    Paused,
+}
+
+/// How a search that recovers a solution itself ended, or the limit that it
+/// exceeded (or the error of its check).
+pub(crate) type Solved<P, E = SolveError> = Result<Ended<(Vec<<P as Puzzle>::Move>, P)>, E>;
+
+impl<T> Ended<T> {
+    /// The goal state at which the search stopped, if any.
+    pub(crate) fn goal(self) -> Option<T> {
+        match self {
+            Ended::Goal(goal) => Some(goal),
+            _ => None,
+        }
+    }
+}
+
+/// Search with hashing of states from `p0`, by the hooks of `search` and the
+/// frontier `frontier`, recording the reached puzzle states in `reached`.
+///
+/// Returns `Ok(ended)` once a goal state `p` (of node `i`) is popped from the
+/// frontier, at which the search stops (`Ended::Goal((i,p))`), or once the
+/// search ends without one (see `Ended`).
+///
+/// The search allocates room for `limits.capacity` puzzle states up front,
+/// does not expand puzzle states whose cost reaches the horizon
+/// `limits.max_depth`, fails once more than `limits.max_states` (or
+/// `limits.max_memory_states`) puzzle states would be reached or once
+/// `limits.deadline` has passed (or `limits.cancel` is cancelled), and records
+/// its counts (and the maximum lengths of its frontier and hash table) in
+/// `stats`, except for the wall time (and, if `limits.layer_stats`, the
+/// statistics of each layer), and reports its progress to `observer` (except
+/// for the solution).  Duplicates are detected by the index of `reached` (so,
+/// for `limits.scope`, only within the last layers of a `Scoped` index).
+///
+/// Successor puzzle states that are dead ends (see `Search::is_dead`) are
+/// recorded as reached, but are never pushed onto the frontier.
+pub(crate) fn search<P, S, I>(
+    p0: P,
+    search: &mut S,
+    mut frontier: S::Frontier,
+    reached: &mut Reached<S::Key, P::Move, I>,
+    limits: BfsLimits,
+    stats: &mut SearchStats,
+    observer: &mut impl Observer<P>,
+) -> Result<Ended<(usize, P)>, SolveError>
+where
+    P: Puzzle,
+    S: Search<P>,
+    I: Index<P, S::Key>,
+{
+    seed(p0, search, &mut frontier, reached, &limits, stats)?;
+    resume(
+        search,
+        &mut frontier,
+        reached,
+        &mut 0,
+        &limits,
+        stats,
+        observer,
+    )
+}
+
+/// The error of reaching `states` puzzle states, more than allowed by
+/// `limits`.
+fn exceeded<P: Puzzle, S: Search<P>>(limits: &BfsLimits, states: usize) -> SolveError {
+    let err = if states >= limits.max_memory_states {
+        SolveError::MemoryLimit(MemoryLimitExceeded { states })
+    } else {
+        SolveError::NodeLimit { states }
+    };
+    event!(debug, "{}: abandoned: {}", S::NAME, err);
+    err
+}
+
+/// The number of puzzle states that a search (see `search`) may reach within
+/// `limits`.
+fn max_states(limits: &BfsLimits) -> usize {
+    limits
+        .max_states
+        .min(MAX_NODES)
+        .min(limits.max_memory_states)
+}
+
+/// Start a search (see `search`) from `p0`: push `p0` onto the frontier
+/// `frontier` and record it in `reached` and in `stats`, to be expanded by
+/// `resume`.
+pub(crate) fn seed<P, S, I>(
+    p0: P,
+    search: &mut S,
+    frontier: &mut S::Frontier,
+    reached: &mut Reached<S::Key, P::Move, I>,
+    limits: &BfsLimits,
+    stats: &mut SearchStats,
+) -> Result<(), SolveError>
+where
+    P: Puzzle,
+    S: Search<P>,
+    I: Index<P, S::Key>,
+{
+    let max_states = max_states(limits);
+    if max_states == 0 {
+        return Err(exceeded::<P, S>(limits, 0));
+    }
+    // never allocate room for more puzzle states than may be reached
+    let capacity = limits.capacity.clamp(1, max_states);
+    reached.index.reserve(capacity);
+    reached.nodes.reserve(capacity);
+    reached
+        .index
+        .entry(&p0, || search.key(&p0), reached.nodes.len(), 0);
+    let priority = search.priority(0, &p0);
+    frontier.push((reached.nodes.len(), p0), priority);
+    reached.nodes.push(Node {
+        cost: 0,
+        closed: false,
+        parent: None,
+    });
+    stats.visited = 1;
+    stats.max_frontier = 1;
+    if limits.layer_stats {
+        stats.layers = vec![LayerStats {
+            states: 1,
+            ..LayerStats::default()
+        }];
+    }
+    stats.max_closed = 1;
+    Ok(())
+}
+
+/// Continue a search (see `search`), started by `seed`, from the puzzle states
+/// of the frontier `frontier`, where `layer` is the cost of the layer being
+/// expanded (updated as the search goes on).
+///
+/// Returns as does `search`; once paused (see `Search::pause`), the search can
+/// be continued by calling `resume` again.
+pub(crate) fn resume<P, S, I>(
+    search: &mut S,
+    frontier: &mut S::Frontier,
+    reached: &mut Reached<S::Key, P::Move, I>,
+    layer: &mut usize,
+    limits: &BfsLimits,
+    stats: &mut SearchStats,
+    observer: &mut impl Observer<P>,
+) -> Result<Ended<(usize, P)>, SolveError>
+where
+    P: Puzzle,
+    S: Search<P>,
+    I: Index<P, S::Key>,
+{
+    let max_states = max_states(limits);
+    // a scratch vector for the successor puzzle states of each expansion, and
+    // one for those of them that are pushed onto the frontier
+    let mut next = Vec::new();
+    let mut batch = Vec::new();
+    // whether or not a reachable puzzle state was not expanded
+    let mut cut = false;
+    // Loop till the frontier is empty
+    while !search.pause(frontier, reached) {
+        let Some((i, p)) = frontier.pop() else {
+            break;
+        };
+        // forget the puzzle states that the frontier dropped, so that they may
+        // be reached again
+        while let Some((_, q)) = frontier.forget() {
+            reached.index.remove(&q, || search.key(&q));
+            cut = true;
+        }
+        // skip stale entries; `p` has already been expanded at its least cost
+        let node = &mut reached.nodes[i];
+        if node.closed {
+            continue;
+        }
+        node.closed = true;
+        let g = node.cost as usize;
+        if g != *layer {
+            event!(
+                debug,
+                "{}: layer {} expanded ({} states visited)",
+                S::NAME,
+                layer,
+                reached.nodes.len()
+            );
+            observer.on_layer_complete(*layer, reached.nodes.len());
+            *layer = g;
+            cut |= reached.index.layer(g);
+        }
+        if search.skip(&p, g) {
+            continue;
+        }
+        if search.is_goal(&p) {
+            if !search.stop(reached, i, &p) {
+                continue;
+            }
+            let (mut i, mut p) = (i, p);
+            if S::PREFER_GOAL_SCORE {
+                // the remaining puzzle states of the same cost that are popped
+                // next; prefer the goal state with the least score
+                while let Some((j, q)) = frontier.pop() {
+                    let node = &reached.nodes[j];
+                    if node.closed {
+                        continue;
+                    }
+                    if node.cost as usize != g {
+                        break;
+                    }
+                    if search.is_goal(&q) && q.goal_score() < p.goal_score() {
+                        (i, p) = (j, q);
+                    }
+                }
+            }
+            let depth = reached.depth(i);
+            stats.depth = Some(depth);
+            event!(
+                debug,
+                "{}: solved in {} moves ({} states visited)",
+                S::NAME,
+                depth,
+                reached.nodes.len()
+            );
+            return Ok(Ended::Goal((i, p)));
+        }
+
+        if search.prune(&p) {
+            continue;
+        }
+        // the successor puzzle states of the horizon would be beyond it
+        if g >= limits.max_depth {
+            cut = true;
+            continue;
+        }
+        if limits
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            let err = SolveError::TimeLimit {
+                states: reached.nodes.len(),
+                depth: g,
+            };
+            event!(debug, "{}: abandoned: {}", S::NAME, err);
+            return Err(err);
+        }
+        if limits
+            .cancel
+            .as_ref()
+            .is_some_and(CancelToken::is_cancelled)
+        {
+            let err = SolveError::Cancelled {
+                states: reached.nodes.len(),
+            };
+            event!(debug, "{}: abandoned: {}", S::NAME, err);
+            return Err(err);
+        }
+        if !search.proceed(&p, g) {
+            return Ok(Ended::Cut);
+        }
+        event!(trace, "{}: expanding node {} at depth {}", S::NAME, i, g);
+        observer.on_expand(&p, g);
+        next.clear();
+        search.successors(&p, g, &mut next);
+        stats.expanded += 1;
+        stats.generated += next.len();
+        let (generated, duplicates) = (next.len(), stats.duplicates);
+        for (m, q) in next.drain(..) {
+            let gq = g + search.cost(&p, &m);
+            let cost = match u32::try_from(gq) {
+                Ok(cost) => cost,
+                Err(_) => panic!("{} cost (is {}) should not exceed u32::MAX", S::NAME, gq),
+            };
+            let j = reached.nodes.len();
+            let j = match reached.index.entry(&q, || search.key(&q), j, gq) {
+                Indexed::New => {
+                    if j == max_states {
+                        return Err(exceeded::<P, S>(limits, j));
+                    }
+                    reached.nodes.push(Node {
+                        cost,
+                        closed: false,
+                        parent: Some((i as u32, m)),
+                    });
+                    stats.visited += 1;
+                    observer.on_discover(&q, &p, gq);
+                    j
+                }
+                Indexed::Node(j) => {
+                    let node = &mut reached.nodes[j];
+                    if !search.reopen() || node.cost <= cost {
+                        stats.duplicates += 1;
+                        search.reach(reached, i, &m, j, gq);
+                        continue;
+                    }
+                    *node = Node {
+                        cost,
+                        closed: false,
+                        parent: Some((i as u32, m)),
+                    };
+                    j
+                }
+                Indexed::Visited => {
+                    stats.duplicates += 1;
+                    continue;
+                }
+            };
+            if let Some((_, m)) = reached.parent(j) {
+                search.reach(reached, i, m, j, gq);
+            }
+            // record dead ends as reached, but do not explore them
+            if !search.is_dead(&q) {
+                batch.push((gq, j, q));
+            }
+        }
+        search.push(frontier, &mut batch);
+        if limits.layer_stats {
+            stats.record_layer(g, generated, stats.duplicates - duplicates);
+        }
+        stats.max_frontier = stats.max_frontier.max(frontier.len());
+        stats.max_closed = stats.max_closed.max(reached.index.len());
+    }
+    if !frontier.is_empty() {
+        return Ok(Ended::Paused);
+    }
+    observer.on_layer_complete(*layer, reached.nodes.len());
+    event!(
+        debug,
+        "{}: exhausted without a goal state ({} states visited)",
+        S::NAME,
+        reached.nodes.len()
+    );
+
+    Ok(if cut { Ended::Cut } else { Ended::Exhausted })
+}
+
+/// Solve a puzzle by `search`, without limits (except the size of the arena),
+/// recording the reached puzzle states in `reached`.
+pub(crate) fn solve_by<P, S, I>(
+    p0: P,
+    search: &mut S,
+    frontier: S::Frontier,
+    reached: &mut Reached<S::Key, P::Move, I>,
+) -> Solution<P>
+where
+    P: Puzzle,
+    S: Search<P>,
+    I: Index<P, S::Key>,
+{
+    let (i, p) = explore_by(p0, search, frontier, reached)?;
+    Some((reached.take_path(i), p))
+}
+
+/// Solve a puzzle by `search`, as configured by `config`: with its limits (see
+/// `SolverConfig::bfs_limits`), recording the reached puzzle states in hash
+/// tables with its hasher (see `Scoped`), and reporting to its observer (and
+/// then the statistics of the search).
+///
+/// Returns `SolveOutcome::Limit(err)` as soon as a limit of `config` is
+/// exceeded (see `SolveError`).
+pub(crate) fn solve_by_config<P, S, H, O>(
+    p0: P,
+    search: &mut S,
+    frontier: S::Frontier,
+    config: &SolverConfig<H, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle,
+    S: Search<P>,
+    H: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let index = Scoped::new(config.hasher.clone(), config.dedupe_scope);
+    let observer = &mut *config.observer.borrow_mut();
+    solve_by_index(p0, search, frontier, index, config, observer)
+}
+
+/// As `solve_by_config`, but recording the reached puzzle states in `index`
+/// (rather than in hash tables with the hasher of `config`), and reporting to
+/// `observer` (rather than to the observer of `config`).
+pub(crate) fn solve_by_index<P, S, I, H, O>(
+    p0: P,
+    search: &mut S,
+    frontier: S::Frontier,
+    index: I,
+    config: &SolverConfig<H, O>,
+    observer: &mut impl Observer<P>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle,
+    S: Search<P>,
+    I: Index<P, S::Key>,
+{
+    let start = Instant::now();
+    let limits = config.bfs_limits(&p0, start);
+    let mut reached = Reached::with_index(index);
+    let stats = &mut SearchStats::default();
+    let ended = self::search(p0, search, frontier, &mut reached, limits, stats, observer);
+    let outcome = SolveOutcome::new(ended, stats.visited, |(i, p)| {
+        let vec = reached.take_path(i);
+        observer.on_solution(&vec, &p);
+        (vec, p)
+    });
+    stats.finish(start, observer);
+    outcome
+}
+
+/// Search by `search`, without limits (except the size of the arena),
+/// recording the reached puzzle states in `reached`; returns the goal state
+/// (and the index of its node) at which the search stopped, if any.
+///
+/// Panics if more than `MAX_NODES` puzzle states would be reached.
+pub(crate) fn explore_by<P, S, I>(
+    p0: P,
+    search: &mut S,
+    frontier: S::Frontier,
+    reached: &mut Reached<S::Key, P::Move, I>,
+) -> Option<(usize, P)>
+where
+    P: Puzzle,
+    S: Search<P>,
+    I: Index<P, S::Key>,
+{
+    let limits = BfsLimits::new(&p0);
+    let stats = &mut SearchStats::default();
+    match self::search(p0, search, frontier, reached, limits, stats, &mut ()) {
+        Ok(ended) => ended.goal(),
+        Err(SolveError::NodeLimit { states }) => {
+            panic!(
+                "{} states (is {}) should not exceed u32::MAX",
+                S::NAME,
+                states
+            )
+        }
+        Err(err) => unreachable!("{} has no limit for {:?}", S::NAME, err),
+    }
+}
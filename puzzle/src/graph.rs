@@ -0,0 +1,244 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::io::{self, BufWriter, Write};
+
+use super::frontier::{explore_by, Reached, Search};
+use super::{Fifo, Puzzle, Solution};
+
+/// The graph explored by a BFS, as returned by `solve_with_graph`: each
+/// visited puzzle state, with the predecessor puzzle state (and the move from
+/// it) by which it was first reached.
+#[derive(Debug)]
+pub struct SearchGraph<P: Puzzle> {
+    parents: HashMap<P, Option<(P, P::Move)>>,
+    goal: Option<P>,
+}
+
+impl<P> SearchGraph<P>
+where
+    P: Puzzle + Eq + Hash,
+{
+    /// The number of visited puzzle states.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Determines whether or not no puzzle state was visited.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Determines whether or not the puzzle state `p` was visited.
+    pub fn contains(&self, p: &P) -> bool {
+        self.parents.contains_key(p)
+    }
+
+    /// The predecessor puzzle state of the visited puzzle state `p` (with the
+    /// move from it to `p`), or `None` if `p` is the initial puzzle state or
+    /// was not visited.
+    pub fn parent(&self, p: &P) -> Option<(&P, &P::Move)> {
+        self.parents.get(p)?.as_ref().map(|(q, m)| (q, m))
+    }
+
+    /// The goal state reached by the search, if any.
+    pub fn goal(&self) -> Option<&P> {
+        self.goal.as_ref()
+    }
+
+    /// An iterator over the visited puzzle states (in no particular order).
+    pub fn states(&self) -> impl Iterator<Item = &P> {
+        self.parents.keys()
+    }
+
+    /// The sequence of moves from the initial puzzle state to the visited
+    /// puzzle state `p` (which is one of the shortest), or `None` if `p` was
+    /// not visited.
+    pub fn path_to(&self, p: &P) -> Option<Vec<P::Move>>
+    where
+        P::Move: Clone,
+    {
+        let mut vec = vec![];
+        let mut p = p;
+        while let Some((q, m)) = self.parents.get(p)? {
+            vec.push(m.clone());
+            p = q;
+        }
+        vec.reverse();
+        Some(vec)
+    }
+}
+
+/// Solve a puzzle using BFS with hashing of states, and return the explored
+/// graph.
+///
+/// Returns `(soln, graph)`, where `soln` is `Some((ms,p))` if puzzle `p0` can
+/// be solved by the sequence of moves `ms` to a goal state `p`, which should be
+/// one of the shortest sequence of moves from `p0` to a goal state (as for
+/// `solve`), or `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// Unlike `solve`, whose hash table is dropped (and whose arena is consumed)
+/// when the solution is recovered, the hash table of predecessor puzzle states
+/// is returned as `graph` (see `SearchGraph`), for analyses of the explored
+/// puzzle states (e.g., the paths to other visited puzzle states).  The
+/// representatives of puzzle states (see `Puzzle::canonicalize`) and the scores
+/// of goal states (see `Puzzle::goal_score`) are ignored.
+pub fn solve_with_graph<P>(p0: P) -> (Solution<P>, SearchGraph<P>)
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    let mut reached = Reached::new();
+    let goal = explore_by(p0, &mut Explored, Fifo::new(), &mut reached);
+    let soln = goal.as_ref().map(|(i, p)| (reached.path(*i), p.clone()));
+    let graph = SearchGraph {
+        parents: reached.into_parents(),
+        goal: goal.map(|(_, p)| p),
+    };
+    (soln, graph)
+}
+
+/// The hooks of `solve_with_graph`.
+struct Explored;
+
+impl<P: Puzzle + Eq + Hash + Clone> Search<P> for Explored {
+    const NAME: &'static str = "solve_with_graph";
+    type Key = P;
+    type Frontier = Fifo<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.clone()
+    }
+
+    fn priority(&mut self, _: usize, _: &P) {}
+}
+
+/// Which part of a `SearchGraph` is written by `export_search_graph_with`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DotOptions {
+    max_nodes: Option<usize>,
+    solution_cone: bool,
+}
+
+impl DotOptions {
+    /// Options that write the whole graph.
+    pub fn new() -> Self {
+        DotOptions::default()
+    }
+
+    /// Write at most `nodes` puzzle states: those nearest to the initial
+    /// puzzle state (so that the written graph remains a tree from it).
+    pub fn max_nodes(mut self, nodes: usize) -> Self {
+        self.max_nodes = Some(nodes);
+        self
+    }
+
+    /// Only write the solution cone: the puzzle states of the path to the
+    /// goal state, and the puzzle states first reached from them (i.e., the
+    /// alternatives to each move of the solution).  Nothing is written but
+    /// the initial puzzle state if no goal state was reached.
+    pub fn solution_cone(mut self) -> Self {
+        self.solution_cone = true;
+        self
+    }
+}
+
+/// Write the graph explored by a BFS (see `solve_with_graph`) to `w`, in the
+/// DOT language of Graphviz (e.g., for `dot -Tsvg`).
+///
+/// That is, `export_search_graph_with(graph, w, &DotOptions::new())`.
+pub fn export_search_graph<P, W>(graph: &SearchGraph<P>, w: W) -> io::Result<()>
+where
+    P: Puzzle + Eq + Hash + Display,
+    P::Move: Debug,
+    W: Write,
+{
+    export_search_graph_with(graph, w, &DotOptions::new())
+}
+
+/// Write (part of, as chosen by `options`) the graph explored by a BFS (see
+/// `solve_with_graph`) to `w`, in the DOT language of Graphviz.
+///
+/// Each node is a visited puzzle state, labeled by its `Display` form (one
+/// left-justified line per line), and each edge is the move (labeled by its
+/// `Debug` form) by which a puzzle state was first reached.  The goal state
+/// has a double border, and the edges of the path to it are bold, so that the
+/// solution stands out from the alternatives that the search also explored.
+/// The nodes are numbered in breadth-first order from the initial puzzle
+/// state, with the successors of each puzzle state in the order of their
+/// labels, so that the same graph is always written the same way.
+pub fn export_search_graph_with<P, W>(
+    graph: &SearchGraph<P>,
+    w: W,
+    options: &DotOptions,
+) -> io::Result<()>
+where
+    P: Puzzle + Eq + Hash + Display,
+    P::Move: Debug,
+    W: Write,
+{
+    let mut children = HashMap::<&P, Vec<(&P, &P::Move)>>::new();
+    let mut root = None;
+    for (p, parent) in &graph.parents {
+        match parent {
+            Some((q, m)) => children.entry(q).or_default().push((p, m)),
+            None => root = Some(p),
+        }
+    }
+    // the puzzle states of the path to the goal state
+    let path: HashSet<&P> =
+        std::iter::successors(graph.goal.as_ref(), |p| graph.parent(p).map(|(q, _)| q)).collect();
+    let max_nodes = options.max_nodes.unwrap_or(usize::MAX);
+    let mut w = BufWriter::new(w);
+    writeln!(w, "digraph search {{")?;
+    writeln!(w, "    node [shape=box, fontname=monospace];")?;
+    let mut queue = VecDeque::from_iter(root.filter(|_| max_nodes > 0).map(|p| (p, 0)));
+    let mut nodes = queue.len();
+    while let Some((p, i)) = queue.pop_front() {
+        let border = if graph.goal.as_ref() == Some(p) {
+            ", peripheries=2"
+        } else {
+            ""
+        };
+        writeln!(w, "    n{} [label=\"{}\"{}];", i, label(p), border)?;
+        if options.solution_cone && !path.contains(p) {
+            continue;
+        }
+        let mut next: Vec<_> = children
+            .get(p)
+            .into_iter()
+            .flatten()
+            .map(|&(q, m)| (label(q), q, m))
+            .collect();
+        next.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+        for (_, q, m) in next {
+            if nodes == max_nodes {
+                break;
+            }
+            let style = if path.contains(q) { ", style=bold" } else { "" };
+            let label = escape(&format!("{:?}", m));
+            writeln!(
+                w,
+                "    n{} -> n{} [label=\"{}\"{}];",
+                i, nodes, label, style
+            )?;
+            queue.push_back((q, nodes));
+            nodes += 1;
+        }
+    }
+    writeln!(w, "}}")?;
+    w.flush()
+}
+
+/// The label of the puzzle state `p` in DOT: its lines, each left-justified.
+fn label<P: Display>(p: &P) -> String {
+    p.to_string()
+        .lines()
+        .map(|line| escape(line) + "\\l")
+        .collect()
+}
+
+/// The string `s` escaped as (part of) a quoted string of DOT.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
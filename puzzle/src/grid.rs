@@ -0,0 +1,400 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+/// The directions in which a piece may move on a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GridDirection {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl GridDirection {
+    /// All directions.
+    pub const VALUES: [Self; 4] = [
+        GridDirection::North,
+        GridDirection::South,
+        GridDirection::West,
+        GridDirection::East,
+    ];
+
+    /// The position adjacent to the position `(row, col)` in this direction,
+    /// if it is on a grid of `width` columns and `height` rows.
+    pub fn step(
+        &self,
+        (row, col): (usize, usize),
+        width: usize,
+        height: usize,
+    ) -> Option<(usize, usize)> {
+        let (row, col) = match self {
+            GridDirection::North => (row.checked_sub(1)?, col),
+            GridDirection::South => (row + 1, col),
+            GridDirection::West => (row, col.checked_sub(1)?),
+            GridDirection::East => (row, col + 1),
+        };
+        (row < height && col < width).then_some((row, col))
+    }
+}
+
+/// The ways in which the pieces of a grid puzzle move (see `grid_moves`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Movement {
+    /// A piece moves to the adjacent position, which must be empty.
+    Step,
+    /// A piece slides through empty positions, as far as possible.
+    Slide,
+    /// A piece jumps over one or more adjacent pieces, to the first empty
+    /// position.
+    Jump,
+}
+
+/// A move of a grid puzzle: the piece at `(row, col)` moves in the direction
+/// `dir`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GridMove {
+    pub row: usize,
+    pub col: usize,
+    pub dir: GridDirection,
+}
+
+/// The positions of a grid of `W` columns and `H` rows in the direction `dir`
+/// from (but excluding) the position `pos`, up to the edge of the grid.
+fn ray<const W: usize, const H: usize>(
+    pos: (usize, usize),
+    dir: GridDirection,
+) -> impl Iterator<Item = (usize, usize)> {
+    std::iter::successors(dir.step(pos, W, H), move |&pos| dir.step(pos, W, H))
+}
+
+/// Swap the cells at positions `a` and `b` of `grid`.
+fn swap_cells<C, const W: usize, const H: usize>(
+    grid: &mut [[C; W]; H],
+    (row1, col1): (usize, usize),
+    (row2, col2): (usize, usize),
+) {
+    if row1 == row2 {
+        grid[row1].swap(col1, col2);
+    } else {
+        let ((lo_row, lo_col), (hi_row, hi_col)) = if row1 < row2 {
+            ((row1, col1), (row2, col2))
+        } else {
+            ((row2, col2), (row1, col1))
+        };
+        let (top, bottom) = grid.split_at_mut(hi_row);
+        std::mem::swap(&mut top[lo_row][lo_col], &mut bottom[0][hi_col]);
+    }
+}
+
+/// The position to which the piece at `(row, col)` of `grid` moves in the
+/// direction `dir`, if the move is legal.
+fn target<C, const W: usize, const H: usize>(
+    grid: &[[C; W]; H],
+    (row, col): (usize, usize),
+    dir: GridDirection,
+    movement: Movement,
+    is_empty: &impl Fn(&C) -> bool,
+) -> Option<(usize, usize)> {
+    let empty = |&(r, c): &(usize, usize)| is_empty(&grid[r][c]);
+    let mut ray = ray::<W, H>((row, col), dir);
+    match movement {
+        Movement::Step => ray.next().filter(empty),
+        Movement::Slide => ray.take_while(empty).last(),
+        Movement::Jump => {
+            ray.next().filter(|pos| !empty(pos))?;
+            ray.find(empty)
+        }
+    }
+}
+
+/// Enumerates all of the legal moves of the pieces of `grid` (a grid of `W`
+/// columns and `H` rows of cells), in row-major order of the pieces and the
+/// order of `GridDirection::VALUES`.
+///
+/// A cell is a piece unless `is_empty`; a piece moves (according to
+/// `movement`) unless `is_fixed`, but every piece is an obstacle.  (For the
+/// board of a `Grid`, pass `Grid::cells`.)
+pub fn grid_moves<C, const W: usize, const H: usize>(
+    grid: &[[C; W]; H],
+    movement: Movement,
+    is_empty: impl Fn(&C) -> bool,
+    is_fixed: impl Fn(&C) -> bool,
+) -> Vec<GridMove> {
+    let mut ms = vec![];
+    for (row, cells) in grid.iter().enumerate() {
+        for (col, cell) in cells.iter().enumerate() {
+            if is_empty(cell) || is_fixed(cell) {
+                continue;
+            }
+            for dir in GridDirection::VALUES {
+                if target(grid, (row, col), dir, movement, &is_empty).is_some() {
+                    ms.push(GridMove { row, col, dir });
+                }
+            }
+        }
+    }
+    ms
+}
+
+/// Makes the (legal) move `m` on `grid`, by swapping the moved piece with the
+/// empty cell to which it moves.
+///
+/// Panics if `m` is not a legal move (according to `movement` and `is_empty`).
+pub fn grid_apply<C, const W: usize, const H: usize>(
+    grid: &mut [[C; W]; H],
+    m: &GridMove,
+    movement: Movement,
+    is_empty: impl Fn(&C) -> bool,
+) {
+    let from = (m.row, m.col);
+    let legal = m.row < H && m.col < W && !is_empty(&grid[m.row][m.col]);
+    let to = legal
+        .then(|| target(grid, from, m.dir, movement, &is_empty))
+        .flatten();
+    match to {
+        Some(to) => swap_cells(grid, from, to),
+        None => panic!("grid_apply m (is {:?}) should be a legal move", m),
+    }
+}
+
+/// A grid of `W` columns and `H` rows of cells `T`, as the board of a grid
+/// puzzle.
+///
+/// The positions of the grid are `(row, col)` pairs, where `(0, 0)` is the
+/// top-left (North-West) corner; the cells are stored in row-major order, and
+/// a grid serializes exactly as its array of rows.  A grid of `Option<T>`
+/// cells is the board of a puzzle whose positions are either occupied by a
+/// piece `T` or unoccupied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Grid<T, const W: usize, const H: usize>([[T; W]; H]);
+
+impl<T, const W: usize, const H: usize> Grid<T, W, H> {
+    /// The grid with the array of rows `cells`.
+    pub const fn new(cells: [[T; W]; H]) -> Self {
+        Grid(cells)
+    }
+
+    /// The grid whose cell at each position is `f(pos)`.
+    pub fn from_fn(mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        Grid(std::array::from_fn(|row| {
+            std::array::from_fn(|col| f((row, col)))
+        }))
+    }
+
+    /// The number of columns of the grid.
+    pub const fn width(&self) -> usize {
+        W
+    }
+
+    /// The number of rows of the grid.
+    pub const fn height(&self) -> usize {
+        H
+    }
+
+    /// The array of rows of the grid.
+    pub fn cells(&self) -> &[[T; W]; H] {
+        &self.0
+    }
+
+    /// The (mutable) array of rows of the grid.
+    pub fn cells_mut(&mut self) -> &mut [[T; W]; H] {
+        &mut self.0
+    }
+
+    /// Determines whether or not `pos` is a position of the grid.
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < H && col < W
+    }
+
+    /// The cell at position `pos`, if `pos` is a position of the grid.
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<&T> {
+        self.0.get(row)?.get(col)
+    }
+
+    /// The (mutable) cell at position `pos`, if `pos` is a position of the
+    /// grid.
+    pub fn get_mut(&mut self, (row, col): (usize, usize)) -> Option<&mut T> {
+        self.0.get_mut(row)?.get_mut(col)
+    }
+
+    /// An iterator over all positions of the grid, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        (0..H).flat_map(|row| (0..W).map(move |col| (row, col)))
+    }
+
+    /// An iterator over all positions of the grid, with their cells, in
+    /// row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.0.iter().enumerate().flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(move |(col, cell)| ((row, col), cell))
+        })
+    }
+
+    /// An iterator over the (at most four) positions adjacent to position
+    /// `pos`, with their directions from `pos`, in the order of
+    /// `GridDirection::VALUES`.
+    pub fn neighbors(
+        &self,
+        pos: (usize, usize),
+    ) -> impl Iterator<Item = (GridDirection, (usize, usize))> {
+        GridDirection::VALUES
+            .into_iter()
+            .filter_map(move |dir| Some((dir, dir.step(pos, W, H)?)))
+    }
+
+    /// An iterator over the positions (with their cells) in the line of sight
+    /// from position `pos` in the direction `dir`; that is, the positions from
+    /// (but excluding) `pos` to the edge of the grid, nearest first.
+    pub fn line_of_sight(
+        &self,
+        pos: (usize, usize),
+        dir: GridDirection,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        ray::<W, H>(pos, dir).map(move |pos| (pos, &self[pos]))
+    }
+
+    /// Swap the cells at positions `a` and `b`.
+    ///
+    /// Panics if `a` or `b` is not a position of the grid.
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        if !self.contains(a) {
+            panic!("Grid::swap a (is {:?}) should be a position of the grid", a)
+        }
+        if !self.contains(b) {
+            panic!("Grid::swap b (is {:?}) should be a position of the grid", b)
+        }
+        swap_cells(&mut self.0, a, b)
+    }
+}
+
+impl<T, const W: usize, const H: usize> Grid<Option<T>, W, H> {
+    /// Determines whether or not position `pos` is occupied by a piece.
+    ///
+    /// Positions off the grid are never occupied.
+    pub fn is_occupied(&self, pos: (usize, usize)) -> bool {
+        matches!(self.get(pos), Some(Some(_)))
+    }
+
+    /// An iterator over the occupied positions of the grid, with their pieces,
+    /// in row-major order.
+    pub fn occupied(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.iter()
+            .filter_map(|(pos, cell)| Some((pos, cell.as_ref()?)))
+    }
+
+    /// The nearest occupied position (with its piece) in the line of sight
+    /// from position `pos` in the direction `dir`, if any.
+    pub fn first_occupied(
+        &self,
+        pos: (usize, usize),
+        dir: GridDirection,
+    ) -> Option<((usize, usize), &T)> {
+        self.line_of_sight(pos, dir)
+            .find_map(|(pos, cell)| Some((pos, cell.as_ref()?)))
+    }
+
+    /// The nearest unoccupied position in the line of sight from position
+    /// `pos` in the direction `dir`, if any.
+    pub fn first_unoccupied(
+        &self,
+        pos: (usize, usize),
+        dir: GridDirection,
+    ) -> Option<(usize, usize)> {
+        self.line_of_sight(pos, dir)
+            .find(|(_, cell)| cell.is_none())
+            .map(|(pos, _)| pos)
+    }
+}
+
+impl<T: Default, const W: usize, const H: usize> Default for Grid<T, W, H> {
+    fn default() -> Self {
+        Grid::from_fn(|_| T::default())
+    }
+}
+
+impl<T, const W: usize, const H: usize> Index<(usize, usize)> for Grid<T, W, H> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.0[row][col]
+    }
+}
+
+impl<T, const W: usize, const H: usize> IndexMut<(usize, usize)> for Grid<T, W, H> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.0[row][col]
+    }
+}
+
+/// A row of a grid (see `Grid`), serialized as a tuple of its cells.
+struct Row<'a, T, const W: usize>(&'a [T; W]);
+
+impl<T: Serialize, const W: usize> Serialize for Row<'_, T, W> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(W)?;
+        for cell in self.0 {
+            tuple.serialize_element(cell)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<T: Serialize, const W: usize, const H: usize> Serialize for Grid<T, W, H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(H)?;
+        for row in &self.0 {
+            tuple.serialize_element(&Row(row))?;
+        }
+        tuple.end()
+    }
+}
+
+/// Deserializes a tuple of exactly `N` elements of type `T` as an array.
+struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a tuple of {} elements", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[T; N], A::Error> {
+        let mut elems = Vec::with_capacity(N);
+        while elems.len() < N {
+            match seq.next_element()? {
+                Some(elem) => elems.push(elem),
+                None => return Err(A::Error::invalid_length(elems.len(), &self)),
+            }
+        }
+        Ok(elems
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("exactly N elements")))
+    }
+}
+
+/// A row of a grid (see `Grid`), deserialized from a tuple of its cells.
+struct RowOwned<T, const W: usize>([T; W]);
+
+impl<'de, T: Deserialize<'de>, const W: usize> Deserialize<'de> for RowOwned<T, W> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_tuple(W, ArrayVisitor::<T, W>(PhantomData))
+            .map(RowOwned)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const W: usize, const H: usize> Deserialize<'de> for Grid<T, W, H> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows =
+            deserializer.deserialize_tuple(H, ArrayVisitor::<RowOwned<T, W>, H>(PhantomData))?;
+        Ok(Grid(rows.map(|RowOwned(row)| row)))
+    }
+}
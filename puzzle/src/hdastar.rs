@@ -0,0 +1,317 @@
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::astar::Priority;
+use super::frontier::{Ended, Solved};
+use super::{
+    BfsLimits, Frontier, Heap, Heuristic, Observer, Puzzle, SearchStats, SolveError, SolveOutcome,
+    SolverConfig,
+};
+
+/// A puzzle state sent to the worker that owns it: the number of moves to it
+/// and its predecessor (with the connecting move).
+type Message<P, M> = (P, usize, Option<(P, M)>);
+
+/// A hash table recording, for each puzzle state owned by a worker, the fewest
+/// number of moves found so far to it and its predecessor.
+type Closed<P, M> = HashMap<P, (usize, Option<(P, M)>)>;
+
+/// The (shared) state of a hash-distributed A* search.
+struct Shared<P> {
+    /// Used to assign each puzzle state to the worker that owns it.
+    hasher: RandomState,
+    /// The number of puzzle states that have been sent to (or are in the open
+    /// list of) some worker, but have not yet been expanded or discarded.  The
+    /// search finishes when this reaches `0`.
+    pending: AtomicUsize,
+    /// The number of moves of the shortest solution found so far.
+    incumbent: AtomicUsize,
+    /// The goal state of the shortest solution found so far.
+    goal: Mutex<Option<(usize, P)>>,
+    /// The limits of the search.
+    limits: BfsLimits,
+    /// The number of puzzle states recorded by the workers.
+    visited: AtomicUsize,
+    /// Whether or not a puzzle state at the `max_depth` of `limits` was left
+    /// unexpanded.
+    cut: AtomicBool,
+    /// The error of the first limit of `limits` exceeded, if any, upon which
+    /// the workers stop.
+    error: Mutex<Option<SolveError>>,
+    /// Whether or not the workers should stop (as a limit was exceeded).
+    stop: AtomicBool,
+}
+
+impl<P> Shared<P> {
+    /// Stop the search, failing with `err` (unless it already failed).
+    fn fail(&self, err: SolveError) {
+        self.error.lock().unwrap().get_or_insert(err);
+        self.stop.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Solve a puzzle using HDA* (hash-distributed A*) search.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  If `P::estimate` is admissible, then the sequence
+/// of moves `ms` is one of the shortest sequence of moves from `p0` to a goal
+/// state (as with `solve_astar`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// The search is performed by `threads` worker threads.  Each puzzle state is
+/// owned by the worker determined by its hash, which keeps the open list
+/// (priority queue) and the hash table of the puzzle states that it owns;
+/// successor puzzle states are sent (by a channel) to the worker that owns
+/// them.  Because no locks are held while expanding puzzle states, the search
+/// scales well with the number of threads.  A goal state does not finish the
+/// search (other workers may still find a shorter solution), but puzzle states
+/// that cannot lead to a shorter solution are discarded; the search finishes
+/// when every puzzle state has been expanded or discarded.
+///
+/// The generic type parameter `P` (and its moves) must additionally implement
+/// `Send` and `Sync`, in order to be sent between threads.
+pub fn solve_hdastar<P>(p0: P, threads: usize) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone + Send + Sync,
+    P::Move: Clone + Send,
+{
+    if threads == 0 {
+        panic!("solve_hdastar threads (is {}) should be positive", threads)
+    }
+    let limits = BfsLimits {
+        max_states: usize::MAX,
+        ..BfsLimits::new(&p0)
+    };
+    match hdastar(p0, threads, limits, &mut 0) {
+        Ok(ended) => ended.goal(),
+        Err(err) => unreachable!("solve_hdastar has no limit for {:?}", err),
+    }
+}
+
+/// Solve a puzzle using HDA* (hash-distributed A*) search, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_hdastar(p0, threads)` (or, with a `max_depth`, without expanding the
+/// puzzle states `max_depth` moves from `p0`) if the search finds it within
+/// the limits of `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// The search is that of `solve_hdastar`, by `config.parallelism` worker
+/// threads (or one per CPU, if `0`; see `SolverConfig::parallelism`).  Every
+/// puzzle state recorded by a worker counts towards the `max_nodes` (and the
+/// `memory_limit`) of `config`, and the time limit (and the cancel token) is
+/// checked before each expansion; once a limit is exceeded, every worker
+/// stops.  The hasher and the `dedupe_scope` of `config` are ignored, and the
+/// puzzle states are expanded on the worker threads, so the observer of
+/// `config` is only told of the solution and of the number of visited puzzle
+/// states (as the statistics of the search).
+pub fn solve_hdastar_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone + Send + Sync,
+    P::Move: Clone + Send,
+    O: Observer<P>,
+{
+    let start = Instant::now();
+    let limits = config.bfs_limits(&p0, start);
+    let threads = match config.parallelism {
+        0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        threads => threads,
+    };
+    let (mut stats, observer) = (SearchStats::default(), &mut *config.observer.borrow_mut());
+    let ended = hdastar(p0, threads, limits, &mut stats.visited);
+    let outcome = SolveOutcome::new(ended, stats.visited, |(vec, p)| {
+        observer.on_solution(&vec, &p);
+        stats.depth = Some(vec.len());
+        (vec, p)
+    });
+    stats.finish(start, observer);
+    outcome
+}
+
+/// HDA* search by `threads` worker threads within the `max_depth`,
+/// `max_states`, `max_memory_states`, `deadline` (and `cancel`) of `limits`,
+/// counting the recorded puzzle states in `visited`.
+fn hdastar<P>(p0: P, threads: usize, limits: BfsLimits, visited: &mut usize) -> Solved<P>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone + Send + Sync,
+    P::Move: Clone + Send,
+{
+    let shared = Shared {
+        hasher: RandomState::new(),
+        pending: AtomicUsize::new(1),
+        incumbent: AtomicUsize::new(usize::MAX),
+        goal: Mutex::new(None),
+        limits,
+        visited: AtomicUsize::new(0),
+        cut: AtomicBool::new(false),
+        error: Mutex::new(None),
+        stop: AtomicBool::new(false),
+    };
+    let (senders, receivers): (Vec<_>, Vec<_>) = (0..threads).map(|_| mpsc::channel()).unzip();
+    let owner = shared.hasher.hash_one(&p0) as usize % threads;
+    senders[owner].send((p0, 0, None)).unwrap();
+
+    let closed: Vec<_> = thread::scope(|s| {
+        let workers: Vec<_> = receivers
+            .into_iter()
+            .map(|receiver| {
+                let senders = senders.clone();
+                let shared = &shared;
+                s.spawn(move || worker(receiver, senders, shared))
+            })
+            .collect();
+        workers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+
+    *visited = shared.visited.into_inner();
+    if let Some(err) = shared.error.into_inner().unwrap() {
+        return Err(err);
+    }
+    let p = match shared.goal.into_inner().unwrap() {
+        Some((_, p)) => p,
+        None if shared.cut.into_inner() => return Ok(Ended::Cut),
+        None => return Ok(Ended::Exhausted),
+    };
+    let hash_map: HashMap<P, Option<(P, P::Move)>> = closed.into_iter().flatten().collect();
+    // backtrack using predecessors
+    let mut vec = vec![];
+    let mut q = &p;
+    loop {
+        match hash_map.get(q) {
+            Some(Some((pred, m))) => {
+                vec.push(m.clone());
+                q = pred;
+            }
+            Some(None) => break,
+            None => panic!("solve_hdastar predecessors should be recorded for every puzzle state"),
+        }
+    }
+    vec.reverse();
+    Ok(Ended::Goal((vec, p)))
+}
+
+/// A worker of a hash-distributed A* search: receives the puzzle states that
+/// it owns on `receiver` and sends successor puzzle states to their owners on
+/// `senders`.  Returns the predecessors of the puzzle states that it owns.
+fn worker<P>(
+    receiver: Receiver<Message<P, P::Move>>,
+    senders: Vec<Sender<Message<P, P::Move>>>,
+    shared: &Shared<P>,
+) -> HashMap<P, Option<(P, P::Move)>>
+where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    let mut hash_map = Closed::new();
+    let mut heap = Heap::new();
+
+    loop {
+        if shared.stop.load(Ordering::SeqCst) {
+            break;
+        }
+        while let Ok(msg) = receiver.try_recv() {
+            receive(msg, &mut hash_map, &mut heap, shared);
+        }
+        let (g, p) = match heap.pop() {
+            Some(node) => node,
+            None => {
+                if shared.pending.load(Ordering::SeqCst) == 0 {
+                    break;
+                }
+                match receiver.recv_timeout(Duration::from_millis(1)) {
+                    Ok(msg) => receive(msg, &mut hash_map, &mut heap, shared),
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+                continue;
+            }
+        };
+
+        let incumbent = shared.incumbent.load(Ordering::SeqCst);
+        if hash_map.get(&p).is_some_and(|(gp, _)| *gp < g) {
+            // a shorter path to `p` has already been expanded
+        } else if g + p.estimate() >= incumbent {
+            // cannot lead to a shorter solution
+        } else if p.is_goal() {
+            let mut goal = shared.goal.lock().unwrap();
+            if goal.as_ref().is_none_or(|(gg, _)| g < *gg) {
+                shared.incumbent.store(g, Ordering::SeqCst);
+                *goal = Some((g, p));
+            }
+        } else if p.prune() {
+            // cannot lead to a solution
+        } else if g >= shared.limits.max_depth {
+            // the successor puzzle states of the horizon would be beyond it
+            shared.cut.store(true, Ordering::SeqCst);
+        } else if let Err(err) = shared
+            .limits
+            .expand(shared.visited.load(Ordering::SeqCst), g)
+        {
+            shared.fail(err);
+        } else {
+            for (m, q) in p.successors() {
+                let gq = g + 1;
+                if gq + q.estimate() >= incumbent {
+                    continue;
+                }
+                let owner = shared.hasher.hash_one(&q) as usize % senders.len();
+                shared.pending.fetch_add(1, Ordering::SeqCst);
+                if senders[owner].send((q, gq, Some((p.clone(), m)))).is_err() {
+                    shared.pending.fetch_sub(1, Ordering::SeqCst);
+                }
+            }
+        }
+        // the puzzle state `p` has been expanded or discarded
+        shared.pending.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    hash_map
+        .into_iter()
+        .map(|(q, (_, pred))| (q, pred))
+        .collect()
+}
+
+/// Record the puzzle state of `msg` in `hash_map` and `heap`, unless a path to
+/// it that is at least as short has already been recorded.
+fn receive<P>(
+    (p, g, pred): Message<P, P::Move>,
+    hash_map: &mut Closed<P, P::Move>,
+    heap: &mut Heap<(usize, P), Priority>,
+    shared: &Shared<P>,
+) where
+    P: Puzzle + Heuristic + Eq + Hash + Clone,
+{
+    match hash_map.entry(p.clone()) {
+        Vacant(e) => {
+            if let Err(err) = shared
+                .limits
+                .visit(shared.visited.fetch_add(1, Ordering::SeqCst))
+            {
+                shared.fail(err);
+                shared.pending.fetch_sub(1, Ordering::SeqCst);
+                return;
+            }
+            e.insert((g, pred));
+        }
+        Occupied(mut e) => {
+            if e.get().0 <= g {
+                // the puzzle state `p` has been discarded
+                shared.pending.fetch_sub(1, Ordering::SeqCst);
+                return;
+            }
+            e.insert((g, pred));
+        }
+    }
+    let f = (g + p.estimate()) as f64;
+    heap.push((g, p), Priority { f, g });
+}
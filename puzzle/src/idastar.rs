@@ -0,0 +1,132 @@
+use std::convert::Infallible;
+
+use super::dfs::{dfs_by_config, ended, search, Search};
+use super::{Heuristic, Observer, Puzzle, Solution, SolveOutcome, SolverConfig};
+
+/// Solve a puzzle using IDA* (iterative-deepening A*) search.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  If `P::estimate` is admissible, then the sequence
+/// of moves `ms` is one of the shortest sequence of moves from `p0` to a goal
+/// state (as with `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// A sequence of depth-first searches is performed, each bounded by a
+/// threshold on the number of moves so far plus `P::estimate`; the threshold
+/// of the first search is the estimate of `p0` and the threshold of each
+/// subsequent search is the smallest value that exceeded the threshold of the
+/// previous search.  Unlike `solve` and `solve_astar`, only the puzzle states
+/// along the current path are kept in memory (and are used to avoid cycles),
+/// at the expense of re-expanding puzzle states that are reached by different
+/// sequences of moves.  In particular, proving that a puzzle cannot be solved
+/// requires enumerating every cycle-free sequence of moves, which may take a
+/// very long time.
+///
+/// The generic type parameter `P` must implement `Eq` (in order to detect
+/// cycles along the current path) but, unlike `solve`, need not implement
+/// `Hash`.  A puzzle without a meaningful heuristic may implement `Heuristic`
+/// with an `estimate` of `0`, in which case IDA* degenerates to an
+/// iterative-deepening depth-first search.
+pub fn solve_idastar<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Heuristic + Eq,
+{
+    idastar_by(p0, P::estimate)
+}
+
+/// Solve a puzzle using IDDFS (iterative-deepening depth-first search).
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  The sequence of moves `ms` is one of the shortest
+/// sequence of moves from `p0` to a goal state (as with `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// A sequence of depth-limited depth-first searches (see `solve_dfs`) is
+/// performed, with depth limits `0`, `1`, `2`, ..., until a goal state is
+/// found.  This is `solve_idastar` without a heuristic (other than
+/// `P::lower_bound`, by which the depth-limited searches abandon puzzle states
+/// early); only the puzzle states along the current path are kept in memory.
+pub fn solve_iddfs<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq,
+{
+    idastar_by(p0, P::lower_bound)
+}
+
+/// Solve a puzzle using IDA* (iterative-deepening A*) search, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_idastar(p0)`
+/// (or, with a `max_depth`, without expanding the puzzle states `max_depth`
+/// moves from `p0`) if the search finds it within the limits of `config`, and
+/// otherwise the outcome of the search as for `solve_with_config`.
+///
+/// Every expansion (by every depth-first search) counts as a visit of a
+/// puzzle state towards the `max_nodes` and the `memory_limit` of `config`
+/// (each of which is a limit on the work of the search, rather than on its
+/// memory, which is only that of the current path), and the time limit (and
+/// the cancel token) is checked before each expansion.  No puzzle states are
+/// hashed, so the `hasher` and the `dedupe_scope` of `config` are ignored, as
+/// is its `parallelism`; the observer of `config` is told of each expansion,
+/// of the solution, and of the number of expansions (as the statistics of the
+/// search).
+pub fn solve_idastar_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Puzzle + Heuristic + Eq,
+    O: Observer<P>,
+{
+    dfs_by_config(p0, config, |p0, check| {
+        idastar_by_checked(p0, P::estimate, check).map(ended)
+    })
+}
+
+/// Solve a puzzle using IDDFS (iterative-deepening depth-first search), as
+/// configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_iddfs(p0)`
+/// (or, with a `max_depth`, without expanding the puzzle states `max_depth`
+/// moves from `p0`) if the search finds it within the limits of `config`, and
+/// otherwise the outcome of the search as for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_idastar_with_config`.
+pub fn solve_iddfs_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq,
+    O: Observer<P>,
+{
+    dfs_by_config(p0, config, |p0, check| {
+        idastar_by_checked(p0, P::lower_bound, check).map(ended)
+    })
+}
+
+/// IDA* search with the heuristic supplied as the function `h`.
+pub(crate) fn idastar_by<P, H>(p0: P, h: H) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq,
+    H: Fn(&P) -> usize,
+{
+    let Ok(soln) = idastar_by_checked(p0, h, &mut |_, _| Ok::<_, Infallible>(true));
+    soln
+}
+
+/// IDA* search with the heuristic supplied as the function `h`, where `check`
+/// is called before each expansion (see `dfs::search`).
+fn idastar_by_checked<P, H, C, E>(p0: P, h: H, check: &mut C) -> Result<Solution<P>, E>
+where
+    P: Puzzle + Eq,
+    H: Fn(&P) -> usize,
+    C: FnMut(&P, usize) -> Result<bool, E> + ?Sized,
+{
+    let mut bound = h(&p0);
+    let mut path = vec![p0];
+    let mut moves = Vec::new();
+    loop {
+        match search(&mut path, &mut moves, bound, &h, check)? {
+            Search::Found(p) => return Ok(Some((moves, p))),
+            Search::Cutoff(None) => return Ok(None),
+            Search::Cutoff(Some(next_bound)) => bound = next_bound,
+        }
+    }
+}
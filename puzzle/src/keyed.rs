@@ -0,0 +1,77 @@
+use std::hash::{BuildHasher, Hash};
+
+use super::frontier::{solve_by, solve_by_config, Reached, Search};
+use super::{Fifo, Observer, Puzzle, SolveOutcome, SolverConfig};
+
+/// Trait for puzzles whose states can be identified by compact keys (e.g., a
+/// packed encoding of a gameboard as a `u64`), so that solvers can record keys
+/// rather than whole puzzle states.
+pub trait Keyed: Puzzle {
+    /// The type of keys of puzzle states.
+    type Key: Eq + Hash + Clone;
+
+    /// Returns the key of the current puzzle state.
+    ///
+    /// Different puzzle states must have different keys, unless (as for
+    /// `Puzzle::canonicalize`) they are equivalent puzzle states, which need
+    /// the same number of moves to reach a goal state.
+    fn key(&self) -> Self::Key;
+}
+
+/// Solve a puzzle using BFS with hashing of keys of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  The sequence of moves `ms` should be one of the
+/// shortest sequence of moves from `p0` to a goal state (as with `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// The BFS is the same as that of `solve`, except that the hash table is keyed
+/// by the key (see `Keyed::key`) of each puzzle state reached, rather than by
+/// (the representative of) the puzzle state.  Only the puzzle states of the
+/// BFS queue are
+/// kept in memory, so when keys are much smaller than puzzle states, this
+/// requires much less memory than `solve`.  The generic type parameter `P`
+/// need not implement `Eq`, `Hash`, or `Clone`.
+pub fn solve_keyed<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Keyed,
+    P::Move: Clone,
+{
+    solve_by(p0, &mut ByKey, Fifo::new(), &mut Reached::new())
+}
+
+/// Solve a puzzle using BFS with hashing of keys of states, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_keyed(p0)`
+/// (or, with a `max_depth`, without expanding the puzzle states `max_depth`
+/// moves from `p0`) if the search finds it within the limits of `config`, and
+/// otherwise the outcome of the search as for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_with_config`, with the hasher
+/// hashing the keys of puzzle states.
+pub fn solve_keyed_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Keyed,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    solve_by_config(p0, &mut ByKey, Fifo::new(), config)
+}
+
+/// The hooks of `solve_keyed`.
+struct ByKey;
+
+impl<P: Keyed> Search<P> for ByKey {
+    const NAME: &'static str = "solve_keyed";
+    type Key = P::Key;
+    type Frontier = Fifo<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P::Key {
+        p.key()
+    }
+
+    fn priority(&mut self, _: usize, _: &P) {}
+}
@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use super::frontier::{solve_by, Reached, Search};
+use super::{Fifo, Puzzle};
+
+/// A sequence of moves along with the puzzle states along it (from the
+/// initial puzzle state to the last puzzle state, so that `states.len() ==
+/// moves.len() + 1`).
+struct Path<P, M> {
+    moves: Vec<M>,
+    states: Vec<P>,
+}
+
+/// Solve a puzzle for the `k` shortest sequences of moves, using Yen's
+/// algorithm with BFS with hashing of states.
+///
+/// Returns up to `k` distinct sequences of moves from puzzle `p0` to a goal
+/// state (along with the goal state reached by each), in order of increasing
+/// number of moves; the first is one of the shortest sequence of moves from
+/// `p0` to a goal state (as with `solve`), and no sequence of moves that is not
+/// returned is shorter than the last.  Fewer than `k` sequences of moves are
+/// returned if there are fewer than `k` of them; in particular, none are
+/// returned if `p0` cannot be solved by any sequence of moves.  Only sequences
+/// of moves that never revisit a puzzle state are considered, so that (for
+/// example) a shortest sequence of moves with a move and its inverse inserted
+/// is not returned.
+///
+/// Each subsequent sequence of moves is found by deviating from a previously
+/// found sequence of moves: for each puzzle state along the last sequence of
+/// moves found, a BFS from that puzzle state to a goal state avoids the
+/// preceding puzzle states (so that the sequence of moves does not revisit a
+/// puzzle state) and the moves taken by previously found sequences of moves
+/// with the same prefix.  The shortest of all such candidate sequences of
+/// moves is the next sequence of moves.  Thus, a BFS is performed for each
+/// puzzle state along each of the (up to `k`) sequences of moves found.
+pub fn solve_k<P>(p0: P, k: usize) -> Vec<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Eq + Clone,
+{
+    let mut found: Vec<Path<P, P::Move>> = vec![];
+    let mut candidates: Vec<Path<P, P::Move>> = vec![];
+    if k > 0 {
+        if let Some(path) = bfs_avoiding(p0, &HashSet::new(), &[]) {
+            found.push(path);
+        }
+    }
+
+    while let Some(last) = found.last().filter(|_| found.len() < k) {
+        for j in 0..last.moves.len() {
+            let spur = &last.states[j];
+            // the moves from the spur puzzle state taken by found sequences of
+            // moves with the same prefix
+            let avoid_moves: Vec<P::Move> = found
+                .iter()
+                .filter(|path| path.moves.len() > j && path.moves[..j] == last.moves[..j])
+                .map(|path| path.moves[j].clone())
+                .collect();
+            let avoid_states: HashSet<P> = last.states[..j].iter().cloned().collect();
+            if let Some(spur_path) = bfs_avoiding(spur.clone(), &avoid_states, &avoid_moves) {
+                let mut moves = last.moves[..j].to_vec();
+                moves.extend(spur_path.moves);
+                let mut states = last.states[..j].to_vec();
+                states.extend(spur_path.states);
+                if !candidates.iter().any(|path| path.moves == moves) {
+                    candidates.push(Path { moves, states });
+                }
+            }
+        }
+
+        // the first of the shortest candidate sequences of moves
+        let next = candidates
+            .iter()
+            .enumerate()
+            .min_by_key(|(i, path)| (path.moves.len(), *i))
+            .map(|(i, _)| i);
+        match next {
+            Some(i) => found.push(candidates.remove(i)),
+            None => break,
+        }
+    }
+
+    found
+        .into_iter()
+        .map(|Path { moves, mut states }| (moves, states.pop().unwrap()))
+        .collect()
+}
+
+/// BFS from `p0` to a goal state, never visiting a puzzle state of
+/// `avoid_states` and never taking a move of `avoid_moves` from `p0`.
+fn bfs_avoiding<P>(
+    p0: P,
+    avoid_states: &HashSet<P>,
+    avoid_moves: &[P::Move],
+) -> Option<Path<P, P::Move>>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Eq + Clone,
+{
+    let mut avoiding = Avoiding {
+        p0: p0.clone(),
+        avoid_states,
+        avoid_moves,
+    };
+    let (moves, _) = solve_by(p0.clone(), &mut avoiding, Fifo::new(), &mut Reached::new())?;
+    // the puzzle states along the sequence of moves
+    let mut states = vec![p0];
+    for m in &moves {
+        let q = states[states.len() - 1].apply_move(m);
+        states.push(q);
+    }
+    Some(Path { moves, states })
+}
+
+/// The hooks of `bfs_avoiding`.
+struct Avoiding<'a, P: Puzzle> {
+    p0: P,
+    avoid_states: &'a HashSet<P>,
+    avoid_moves: &'a [P::Move],
+}
+
+impl<P> Search<P> for Avoiding<'_, P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Eq,
+{
+    const NAME: &'static str = "solve_k";
+    type Key = P;
+    type Frontier = Fifo<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.clone()
+    }
+
+    fn priority(&mut self, _: usize, _: &P) {}
+
+    fn successors(&mut self, p: &P, _: usize, next: &mut Vec<(P::Move, P)>) {
+        p.next_into(next);
+        next.retain(|(m, q)| {
+            !(self.avoid_states.contains(q) || (*p == self.p0 && self.avoid_moves.contains(m)))
+        });
+    }
+}
@@ -0,0 +1,119 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use super::Puzzle;
+
+/// Trait for puzzles that can label their moves (e.g., with a human-readable
+/// description of the move for a front-end).
+pub trait Labeled: Puzzle {
+    /// The type of labels of moves.
+    type Label;
+
+    /// Returns the label of the (legal) move `m` from the current puzzle state.
+    fn label(&self, m: &Self::Move) -> Self::Label;
+}
+
+/// A move `m` together with its label (see `Labeled::label`).
+///
+/// Equality, ordering, and hashing only consider the move `m`, so that labels
+/// never affect how a solver treats moves.
+#[derive(Debug, Clone)]
+pub struct LabeledMove<M, L> {
+    pub m: M,
+    pub label: L,
+}
+
+impl<M: PartialEq, L> PartialEq for LabeledMove<M, L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.m == other.m
+    }
+}
+
+impl<M: Eq, L> Eq for LabeledMove<M, L> {}
+
+impl<M: PartialOrd, L> PartialOrd for LabeledMove<M, L> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.m.partial_cmp(&other.m)
+    }
+}
+
+impl<M: Ord, L> Ord for LabeledMove<M, L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.m.cmp(&other.m)
+    }
+}
+
+impl<M: Hash, L> Hash for LabeledMove<M, L> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.m.hash(state)
+    }
+}
+
+/// A puzzle state of a `Labeled` puzzle, whose moves are the moves of the
+/// puzzle state together with their labels (see `LabeledMove`).
+///
+/// Solvers treat the labels as part of the moves, so that every move of a
+/// solution of a `WithLabels` puzzle carries its label, without any need to
+/// re-derive the labels from the puzzle states along the solution.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WithLabels<P>(pub P);
+
+impl<P> Puzzle for WithLabels<P>
+where
+    P: Labeled,
+{
+    type Move = LabeledMove<P::Move, P::Label>;
+
+    fn is_goal(&self) -> bool {
+        self.0.is_goal()
+    }
+
+    fn legal_moves(&self) -> Vec<Self::Move> {
+        self.0
+            .legal_moves()
+            .into_iter()
+            .map(|m| {
+                let label = self.0.label(&m);
+                LabeledMove { m, label }
+            })
+            .collect()
+    }
+
+    fn apply_move(&self, m: &Self::Move) -> Self {
+        WithLabels(self.0.apply_move(&m.m))
+    }
+
+    fn next(&self) -> Vec<(Self::Move, Self)> {
+        self.0
+            .successors()
+            .map(|(m, p)| {
+                let label = self.0.label(&m);
+                (LabeledMove { m, label }, WithLabels(p))
+            })
+            .collect()
+    }
+
+    fn move_cost(&self, m: &Self::Move) -> u32 {
+        self.0.move_cost(&m.m)
+    }
+
+    fn is_dead(&self) -> bool {
+        self.0.is_dead()
+    }
+
+    fn prune(&self) -> bool {
+        self.0.prune()
+    }
+
+    fn lower_bound(&self) -> usize {
+        self.0.lower_bound()
+    }
+
+    fn goal_score(&self) -> i64 {
+        self.0.goal_score()
+    }
+
+    fn order_hint(&self, m: &Self::Move) -> i32 {
+        self.0.order_hint(&m.m)
+    }
+}
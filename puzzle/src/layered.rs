@@ -0,0 +1,233 @@
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use super::frontier::{Ended, Solved};
+use super::{BfsLimits, Observer, Puzzle, SearchStats, SolveOutcome, SolverConfig};
+
+/// The links of a layer of a layer-synchronous BFS (whose puzzle states are
+/// sorted, and without duplicates): for each puzzle state, the index of its
+/// predecessor in the previous layer and the move that leads from it.
+type Links<M> = Vec<Option<(usize, M)>>;
+
+/// Solve a puzzle using layer-synchronous BFS with delayed duplicate detection.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  The sequence of moves `ms` should be one of the
+/// shortest sequence of moves from `p0` to a goal state (as with `solve`).
+/// Note that the goal state `p` need not be the same goal state as is returned
+/// by `solve`.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// Rather than checking each successor puzzle state against a hash set or hash
+/// table as it is generated (as does `solve`), the BFS proceeds one layer
+/// (i.e., number of moves from `p0`) at a time: all of the successor puzzle
+/// states of a layer are generated, then sorted and deduplicated in one pass,
+/// and finally the puzzle states of previous layers are subtracted in a batch
+/// (by merging the sorted successor puzzle states with each sorted previous
+/// layer).  For puzzles with many transpositions (i.e., different sequences of
+/// moves that lead to the same puzzle state), this replaces many random
+/// hash-table probes by cache-friendly sorted passes.
+///
+/// The generic type parameter `P` must implement `Ord` (in order for puzzle
+/// states to be sorted), but, unlike `solve`, need not implement `Hash` or
+/// `Clone`, and `P::Move` need not implement `Clone`.
+pub fn solve_layered<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Ord,
+{
+    unlimited(p0, usize::MAX)
+}
+
+/// Solve a puzzle using layer-synchronous BFS with delayed duplicate detection
+/// within the last `scope` layers (i.e., frontier search).
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`, which should be one of the shortest sequence of
+/// moves from `p0` to a goal state (as with `solve_layered`).
+///
+/// Returns `None` if no layer of successor puzzle states remains; this implies
+/// that `p0` cannot be solved by any sequence of moves.  Note that, unlike
+/// `solve_layered`, the search need not terminate for a puzzle that cannot be
+/// solved (see below).
+///
+/// As with `solve_layered`, but the successor puzzle states of a layer are
+/// only subtracted from the last `scope` layers (the current layer included),
+/// and the puzzle states of older layers are dropped; only the links of each
+/// puzzle state to its predecessor (an index and a move) are kept, in order to
+/// backtrack from a goal state.  A puzzle state that was reached in an older
+/// layer is regenerated (and explored again) in a later layer, which wastes
+/// time but never yields a longer sequence of moves, since the puzzle state is
+/// reached later than before.  So, the memory of the puzzle states is that of
+/// `scope` layers, rather than of every visited puzzle state.  For puzzles
+/// whose moves can all be undone in one move (so that every successor puzzle
+/// state of a layer is in the previous layer, the layer itself, or the next
+/// layer), a `scope` of `2` already detects every duplicate, and the search
+/// terminates when the reachable puzzle states are exhausted; with a smaller
+/// `scope` (or for other puzzles), the search may cycle forever among puzzle
+/// states that cannot reach a goal state.
+///
+/// Panics if `scope` is `0`.
+pub fn solve_layered_scoped<P>(p0: P, scope: usize) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Ord,
+{
+    if scope == 0 {
+        panic!(
+            "solve_layered_scoped scope (is {}) should be positive",
+            scope
+        )
+    }
+    unlimited(p0, scope)
+}
+
+/// Solve a puzzle using layer-synchronous BFS with delayed duplicate detection,
+/// as configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_layered(p0)`
+/// (or, with a `dedupe_scope`, as `solve_layered_scoped(p0, scope)`, and, with
+/// a `max_depth`, without expanding the puzzle states `max_depth` moves from
+/// `p0`) if the search finds it within the limits of `config`, and otherwise
+/// the outcome of the search as for `solve_with_config`.
+///
+/// The puzzle states of every layer count towards the `max_nodes` (and the
+/// `memory_limit`) of `config`, as each layer is added, and the time limit
+/// (and the cancel token) is checked before each layer is expanded.  No
+/// puzzle states are hashed, so the `hasher` of `config` is ignored, as is its
+/// `parallelism`.  The observer of `config` is told of every event of
+/// `Observer`, each successor puzzle state being discovered once its layer is
+/// deduplicated (so that the statistics of the search count no frontier or
+/// closed list).
+pub fn solve_layered_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Puzzle + Ord,
+    O: Observer<P>,
+{
+    let start = Instant::now();
+    let limits = config.bfs_limits(&p0, start);
+    let (mut stats, observer) = (SearchStats::default(), &mut *config.observer.borrow_mut());
+    let ended = layered(p0, config.dedupe_scope, &limits, &mut stats, observer);
+    let outcome = SolveOutcome::new(ended, stats.visited, |(vec, p)| {
+        observer.on_solution(&vec, &p);
+        stats.depth = Some(vec.len());
+        (vec, p)
+    });
+    stats.finish(start, observer);
+    outcome
+}
+
+/// Layer-synchronous BFS, subtracting the successor puzzle states of a layer
+/// from the last (at most) `scope` layers, without limits.
+fn unlimited<P>(p0: P, scope: usize) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Ord,
+{
+    let limits = BfsLimits {
+        max_states: usize::MAX,
+        ..BfsLimits::new(&p0)
+    };
+    match layered(p0, scope, &limits, &mut SearchStats::default(), &mut ()) {
+        Ok(ended) => ended.goal(),
+        Err(err) => unreachable!("solve_layered has no limit for {:?}", err),
+    }
+}
+
+/// Layer-synchronous BFS, subtracting the successor puzzle states of a layer
+/// from the last (at most) `scope` layers, within the `max_depth`,
+/// `max_states`, `max_memory_states`, `deadline` (and `cancel`) of `limits`,
+/// counting its expansions and the puzzle states of every layer in `stats`,
+/// and reporting to `observer`.
+fn layered<P>(
+    p0: P,
+    scope: usize,
+    limits: &BfsLimits,
+    stats: &mut SearchStats,
+    observer: &mut impl Observer<P>,
+) -> Solved<P>
+where
+    P: Puzzle + Ord,
+{
+    limits.visit(0)?;
+    // the puzzle states of the last (at most) `scope` layers, oldest first,
+    // and the links of every layer
+    let mut layers: VecDeque<Vec<P>> = VecDeque::from([vec![p0]]);
+    let mut links: Vec<Links<P::Move>> = vec![vec![None]];
+    stats.visited = 1;
+    // whether a layer was forgotten, so that a duplicate may have been missed
+    let mut forgot = false;
+    loop {
+        let layer = layers.back().unwrap();
+        if let Some(i) = layer.iter().position(|p| p.is_goal()) {
+            let p = layers.pop_back().unwrap().swap_remove(i);
+            return Ok(Ended::Goal(backtrack_links(links, i, p)));
+        }
+        // the successor puzzle states of the horizon would be beyond it
+        let depth = links.len() - 1;
+        if depth >= limits.max_depth {
+            return Ok(Ended::Cut);
+        }
+        limits.expand(stats.visited, depth)?;
+
+        let mut next: Vec<_> = layer
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| !p.prune())
+            .inspect(|(_, p)| {
+                observer.on_expand(p, depth);
+                stats.expanded += 1;
+            })
+            .flat_map(|(i, p)| p.successors().map(move |(m, q)| (q, Some((i, m)))))
+            .collect();
+        stats.generated += next.len();
+        // a stable sort, so that deduplication keeps the first predecessor
+        next.sort_by(|(p, _), (q, _)| p.cmp(q));
+        next.dedup_by(|(q, _), (p, _)| q == p);
+        for layer in &layers {
+            subtract_sorted(&mut next, layer);
+        }
+        stats.duplicates = stats.generated - (stats.visited - 1 + next.len());
+        if next.is_empty() {
+            observer.on_layer_complete(depth, stats.visited);
+            return Ok(if forgot { Ended::Cut } else { Ended::Exhausted });
+        }
+        limits.visit(stats.visited + next.len() - 1)?;
+        for (q, link) in &next {
+            if let Some((i, _)) = link {
+                observer.on_discover(q, &layer[*i], depth + 1);
+            }
+        }
+        stats.visited += next.len();
+        observer.on_layer_complete(depth, stats.visited);
+        if layers.len() == scope {
+            layers.pop_front();
+            forgot = true;
+        }
+        let (next, next_links) = next.into_iter().unzip();
+        layers.push_back(next);
+        links.push(next_links);
+    }
+}
+
+/// Remove the puzzle states of the sorted layer `layer` from the sorted layer
+/// `next`, in one merge of the two layers (rather than a search of `layer` for
+/// each puzzle state of `next`).
+fn subtract_sorted<P: Ord, M>(next: &mut Vec<(P, M)>, layer: &[P]) {
+    let mut rest = layer.iter().peekable();
+    next.retain(|(q, _)| {
+        while rest.next_if(|p| *p < q).is_some() {}
+        rest.peek() != Some(&q)
+    });
+}
+
+/// Backtrack from the puzzle state `p` at index `i` of the last layer of
+/// `links` to the (only) puzzle state of the first layer.
+fn backtrack_links<P, M>(mut links: Vec<Links<M>>, i: usize, p: P) -> (Vec<M>, P) {
+    let mut pred = links.pop().unwrap().swap_remove(i);
+    let mut vec = vec![];
+    while let Some((j, m)) = pred {
+        vec.push(m);
+        pred = links.pop().unwrap().swap_remove(j);
+    }
+    vec.reverse();
+    (vec, p)
+}
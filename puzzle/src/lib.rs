@@ -1,10 +1,170 @@
-use std::collections::hash_map::Entry::Vacant;
-use std::collections::{HashMap, VecDeque};
-use std::fmt::Debug;
-use std::hash::Hash;
+//! A generic puzzle solver using BFS with hashing of states.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
 use std::marker::Sized;
+use std::time::Instant;
 
-/// A generic puzzle solver using BFS with hashing of states.
+/// Emit a `log` event of `level` (e.g., `debug`) with the target `puzzle`, if
+/// the `log` feature is enabled; otherwise, the event is neither formatted nor
+/// emitted (its arguments are only type-checked).
+macro_rules! event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "log")]
+        log::$level!(target: "puzzle", $($arg)+);
+        #[cfg(not(feature = "log"))]
+        if false {
+            let _ = format_args!($($arg)+);
+        }
+    };
+}
+
+mod andor;
+mod astar;
+mod beam;
+mod bidirectional;
+mod bloom;
+mod bnb;
+mod broadening;
+mod bytes;
+mod cancel;
+#[cfg(feature = "serde")]
+mod checkpoint;
+mod chunked;
+mod closed;
+mod config;
+mod deterministic;
+mod dfs;
+mod dijkstra;
+mod dynamic;
+mod estimate;
+mod explain;
+#[cfg(feature = "serde")]
+mod external;
+mod focal;
+mod frontier;
+mod graph;
+mod grid;
+mod hdastar;
+mod idastar;
+mod keyed;
+mod kshortest;
+mod labels;
+mod layered;
+mod limit;
+#[cfg(feature = "parallel")]
+mod lockfree;
+mod macros;
+mod mcts;
+mod memory;
+mod mutable;
+mod observer;
+mod optimal;
+mod outcome;
+mod packed;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod pattern_db;
+mod perf;
+#[cfg(feature = "serde")]
+mod persist;
+mod por;
+mod product;
+mod ranked;
+mod reachability;
+mod retrograde;
+mod seeded;
+mod shared;
+mod shared_grid;
+mod smallvec;
+mod solver;
+mod stats;
+mod symmetry;
+mod table;
+mod targets;
+mod zobrist;
+pub use andor::{solve_and_or, NondeterministicPuzzle, Strategy};
+pub use astar::{
+    solve_anytime, solve_anytime_timed, solve_astar, solve_astar_batched,
+    solve_astar_batched_with_config, solve_astar_certified, solve_astar_with_config, solve_greedy,
+    solve_greedy_with_config, solve_weighted_astar, solve_weighted_astar_with_config, Heuristic,
+};
+pub use beam::{solve_beam, solve_beam_with_config};
+pub use bidirectional::{
+    solve_bidirectional, solve_bidirectional_with_config, solve_meet_in_the_middle,
+    solve_meet_in_the_middle_with_config,
+};
+pub use bloom::{solve_bloom, solve_bloom_with_config, BloomSet};
+pub use bnb::{
+    solve_bnb, solve_bnb_with_config, solve_bnb_with_heuristic,
+    solve_bnb_with_heuristic_with_config,
+};
+pub use broadening::{solve_broadening, solve_broadening_with_config};
+pub use bytes::ByteArray;
+pub use cancel::CancelToken;
+#[cfg(feature = "serde")]
+pub use checkpoint::{solve_checkpointed, BfsCheckpoint};
+pub use chunked::{ChunkedQueue, CHUNK_LEN};
+pub use closed::{solve_with_closed_set, solve_with_closed_set_with_config, ClosedSet, DiskSet};
+pub use config::{solve_with_config, SolveError, SolverConfig, TimedOut};
+pub use deterministic::{solve_deterministic, solve_deterministic_with_config, Deterministic};
+pub use dfs::{solve_any, solve_any_with_config, solve_dfs, solve_dfs_with_config};
+pub use dijkstra::{solve_min_cost, solve_min_cost_with_config};
+pub use dynamic::{BoxedMove, BoxedPuzzle, DynMove, DynPuzzle};
+pub use estimate::{estimate_tree_size, estimate_tree_size_with_rng, TreeSizeEstimate};
+pub use explain::{solve_or_explain, solve_or_explain_by, ClosestState, UnsolvableReport};
+#[cfg(feature = "serde")]
+pub use external::{solve_external, solve_external_with_config};
+pub use focal::{solve_focal, solve_focal_with_config};
+use frontier::{search, Reached, Scoped, Search};
+pub use frontier::{
+    solve_with_frontier, solve_with_frontier_with_config, BucketQueue, Fifo, Frontier, Heap, Lifo,
+};
+pub use graph::{
+    export_search_graph, export_search_graph_with, solve_with_graph, DotOptions, SearchGraph,
+};
+pub use grid::{grid_apply, grid_moves, Grid, GridDirection, GridMove, Movement};
+pub use hdastar::{solve_hdastar, solve_hdastar_with_config};
+pub use idastar::{solve_idastar, solve_idastar_with_config, solve_iddfs, solve_iddfs_with_config};
+pub use keyed::{solve_keyed, solve_keyed_with_config, Keyed};
+pub use kshortest::solve_k;
+pub use labels::{Labeled, LabeledMove, WithLabels};
+pub use layered::{solve_layered, solve_layered_scoped, solve_layered_with_config};
+pub use limit::{MemoryLimit, MemoryLimitExceeded};
+pub use macros::{Macro, MacroPuzzle};
+pub use mcts::{solve_mcts, solve_mcts_with_rng};
+pub use memory::{CountingAllocator, MemoryStats};
+pub use mutable::{solve_idastar_mut, solve_idastar_mut_with_config, MutablePuzzle};
+pub use observer::{solve_observed, Events, Observer, SearchEvent};
+pub use optimal::{count_optimal_solutions, solve_all};
+pub use outcome::SolveOutcome;
+pub use packed::{solve_packed, solve_packed_with_config, PackState};
+#[cfg(feature = "parallel")]
+pub use parallel::{solve_parallel, solve_parallel_with_config};
+pub use pattern_db::{Guided, PatternDatabase};
+pub use perf::{measure, Measured, PerfReport};
+#[cfg(feature = "serde")]
+pub use persist::{read_puzzle, read_solution, write_puzzle, write_solution, PersistentPuzzle};
+pub use por::{solve_por, solve_por_with_config, Commuting};
+pub use product::{Product, Side};
+#[cfg(feature = "derive")]
+pub use puzzle_derive::GridPuzzle;
+pub use ranked::{solve_ranked, solve_ranked_with_config, RankSet, RankablePuzzle};
+pub use reachability::{enumerate_reachable, ReachabilityReport};
+pub use retrograde::analyze_backwards;
+pub use seeded::{solve_seeded, solve_seeded_with_config, solve_seeded_with_rng};
+pub use shared::{solve_shared, solve_shared_with_config};
+pub use shared_grid::SharedGrid;
+pub use smallvec::{SmallVec, Successors, INLINE_SUCCESSORS};
+pub use solver::Solver;
+pub use stats::{solve_with_stats, LayerStats, SearchStats};
+pub use symmetry::{least_symmetry, solve_symmetric, solve_symmetric_with_config, Symmetric};
+pub use table::{write_distance_table, DistanceTable, Tabled};
+pub use targets::{solve_to_any, solve_to_any_with_config, solve_until, solve_until_with_config};
+pub use zobrist::{
+    solve_zobrist, solve_zobrist_exact, solve_zobrist_exact_with_config, solve_zobrist_with_config,
+    zobrist_keys, ZobristHash,
+};
 
 /// Trait for puzzles that can be goal using BFS with hashing of states.
 pub trait Puzzle {
@@ -14,10 +174,22 @@ pub trait Puzzle {
     /// Determines whether or not the puzzle state represents a solved puzzle.
     fn is_goal(&self) -> bool;
 
+    /// Enumerates all of the (legal) moves from the current puzzle state.
+    fn legal_moves(&self) -> Vec<Self::Move>;
+
+    /// Makes the (legal) move `m` from the current puzzle state, returning the
+    /// successor puzzle state that `m` leads to.
+    fn apply_move(&self, m: &Self::Move) -> Self
+    where
+        Self: Sized;
+
     /// Enumerates all of the (legal) successor puzzle states of the current
     /// puzzle state, along with the move that leads to that successor puzzle
     /// state.
     ///
+    /// The default implementation applies each move of `legal_moves` (in
+    /// order) with `apply_move`.
+    ///
     /// The `Self: Sized` trait bound is a technical requirement for a trait
     /// method that requires the type of `Self` to be known at compile time (in
     /// this case, in order to know the size of each element of the result
@@ -25,40 +197,310 @@ pub trait Puzzle {
     /// the `Self` type of a trait is an exception to that rule that types are
     /// assumed to be `Sized`.
     fn next(&self) -> Vec<(Self::Move, Self)>
+    where
+        Self: Sized,
+    {
+        self.legal_moves()
+            .into_iter()
+            .map(|m| {
+                let p = self.apply_move(&m);
+                (m, p)
+            })
+            .collect()
+    }
+
+    /// Enumerates all of the (legal) successor puzzle states of the current
+    /// puzzle state, along with the move that leads to that successor puzzle
+    /// state, lazily.
+    ///
+    /// This should produce the same successor puzzle states (in the same order)
+    /// as `next`.  The default implementation simply iterates over the vector
+    /// returned by `next`; an implementation that generates each successor
+    /// puzzle state on demand allows solvers to avoid allocating a vector for
+    /// every expansion (and to avoid generating the remaining successor puzzle
+    /// states when an expansion is cut short).
+    fn successors(&self) -> impl Iterator<Item = (Self::Move, Self)>
+    where
+        Self: Sized,
+    {
+        self.next().into_iter()
+    }
+
+    /// Appends all of the (legal) successor puzzle states of the current
+    /// puzzle state, along with the move that leads to that successor puzzle
+    /// state, to `out`.
+    ///
+    /// This should append the same successor puzzle states (in the same order)
+    /// as are returned by `next`.  Reusing the same vector `out` (cleared
+    /// between calls) for every expansion avoids allocating a vector for each
+    /// expansion.  The default implementation extends `out` with `successors`.
+    fn next_into(&self, out: &mut Vec<(Self::Move, Self)>)
+    where
+        Self: Sized,
+    {
+        out.extend(self.successors())
+    }
+
+    /// Returns all of the (legal) successor puzzle states of the current
+    /// puzzle state, along with the move that leads to that successor puzzle
+    /// state, stored inline for up to `INLINE_SUCCESSORS` successor puzzle
+    /// states.
+    ///
+    /// This should return the same successor puzzle states (in the same order)
+    /// as `next`.  Solvers that need the successor puzzle states of each
+    /// expansion all at once (e.g., to sort them) use it to avoid a heap
+    /// allocation per expansion.  The default implementation collects
+    /// `successors`.
+    fn next_small(&self) -> Successors<Self>
+    where
+        Self: Sized,
+    {
+        self.successors().collect()
+    }
+
+    /// Enumerates all of the (legal) successor puzzle states of each of the
+    /// puzzle states `ps`, along with the move that leads to that successor
+    /// puzzle state; that is, `P::next_batch(ps)[i]` should equal
+    /// `ps[i].next()`.
+    ///
+    /// Layered solvers (e.g., `solve_parallel`) expand the puzzle states of a
+    /// layer in batches, so that an implementation can amortize work across
+    /// the puzzle states of a batch (e.g., by computing a shared table once
+    /// per batch, rather than once per puzzle state); a batch is also the unit
+    /// of work that is handed to a thread.  The default implementation calls
+    /// `next` on each puzzle state in turn.
+    fn next_batch(ps: &[Self]) -> Vec<Vec<(Self::Move, Self)>>
+    where
+        Self: Sized,
+    {
+        ps.iter().map(Puzzle::next).collect()
+    }
+
+    /// Returns the representative of the equivalence class of the puzzle state,
+    /// which `solve` uses in place of the puzzle state as a hash-table key.
+    ///
+    /// Equivalent puzzle states (e.g., that differ only in the identities of
+    /// interchangeable pieces) must have the same representative, and must
+    /// need the same number of moves to reach a goal state; then, only one
+    /// puzzle state of each equivalence class is explored.  The default
+    /// implementation returns (a clone of) the puzzle state itself, so that
+    /// every puzzle state is its own equivalence class.
+    fn canonicalize(&self) -> Self
+    where
+        Self: Sized + Clone,
+    {
+        self.clone()
+    }
+
+    /// The cost of making the move `m` from the current puzzle state.
+    ///
+    /// Cost-aware solvers (e.g., `solve_min_cost`) minimize the sum of the
+    /// costs of the moves, rather than the number of moves.  The default
+    /// implementation gives every move a cost of `1`, so that the cost of a
+    /// sequence of moves is its number of moves.
+    fn move_cost(&self, _m: &Self::Move) -> u32 {
+        1
+    }
+
+    /// Determines whether or not the puzzle state is a dead end; that is, is
+    /// provably not a goal state and cannot reach a goal state by any sequence
+    /// of moves.
+    ///
+    /// Dead-end puzzle states are not enqueued by `solve`, so that their
+    /// successor puzzle states are never explored.  An implementation may
+    /// return `false` for a puzzle state that cannot actually reach a goal
+    /// state (as does the default implementation, for every puzzle state), but
+    /// must not return `true` for a puzzle state that can.
+    fn is_dead(&self) -> bool {
+        false
+    }
+
+    /// Determines whether or not the puzzle state should be pruned; that is,
+    /// not be expanded, because no goal state can be reached from it.
+    ///
+    /// Every solver for the goal states of `is_goal` checks each puzzle state
+    /// that is not a goal state with `prune` before expanding it, so that a
+    /// domain-specific infeasibility check need only be encoded once; unlike
+    /// `is_dead` and `canonicalize`, which some solvers (e.g., `solve_hdastar`
+    /// and `solve_por`) ignore.  (Searches that do not target the goal states
+    /// of `is_goal`, such as `solve_until`, `solve_to_any`,
+    /// `enumerate_reachable`, and backwards searches, do not prune.)  As with
+    /// `is_dead`, an implementation must not return `true` for a puzzle state
+    /// that can reach a goal state, or else solvers may miss (shortest)
+    /// solutions.  The default implementation prunes no puzzle states.
+    fn prune(&self) -> bool {
+        false
+    }
+
+    /// A lower bound on the number of moves from the current puzzle state to a
+    /// goal state.
+    ///
+    /// Depth-bounded solvers without a heuristic (`solve_dfs`, `solve_iddfs`,
+    /// and `solve_bnb`) abandon a puzzle state reached by `d` moves when
+    /// `d + lower_bound()` exceeds their bound.  The bound must never exceed
+    /// the actual number of moves to a goal state (e.g., it must be `0` for a
+    /// goal state).  The default implementation returns `0`, which never
+    /// abandons a puzzle state early.
+    fn lower_bound(&self) -> usize {
+        0
+    }
+
+    /// A secondary objective for goal states: of the goal states that can be
+    /// reached by the fewest moves, `solve` returns one with the least score.
+    ///
+    /// The default implementation scores every puzzle state `0`, so that ties
+    /// are broken in favor of the first goal state reached.
+    fn goal_score(&self) -> i64 {
+        0
+    }
+
+    /// A hint of how promising the move `m` from the current puzzle state is;
+    /// solvers that explore one move at a time (`solve_dfs`, `solve_any`,
+    /// `solve_idastar`, `solve_idastar_mut`, and `solve_beam`) explore the
+    /// moves with greater hints first.
+    ///
+    /// Ties are explored in the order of `next`; the default implementation
+    /// gives every move a hint of `0`, so that every move is explored in the
+    /// order of `next`.
+    fn order_hint(&self, _m: &Self::Move) -> i32 {
+        0
+    }
+
+    /// A hint of the number of distinct puzzle states (or rather, of their
+    /// representatives) reachable from the current puzzle state, so that
+    /// `solve` can allocate its hash table and arena up front, rather than
+    /// growing them (and rehashing) repeatedly during a large search.
+    ///
+    /// The hint affects only performance, not the result; an overestimate
+    /// wastes memory, while an underestimate leaves the remaining growth to be
+    /// done on demand.  The default implementation gives a hint of `0`, so
+    /// that nothing is allocated up front.
+    fn state_count_hint(&self) -> usize {
+        0
+    }
+}
+
+/// Trait for puzzles whose predecessor puzzle states can be enumerated, so
+/// that they can be searched backwards from a goal state (e.g., by
+/// `solve_bidirectional` and `analyze_backwards`).
+pub trait InvertiblePuzzle: Puzzle {
+    /// Enumerates all of the (legal) predecessor puzzle states of the current
+    /// puzzle state, along with the move that leads from that predecessor
+    /// puzzle state to the current puzzle state.
+    ///
+    /// That is, `(m, q)` should be an element of `p.prev()` exactly when
+    /// `(m, p)` is an element of `q.next()`.
+    fn prev(&self) -> Vec<(Self::Move, Self)>
     where
         Self: Sized;
 }
 
+/// Marker trait for puzzles whose moves are their own inverses; that is,
+/// `(m, q)` is an element of `p.next()` exactly when `(m, p)` is an element of
+/// `q.next()` (e.g., toggling a light or swapping two tiles).
+///
+/// Every such puzzle is an `InvertiblePuzzle`, whose predecessor puzzle states
+/// are exactly its successor puzzle states.
+pub trait SelfInverse: Puzzle {}
+
+impl<P: SelfInverse> InvertiblePuzzle for P {
+    fn prev(&self) -> Vec<(Self::Move, Self)> {
+        self.next()
+    }
+}
+
 /// Verify that a sequence of moves solves a puzzle.
 ///
 /// Returns `Some(p)`, if `p` is the goal puzzle state reached from `p0` by the moves `ms`.
 ///
 /// Returns `None`, if either the sequence of moves `ms` starting from `p0` is
 /// not legal or if the puzzle state reached from `p0` by the moves `ms` is not
-/// a goal state.
+/// a goal state.  (See `check_steps` for the reason of a failure.)
 pub fn check<P>(p0: P, ms: &[P::Move]) -> Option<P>
 where
-    P: Puzzle + Debug,
-    P::Move: Eq + Debug,
+    P: Puzzle,
+    P::Move: Eq,
 {
     let mut p = p0;
-    'moves: for cm in ms {
-        for (m, pp) in p.next() {
-            if cm == &m {
-                p = pp;
-                println!("p {:?}", p);
-                continue 'moves;
-            }
+    for (step, m) in ms.iter().enumerate() {
+        if !p.legal_moves().contains(m) {
+            event!(debug, "check: move {} of {} is illegal", step, ms.len());
+            return None;
         }
-        return None;
+        event!(trace, "check: move {} of {} is legal", step, ms.len());
+        p = p.apply_move(m);
     }
     if p.is_goal() {
+        event!(debug, "check: {} moves reach a goal state", ms.len());
         Some(p)
     } else {
+        event!(debug, "check: {} moves reach a non-goal state", ms.len());
         None
     }
 }
 
+/// The reason that a sequence of moves `ms` does not solve a puzzle, as
+/// returned by `check_steps`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckError<P> {
+    /// The move `ms[step]` is not a legal move of the puzzle state `p` reached
+    /// by the first `step` moves.
+    IllegalMove { step: usize, p: P },
+    /// The puzzle state reached by all of the moves is not a goal state.
+    NotGoal(P),
+}
+
+/// Verify that a sequence of moves solves a puzzle, step by step.
+///
+/// Returns `Ok(ps)`, if the moves `ms` solve `p0`, where `ps[i]` is the puzzle
+/// state reached from `p0` by the first `i` moves; so, `ps[0]` is `p0` and
+/// `ps[ms.len()]` is the goal puzzle state.
+///
+/// Returns `Err(err)` with the first reason (see `CheckError`) that the moves
+/// `ms` do not solve `p0`.
+pub fn check_steps<P>(p0: P, ms: &[P::Move]) -> Result<Vec<P>, CheckError<P>>
+where
+    P: Puzzle + Clone,
+    P::Move: Eq,
+{
+    let mut ps = Vec::with_capacity(ms.len() + 1);
+    let mut p = p0;
+    for (step, m) in ms.iter().enumerate() {
+        if !p.legal_moves().contains(m) {
+            event!(
+                debug,
+                "check_steps: move {} of {} is illegal",
+                step,
+                ms.len()
+            );
+            return Err(CheckError::IllegalMove { step, p });
+        }
+        event!(trace, "check_steps: move {} of {} is legal", step, ms.len());
+        let q = p.apply_move(m);
+        ps.push(p);
+        p = q;
+    }
+    if p.is_goal() {
+        event!(debug, "check_steps: {} moves reach a goal state", ms.len());
+        ps.push(p);
+        Ok(ps)
+    } else {
+        event!(
+            debug,
+            "check_steps: {} moves reach a non-goal state",
+            ms.len()
+        );
+        Err(CheckError::NotGoal(p))
+    }
+}
+
+/// A solution of a puzzle (or `None`, if the puzzle cannot be solved), as
+/// returned by `solve`.
+type Solution<P> = Option<(Vec<<P as Puzzle>::Move>, P)>;
+
+/// The maximum number of nodes of the arena of a search (see `search`).
+const MAX_NODES: usize = u32::MAX as usize;
+
 /// Solve a puzzle using BFS with hashing of states.
 ///
 /// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
@@ -66,13 +508,24 @@ where
 /// shortest sequence of moves from `p0` to a goal state; that is, for any
 /// sequence of moves `ns` from `p0` to a goal state, `ms.len() <= ns.len()`.
 /// Note that there may not be a unique goal state for a puzzle, therefore, the
-/// goal state `p` reached by the sequence of moves `ms` is returned.
+/// goal state `p` reached by the sequence of moves `ms` is returned; of the
+/// goal states that can be reached by the fewest moves, `p` is one with the
+/// least `Puzzle::goal_score`.
 ///
 /// Returns `None` if `p0` cannot be solved by any sequence of moves.
 ///
 /// A BFS is used to find the shortest sequence of moves from `p0` to a goal
 /// state.  A hash set or hash table is used to avoid redundant puzzle states
-/// (e.g., different sequences of moves may lead to the same puzzle state).
+/// (e.g., different sequences of moves may lead to the same puzzle state); it
+/// is keyed by the representatives of puzzle states (see
+/// `Puzzle::canonicalize`), so that only the first puzzle state reached of each
+/// equivalence class is enqueued (in a `ChunkedQueue`).  Successor puzzle
+/// states that are dead ends (see `Puzzle::is_dead`) are not enqueued.  Rather
+/// than a copy of its predecessor puzzle state, each representative is mapped
+/// to the (`u32`) index of a node in an arena, which records the index of the
+/// node of its predecessor and the move from it.
+///
+/// Panics if more than `u32::MAX` puzzle states would be visited.
 ///
 /// The generic type parameter `P` must implement `Puzzle` (because it
 /// represents a puzzle state), `Eq` and `Hash` (in order to for puzzle states
@@ -88,61 +541,272 @@ where
     P: Puzzle + Eq + Hash + Clone,
     P::Move: Clone,
 {
-    let mut hash_map = HashMap::<P, Option<(P, P::Move)>>::new();
-    let mut queue = VecDeque::new();
-    queue.push_back(p0.clone());
-    //inserting the initial puzzle state to hash map
-    hash_map.insert(p0.clone(), None);
-    // Loop till queue is not empty
-    while !queue.is_empty() {
-        let p = match queue.pop_front() {
-            Some(v) => v,
-            None => return None,
-        };
-
-        if p.is_goal() {
-            let p1 = p.clone();
-            // backtrack using predecessor
-            let mut vec = match backtrack(hash_map, p1) {
-                Some(vec) => vec,
-                None => return None,
-            };
-            vec.reverse();
-            return Some((vec, p));
-        }
+    solve_with_hasher(p0, RandomState::new())
+}
+
+/// Solve a puzzle using BFS with hashing of states, allocating room for
+/// `capacity` puzzle states up front.
+///
+/// Returns the same result as `solve(p0)`, but the hash table and the arena of
+/// the BFS are allocated with room for `capacity` puzzle states (instead of
+/// `p0.state_count_hint()`, see `Puzzle::state_count_hint`), so that a search
+/// that is expected to visit about `capacity` puzzle states does not
+/// repeatedly grow them.  (The queue is a `ChunkedQueue`, which never
+/// reallocates.)
+pub fn solve_with_capacity<P>(p0: P, capacity: usize) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    let limits = BfsLimits {
+        capacity,
+        ..BfsLimits::new(&p0)
+    };
+    unbounded_bfs(p0, RandomState::new(), limits, &mut SearchStats::default())
+}
+
+/// Solve a puzzle using BFS with hashing of states, within a horizon of
+/// `max_depth` moves.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by a sequence of moves
+/// `ms` of at most `max_depth` moves to a goal state `p`, which should be one
+/// of the shortest sequence of moves from `p0` to a goal state (as for
+/// `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of at most
+/// `max_depth` moves.
+///
+/// The BFS is the same as that of `solve`, except that the puzzle states at
+/// the horizon (`max_depth` moves from `p0`) are not expanded, since none of
+/// their successor puzzle states could be part of a solution; so, the last
+/// layer of a bounded search costs no calls of `Puzzle::next_into`.  (Nor does
+/// `solve` ever expand a goal state: the BFS stops at the first layer with a
+/// goal state.)
+pub fn solve_within<P>(p0: P, max_depth: usize) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    let limits = BfsLimits {
+        max_depth,
+        ..BfsLimits::new(&p0)
+    };
+    unbounded_bfs(p0, RandomState::new(), limits, &mut SearchStats::default())
+}
+
+/// Solve a puzzle using BFS with hashing of states, where the hash table of
+/// (the representatives of) puzzle states uses the hasher built by
+/// `hash_builder`.
+///
+/// Returns the same result as `solve(p0)` (which uses the default SipHash
+/// hasher of `HashMap`).  A faster, non-cryptographic hasher (such as FxHash or
+/// aHash) can be much cheaper when the puzzle states are small (e.g., packed
+/// integers), so that hashing dominates the expansion of puzzle states.
+pub fn solve_with_hasher<P, S>(p0: P, hash_builder: S) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher,
+{
+    let limits = BfsLimits::new(&p0);
+    unbounded_bfs(p0, hash_builder, limits, &mut SearchStats::default())
+}
 
-        for (m, puzz) in p.next() {
-            match hash_map.entry(puzz.clone()) {
-                Vacant(e) => {
-                    queue.push_back(puzz);
-                    e.insert(Some((p.clone(), m)));
-                }
-                _ => {}
-            }
+/// Solve a puzzle using BFS with hashing of states, within a memory limit.
+///
+/// Returns `Ok(soln)`, where `soln` is the same result as `solve(p0)`, if the
+/// search visits no more puzzle states than allowed by `limit` (see
+/// `MemoryLimit`).
+///
+/// Returns `Err(err)`, where `err` is `MemoryLimitExceeded`, as soon as the
+/// search would visit more puzzle states than allowed by `limit`; the memory
+/// used by the search is released before returning.
+///
+/// A limit of bytes is converted to a limit of visited puzzle states by an
+/// estimate of the memory used per visited puzzle state (an entry of the hash
+/// table, of the arena, and of the queue), which does not include any memory
+/// owned (e.g., on the heap) by the puzzle states themselves, nor the slack of
+/// the growth of the hash table; so, the limit should leave some headroom.
+pub fn solve_bounded<P>(p0: P, limit: MemoryLimit) -> Result<Solution<P>, MemoryLimitExceeded>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    let limits = BfsLimits {
+        max_memory_states: limit.max_states(limit::solve_bytes_per_state::<P, P::Move>()),
+        ..BfsLimits::new(&p0)
+    };
+    match bounded_bfs(
+        p0,
+        RandomState::new(),
+        limits,
+        &mut SearchStats::default(),
+        &mut (),
+    )
+    .into_result()
+    {
+        Ok(soln) => Ok(soln),
+        Err(SolveError::MemoryLimit(err)) => Err(err),
+        Err(SolveError::NodeLimit { states }) => Err(MemoryLimitExceeded { states }),
+        Err(err) => unreachable!("solve_bounded has no limit for {:?}", err),
+    }
+}
+
+/// Solve a puzzle using BFS with hashing of states, and measure the memory
+/// used by the search.
+///
+/// Returns `(soln, stats)`, where `soln` is the same result as `solve(p0)`, and
+/// `stats` records the maximum lengths of the queue and of the hash table of
+/// the search, and (if `CountingAllocator` is the global allocator) the peak
+/// number of bytes allocated during the search (see `MemoryStats`).  These can
+/// be used to compare the memory profiles of puzzles (or of solvers), and to
+/// choose a `MemoryLimit` for `solve_bounded`.
+pub fn solve_with_memory_stats<P>(p0: P) -> (Solution<P>, MemoryStats)
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    let mut stats = SearchStats::default();
+    let peak = memory::PeakBytes::start();
+    let limits = BfsLimits::new(&p0);
+    let soln = unbounded_bfs(p0, RandomState::new(), limits, &mut stats);
+    let stats = MemoryStats {
+        max_queue_len: stats.max_frontier,
+        max_closed_len: stats.max_closed,
+        peak_bytes: peak.finish(),
+    };
+    (soln, stats)
+}
+
+/// The limits of the BFS of `solve` (see `bounded_bfs`).
+#[derive(Debug, Clone)]
+struct BfsLimits {
+    /// The number of puzzle states to allocate room for up front.
+    capacity: usize,
+    /// The maximum number of visited puzzle states.
+    max_states: usize,
+    /// The maximum number of visited puzzle states allowed by a memory limit.
+    max_memory_states: usize,
+    /// The horizon: puzzle states this many moves from `p0` are not expanded.
+    max_depth: usize,
+    /// The time after which no puzzle state is expanded.
+    deadline: Option<Instant>,
+    /// The number of layers (the current layer included) whose puzzle states
+    /// are remembered, in order to detect duplicate successor puzzle states.
+    scope: usize,
+    /// The token whose cancellation abandons the search.
+    cancel: Option<CancelToken>,
+    /// Whether or not to record the statistics of each layer.
+    layer_stats: bool,
+}
+
+impl BfsLimits {
+    /// No limits (except the size of the arena), with room for
+    /// `p0.state_count_hint()` puzzle states.
+    fn new<P: Puzzle>(p0: &P) -> Self {
+        BfsLimits {
+            capacity: p0.state_count_hint(),
+            max_states: MAX_NODES,
+            max_memory_states: usize::MAX,
+            max_depth: usize::MAX,
+            deadline: None,
+            scope: usize::MAX,
+            cancel: None,
+            layer_stats: false,
         }
     }
+}
 
-    None
+/// The BFS of `solve`, which panics if `limits.max_states` puzzle states
+/// would be exceeded (which, by default, is the size of its arena).
+fn unbounded_bfs<P, S>(
+    p0: P,
+    hash_builder: S,
+    limits: BfsLimits,
+    stats: &mut SearchStats,
+) -> Solution<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher,
+{
+    match bounded_bfs(p0, hash_builder, limits, stats, &mut ()).into_result() {
+        Ok(soln) => soln,
+        Err(SolveError::NodeLimit { states }) => {
+            panic!("solve states (is {}) should not exceed u32::MAX", states)
+        }
+        Err(err) => unreachable!("solve has no limit for {:?}", err),
+    }
 }
 
-fn backtrack<P>(mut hash_map: HashMap<P, Option<(P, P::Move)>>, mut p1: P) -> Option<Vec<P::Move>>
+/// The BFS of `solve`, which allocates room for `limits.capacity` puzzle
+/// states up front, does not expand puzzle states at the horizon
+/// `limits.max_depth`, fails once more than `limits.max_states` (or
+/// `limits.max_memory_states`) puzzle states would be visited or once
+/// `limits.deadline` has passed (or `limits.cancel` is cancelled), only
+/// detects duplicates within the last `limits.scope` layers, and records its
+/// statistics (see `SearchStats`) in `stats`, and reports its progress (and
+/// then its statistics) to `observer`.
+fn bounded_bfs<P, S>(
+    p0: P,
+    hash_builder: S,
+    limits: BfsLimits,
+    stats: &mut SearchStats,
+    observer: &mut impl Observer<P>,
+) -> SolveOutcome<P>
 where
     P: Puzzle + Eq + Hash + Clone,
     P::Move: Clone,
+    S: BuildHasher,
 {
-    let mut vec = vec![];
-    loop {
-        let (predecssor, m) = match hash_map.remove(&p1) {
-            Some(pm_o) => match pm_o {
-                Some(pm) => pm,
-                None => break,
-            },
-            None => return None,
-        };
-        vec.push(m);
-        p1 = predecssor;
-    }
-    Some(vec)
+    let start = Instant::now();
+    let index = Scoped::new(hash_builder, limits.scope);
+    let mut reached = Reached::with_index(index);
+    let ended = search(
+        p0,
+        &mut Bfs,
+        ChunkedQueue::new(),
+        &mut reached,
+        limits,
+        stats,
+        observer,
+    );
+    let outcome = SolveOutcome::new(ended, stats.visited, |(i, p)| {
+        let vec = reached.take_path(i);
+        observer.on_solution(&vec, &p);
+        (vec, p)
+    });
+    stats.finish(start, observer);
+    outcome
 }
+
+/// The hooks of the BFS of `solve`, which is keyed by the representatives of
+/// puzzle states (see `Puzzle::canonicalize`) and prefers the goal state with
+/// the least score among those of its layer (see `Puzzle::goal_score`); each
+/// puzzle state is enqueued itself, so that only its representative (at most
+/// one clone) is stored in the hash table.
+struct Bfs;
+
+impl<P: Puzzle + Eq + Hash + Clone> Search<P> for Bfs {
+    const NAME: &'static str = "bfs";
+    const PREFER_GOAL_SCORE: bool = true;
+    type Key = P;
+    type Frontier = ChunkedQueue<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.canonicalize()
+    }
+
+    fn priority(&mut self, _: usize, _: &P) {}
+}
+
+/// The successor puzzle states of `p` (see `Puzzle::next`), ordered so that the
+/// moves with greater hints (see `Puzzle::order_hint`) come first.
+fn ordered_next<P: Puzzle>(p: &P) -> Successors<P> {
+    let mut next = p.next_small();
+    next.sort_by_key(|(m, _)| std::cmp::Reverse(p.order_hint(m)));
+    next
+}
+
 #[allow(clippy::type_complexity)]
 pub mod test;
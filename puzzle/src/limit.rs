@@ -0,0 +1,51 @@
+use std::mem::size_of;
+
+use super::frontier::Node;
+
+/// A limit on the memory used by a search, as given to `solve_bounded`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryLimit {
+    /// At most this many visited puzzle states.
+    States(usize),
+    /// At most (an estimate of) this many bytes for the visited puzzle states.
+    Bytes(usize),
+}
+
+impl MemoryLimit {
+    /// The number of visited puzzle states allowed by the limit, for a search
+    /// that stores `bytes_per_state` bytes per visited puzzle state.
+    pub(crate) fn max_states(&self, bytes_per_state: usize) -> usize {
+        match *self {
+            MemoryLimit::States(states) => states,
+            MemoryLimit::Bytes(bytes) => bytes / bytes_per_state.max(1),
+        }
+    }
+}
+
+/// The error returned by a search that would have exceeded its memory limit
+/// (see `MemoryLimit`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryLimitExceeded {
+    /// The number of puzzle states visited when the search was abandoned.
+    pub states: usize,
+}
+
+impl std::fmt::Display for MemoryLimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "memory limit exceeded after {} visited states",
+            self.states
+        )
+    }
+}
+
+impl std::error::Error for MemoryLimitExceeded {}
+
+/// An estimate of the bytes used by `solve` per visited puzzle state of type
+/// `P` (with moves of type `M`): a hash-table entry (a puzzle state, an index,
+/// and a control byte), a node of the arena, and (at most) one entry of the
+/// queue.
+pub(crate) fn solve_bytes_per_state<P, M>() -> usize {
+    (size_of::<P>() + size_of::<u32>() + 1) + size_of::<Node<M>>() + size_of::<(usize, P)>()
+}
@@ -0,0 +1,289 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::Instant;
+
+use rayon::iter::Either;
+use rayon::prelude::*;
+
+use super::frontier::{Ended, Solved};
+use super::{BfsLimits, CancelToken, MemoryLimitExceeded, Observer, PackState, SolveError};
+
+/// The key of an empty slot of an `AtomicMap`.
+const EMPTY: u64 = u64::MAX;
+
+/// The outcome of `AtomicMap::insert`.
+enum Insert {
+    /// The key was newly inserted.
+    New,
+    /// The key was already in the map.
+    Present,
+    /// The map is too full to insert the key (see `AtomicMap::grow`).
+    Full,
+}
+
+/// A lock-free hash table from `u64` keys to `u64` values, by open addressing
+/// (with linear probing) over slots of atomic words, which can be inserted into
+/// concurrently (but only grows between insertions, see `grow`).
+pub(crate) struct AtomicMap {
+    keys: Vec<AtomicU64>,
+    values: Vec<AtomicU64>,
+    /// The key `EMPTY` (which cannot be stored in a slot) and its value.
+    empty_key: (AtomicBool, AtomicU64),
+    len: AtomicUsize,
+}
+
+impl AtomicMap {
+    /// An empty map with room for (at least) `capacity` keys.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        let slots = (2 * capacity).next_power_of_two().max(16);
+        AtomicMap {
+            keys: (0..slots).map(|_| AtomicU64::new(EMPTY)).collect(),
+            values: (0..slots).map(|_| AtomicU64::new(0)).collect(),
+            empty_key: (AtomicBool::new(false), AtomicU64::new(0)),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// The number of keys of the map.
+    pub(crate) fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// The first slot to probe for `key` (by the SplitMix64 finalizer, since
+    /// packed puzzle states are rarely uniformly distributed).
+    fn slot(&self, key: u64) -> usize {
+        let mut z = key;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        (z as usize) & (self.keys.len() - 1)
+    }
+
+    /// Insert `key` with `value`, unless `key` is already in the map (in which
+    /// case its value is unchanged), or the map is more than three-quarters
+    /// full.
+    fn insert(&self, key: u64, value: u64) -> Insert {
+        if key == EMPTY {
+            let (present, empty_value) = &self.empty_key;
+            return if present.swap(true, Ordering::AcqRel) {
+                Insert::Present
+            } else {
+                empty_value.store(value, Ordering::Release);
+                self.len.fetch_add(1, Ordering::Relaxed);
+                Insert::New
+            };
+        }
+        if 4 * self.len() >= 3 * self.keys.len() {
+            return Insert::Full;
+        }
+        let mask = self.keys.len() - 1;
+        let mut i = self.slot(key);
+        loop {
+            match self.keys[i].compare_exchange(EMPTY, key, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => {
+                    self.values[i].store(value, Ordering::Release);
+                    self.len.fetch_add(1, Ordering::Relaxed);
+                    return Insert::New;
+                }
+                Err(k) if k == key => return Insert::Present,
+                Err(_) => i = (i + 1) & mask,
+            }
+        }
+    }
+
+    /// The value of `key`, if `key` is in the map.
+    pub(crate) fn get(&self, key: u64) -> Option<u64> {
+        if key == EMPTY {
+            let (present, empty_value) = &self.empty_key;
+            return present
+                .load(Ordering::Acquire)
+                .then(|| empty_value.load(Ordering::Acquire));
+        }
+        let mask = self.keys.len() - 1;
+        let mut i = self.slot(key);
+        loop {
+            match self.keys[i].load(Ordering::Acquire) {
+                EMPTY => return None,
+                k if k == key => return Some(self.values[i].load(Ordering::Acquire)),
+                _ => i = (i + 1) & mask,
+            }
+        }
+    }
+
+    /// Double the number of slots of the map, rehashing its keys.
+    fn grow(&mut self) {
+        let mut grown = AtomicMap::with_capacity(self.keys.len());
+        for (key, value) in self.keys.iter_mut().zip(self.values.iter_mut()) {
+            if *key.get_mut() != EMPTY {
+                grown.insert(*key.get_mut(), *value.get_mut());
+            }
+        }
+        grown.empty_key = std::mem::replace(
+            &mut self.empty_key,
+            (AtomicBool::new(false), AtomicU64::new(0)),
+        );
+        *grown.len.get_mut() = *self.len.get_mut();
+        *self = grown;
+    }
+}
+
+/// The number of puzzle states of a layer that are expanded together, by one
+/// call of `P::next_batch` (on one thread).
+const BATCH_LEN: usize = 64;
+
+/// A successor puzzle state whose recording is deferred until the hash table
+/// has grown: its packed form, the packed form of its predecessor puzzle state,
+/// and whether it is a dead end.
+type Deferred = (u64, u64, bool);
+
+/// The BFS of `solve_parallel`, within `limits` (except for `limits.capacity`
+/// and `limits.scope`), whose visited puzzle states are recorded in a
+/// lock-free hash table, whose number of puzzle states is kept in `visited`;
+/// each completed layer is reported to `observer`.
+///
+/// The hash table maps the packed form of each visited puzzle state to the
+/// packed form of its predecessor puzzle state, so that threads never wait for
+/// one another to record a successor puzzle state.  The hash table cannot grow
+/// while it is shared, so a successor puzzle state that finds it too full is
+/// recorded after the layer, once the hash table has grown.
+///
+/// Panics if `P::next_batch` returns a different number of vectors of
+/// successor puzzle states than puzzle states.
+pub(crate) fn lockfree_bfs<P>(
+    p0: P,
+    limits: BfsLimits,
+    visited: &mut usize,
+    observer: &mut impl Observer<P>,
+) -> Solved<P>
+where
+    P: PackState<Packed = u64> + Send + Sync,
+    P::Move: Send,
+{
+    let root = p0.pack();
+    let mut closed = AtomicMap::with_capacity(1 << 10);
+    // the initial puzzle state is its own predecessor
+    closed.insert(root, root);
+    let mut layer = vec![root];
+    let mut depth = 0;
+    *visited = 1;
+
+    // Loop till the layer is empty
+    while !layer.is_empty() {
+        // of the goal states of the layer, prefer one with the least score
+        let goal = layer
+            .par_iter()
+            .map(|packed| P::unpack(packed))
+            .filter(|p| p.is_goal())
+            .min_by_key(|p| p.goal_score());
+        if let Some(p) = goal {
+            return Ok(Ended::Goal(backtrack_packed(&closed, root, p)));
+        }
+        // the successor puzzle states of the horizon would be beyond it
+        if depth >= limits.max_depth {
+            return Ok(Ended::Cut);
+        }
+        let states = closed.len();
+        if limits
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Err(SolveError::TimeLimit { states, depth });
+        }
+        if limits
+            .cancel
+            .as_ref()
+            .is_some_and(CancelToken::is_cancelled)
+        {
+            return Err(SolveError::Cancelled { states });
+        }
+
+        // each successor puzzle state is either recorded now (and explored,
+        // unless it is a dead end) or deferred until the table has grown
+        let table = &closed;
+        let (next, deferred): (Vec<u64>, Vec<Deferred>) = layer
+            .par_chunks(BATCH_LEN)
+            .flat_map_iter(|batch| {
+                let ps: Vec<P> = batch
+                    .iter()
+                    .map(P::unpack)
+                    .filter(|p| !p.prune())
+                    .collect();
+                let next = P::next_batch(&ps);
+                if next.len() != ps.len() {
+                    panic!(
+                        "solve_parallel successors (is {}) should be as many as puzzle states (is {})",
+                        next.len(),
+                        ps.len()
+                    )
+                }
+                let mut out = vec![];
+                for (p, next) in ps.iter().zip(next) {
+                    let packed = p.pack();
+                    for (_, q) in next {
+                        let key = q.pack();
+                        match table.insert(key, packed) {
+                            Insert::New if !q.is_dead() => out.push(Either::Left(key)),
+                            Insert::New | Insert::Present => {}
+                            Insert::Full => out.push(Either::Right((key, packed, q.is_dead()))),
+                        }
+                    }
+                }
+                out
+            })
+            .partition_map(|either| either);
+        layer = next;
+        for (key, packed, dead) in deferred {
+            loop {
+                match closed.insert(key, packed) {
+                    Insert::New if !dead => layer.push(key),
+                    Insert::Full => {
+                        closed.grow();
+                        continue;
+                    }
+                    _ => {}
+                }
+                break;
+            }
+        }
+        if 2 * closed.len() >= closed.keys.len() {
+            closed.grow();
+        }
+        let states = closed.len();
+        *visited = states;
+        observer.on_layer_complete(depth, states);
+        depth += 1;
+        if states > limits.max_memory_states {
+            return Err(SolveError::MemoryLimit(MemoryLimitExceeded { states }));
+        }
+        if states > limits.max_states {
+            return Err(SolveError::NodeLimit { states });
+        }
+    }
+
+    Ok(Ended::Exhausted)
+}
+
+/// Backtrack from the goal state `goal` to the packed initial puzzle state
+/// `root`, recovering each move by expanding the predecessor puzzle state.
+///
+/// Panics if a puzzle state on the way has no recorded predecessor, or is not
+/// a successor puzzle state of it (if the packed forms are not distinct).
+fn backtrack_packed<P>(closed: &AtomicMap, root: u64, goal: P) -> (Vec<P::Move>, P)
+where
+    P: PackState<Packed = u64>,
+{
+    let mut vec = vec![];
+    let mut packed = goal.pack();
+    while packed != root {
+        let pred = closed
+            .get(packed)
+            .expect("solve_parallel should record the predecessor of every puzzle state");
+        let m = P::unpack(&pred)
+            .successors()
+            .find_map(|(m, q)| (q.pack() == packed).then_some(m))
+            .expect("solve_parallel should reach a puzzle state from its predecessor");
+        vec.push(m);
+        packed = pred;
+    }
+    vec.reverse();
+    (vec, goal)
+}
@@ -0,0 +1,89 @@
+use super::Puzzle;
+
+/// Trait for puzzles that declare compound moves: sequences of (legal) moves
+/// that a solver should explore as a single step (e.g., a piece sliding by
+/// several positions at once).
+pub trait MacroPuzzle: Puzzle {
+    /// Enumerates the compound moves from the current puzzle state, each as the
+    /// sequence of moves that it consists of.
+    ///
+    /// Each sequence of moves should be legal from the current puzzle state.
+    /// The (single) moves of `legal_moves` need not be included; the default
+    /// implementation declares no compound moves.
+    fn macro_moves(&self) -> Vec<Vec<Self::Move>> {
+        vec![]
+    }
+}
+
+/// A puzzle state of a `MacroPuzzle`, whose moves are both the (single) moves
+/// and the compound moves (see `MacroPuzzle::macro_moves`) of the puzzle
+/// state.
+///
+/// Each move of a `Macro` is a sequence of moves, so that a solver explores a
+/// compound move atomically; in particular, `solve` finds a solution with the
+/// fewest steps, where a compound move is one step.  A solution can be
+/// expanded to the sequence of moves of the underlying puzzle (e.g., for
+/// `check`) with `Macro::expand`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Macro<P>(pub P);
+
+impl<P> Macro<P> {
+    /// Expand a sequence of moves of a `Macro` to the sequence of moves of the
+    /// underlying puzzle.
+    pub fn expand<M>(ms: Vec<Vec<M>>) -> Vec<M> {
+        ms.into_iter().flatten().collect()
+    }
+}
+
+impl<P> Puzzle for Macro<P>
+where
+    P: MacroPuzzle,
+{
+    type Move = Vec<P::Move>;
+
+    fn is_goal(&self) -> bool {
+        self.0.is_goal()
+    }
+
+    fn legal_moves(&self) -> Vec<Self::Move> {
+        let mut ms: Vec<Self::Move> = self.0.legal_moves().into_iter().map(|m| vec![m]).collect();
+        ms.extend(self.0.macro_moves());
+        ms
+    }
+
+    fn apply_move(&self, ms: &Self::Move) -> Self {
+        match ms.split_first() {
+            None => panic!("Macro::apply_move ms (is empty) should not be empty"),
+            Some((m, ms)) => Macro(ms.iter().fold(self.0.apply_move(m), |p, m| p.apply_move(m))),
+        }
+    }
+
+    /// The sum of the costs of the moves of the compound move.
+    fn move_cost(&self, ms: &Self::Move) -> u32 {
+        let mut p = None;
+        let mut cost = 0;
+        for m in ms {
+            let q = p.as_ref().unwrap_or(&self.0);
+            cost += q.move_cost(m);
+            p = Some(q.apply_move(m));
+        }
+        cost
+    }
+
+    fn is_dead(&self) -> bool {
+        self.0.is_dead()
+    }
+
+    fn prune(&self) -> bool {
+        self.0.prune()
+    }
+
+    fn goal_score(&self) -> i64 {
+        self.0.goal_score()
+    }
+
+    /// The hint of the first move of the compound move.
+    fn order_hint(&self, ms: &Self::Move) -> i32 {
+        ms.first().map_or(0, |m| self.0.order_hint(m))
+    }
+}
@@ -0,0 +1,189 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use super::Puzzle;
+
+/// A node of the search tree of a Monte Carlo tree search.
+struct Node<P, M> {
+    p: P,
+    /// The index of the parent node (with the connecting move), or `None` for
+    /// the root node.
+    parent: Option<(usize, M)>,
+    /// The number of moves from the initial puzzle state.
+    depth: usize,
+    /// The indices of the child nodes, or `None` if not yet expanded.
+    children: Option<Vec<usize>>,
+    /// The number of rollouts through this node.
+    visits: usize,
+    /// The number of those rollouts that reached a goal state.
+    wins: usize,
+}
+
+impl<P, M> Node<P, M> {
+    /// The UCT (upper confidence bound applied to trees) score of this node,
+    /// whose parent node has `parent_visits` visits.
+    fn uct(&self, parent_visits: usize, exploration: f64) -> f64 {
+        if self.visits == 0 {
+            return f64::INFINITY;
+        }
+        let visits = self.visits as f64;
+        self.wins as f64 / visits + exploration * ((parent_visits as f64).ln() / visits).sqrt()
+    }
+}
+
+/// Solve a puzzle using Monte Carlo tree search.
+///
+/// Returns `Some((ms,p))` if a sequence of moves `ms` from puzzle `p0` to a
+/// goal state `p` was found within `iterations` iterations.  Note that, unlike
+/// `solve`, the sequence of moves `ms` need not be one of the shortest sequence
+/// of moves from `p0` to a goal state; it is the shortest sequence of moves
+/// found by any iteration.
+///
+/// Returns `None` if no sequence of moves from `p0` to a goal state was found
+/// within `iterations` iterations (even though one may exist).
+///
+/// Each iteration descends the search tree from `p0` by repeatedly choosing the
+/// child node with the best UCT score (which balances the fraction of rollouts
+/// through a node that reached a goal state against how rarely the node has
+/// been visited, as weighted by `exploration`), expands the leaf node reached
+/// (using `P::next`), and then performs a rollout of at most `rollout_depth`
+/// uniformly random moves from one of its children.  Only the search tree is
+/// kept in memory (one node per iteration, plus the children of expanded
+/// nodes), so this can be used for puzzles whose state space is too large for
+/// exhaustive search.  The random moves are chosen using a random number
+/// generator seeded with `seed`, so that the same `seed` always returns the
+/// same sequence of moves.
+pub fn solve_mcts<P>(
+    p0: P,
+    iterations: usize,
+    rollout_depth: usize,
+    exploration: f64,
+    seed: u64,
+) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Clone,
+    P::Move: Clone,
+{
+    let mut rng = StdRng::seed_from_u64(seed);
+    solve_mcts_with_rng(p0, iterations, rollout_depth, exploration, &mut rng)
+}
+
+/// Solve a puzzle using Monte Carlo tree search, choosing the random moves
+/// using the random number generator `rng`.
+///
+/// Returns the same result as `solve_mcts`, but draws from `rng` instead of
+/// from a random number generator seeded by the caller; e.g., so that a test
+/// can share one seeded random number generator among several searches, or
+/// a caller can supply its own kind of random number generator.
+pub fn solve_mcts_with_rng<P, R>(
+    p0: P,
+    iterations: usize,
+    rollout_depth: usize,
+    exploration: f64,
+    rng: &mut R,
+) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Clone,
+    P::Move: Clone,
+    R: Rng + ?Sized,
+{
+    if exploration.is_nan() || exploration < 0.0 {
+        panic!(
+            "solve_mcts exploration (is {}) should be non-negative",
+            exploration
+        )
+    }
+    let mut tree = vec![Node {
+        p: p0,
+        parent: None,
+        depth: 0,
+        children: None,
+        visits: 0,
+        wins: 0,
+    }];
+    let mut best: Option<(Vec<P::Move>, P)> = None;
+
+    for _ in 0..iterations {
+        // select
+        let mut i = 0;
+        while let Some(children) = &tree[i].children {
+            let parent_visits = tree[i].visits;
+            let best_child = children.iter().copied().max_by(|&j, &k| {
+                let uj = tree[j].uct(parent_visits, exploration);
+                let uk = tree[k].uct(parent_visits, exploration);
+                uj.total_cmp(&uk)
+            });
+            match best_child {
+                Some(j) => i = j,
+                None => break,
+            }
+        }
+
+        // expand
+        if tree[i].children.is_none() && !tree[i].p.is_goal() && !tree[i].p.prune() {
+            let depth = tree[i].depth + 1;
+            let children = tree[i]
+                .p
+                .next()
+                .into_iter()
+                .map(|(m, q)| {
+                    tree.push(Node {
+                        p: q,
+                        parent: Some((i, m)),
+                        depth,
+                        children: None,
+                        visits: 0,
+                        wins: 0,
+                    });
+                    tree.len() - 1
+                })
+                .collect::<Vec<_>>();
+            let child = children.choose(rng).copied();
+            tree[i].children = Some(children);
+            if let Some(j) = child {
+                i = j;
+            }
+        }
+
+        // rollout
+        let mut p = tree[i].p.clone();
+        let mut rollout = vec![];
+        while !p.is_goal() && !p.prune() && rollout.len() < rollout_depth {
+            let mut next = p.next();
+            if next.is_empty() {
+                break;
+            }
+            let (m, q) = next.swap_remove(rng.gen_range(0..next.len()));
+            rollout.push(m);
+            p = q;
+        }
+        let win = p.is_goal();
+        if win {
+            let len = tree[i].depth + rollout.len();
+            if best.as_ref().is_none_or(|(ms, _)| len < ms.len()) {
+                let mut vec = vec![];
+                let mut j = i;
+                while let Some((k, m)) = &tree[j].parent {
+                    vec.push(m.clone());
+                    j = *k;
+                }
+                vec.reverse();
+                vec.extend(rollout);
+                best = Some((vec, p));
+            }
+        }
+
+        // backpropagate
+        let mut j = Some(i);
+        while let Some(k) = j {
+            tree[k].visits += 1;
+            if win {
+                tree[k].wins += 1;
+            }
+            j = tree[k].parent.as_ref().map(|(k, _)| *k);
+        }
+    }
+
+    best
+}
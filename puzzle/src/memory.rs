@@ -0,0 +1,136 @@
+use std::alloc::{GlobalAlloc, Layout};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering::Relaxed};
+
+/// Statistics of the memory used by a search, as returned by
+/// `solve_with_memory_stats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryStats {
+    /// The maximum number of puzzle states in the queue (the open list) at
+    /// once.
+    pub max_queue_len: usize,
+    /// The maximum number of puzzle states in the hash table (the closed list)
+    /// at once.
+    pub max_closed_len: usize,
+    /// The peak number of bytes allocated during the search (beyond those
+    /// allocated before it), or `None` if `CountingAllocator` is not the
+    /// global allocator.
+    pub peak_bytes: Option<usize>,
+}
+
+/// The current number of bytes allocated by `CountingAllocator`.
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+/// The peak of `ALLOCATED` since the last call of `reset_peak`.
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+/// Whether or not `CountingAllocator` has ever allocated.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// A global allocator that counts the bytes allocated by the allocator `A`
+/// (e.g., `std::alloc::System`), so that searches can report their peak
+/// memory (see `MemoryStats::peak_bytes`).
+///
+/// To count allocations, install it as the global allocator of the program:
+///
+/// ```
+/// # use puzzle::Puzzle;
+/// # #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// # struct Count(u32);
+/// # impl Puzzle for Count {
+/// #     type Move = u32;
+/// #     fn is_goal(&self) -> bool {
+/// #         self.0 == 10
+/// #     }
+/// #     fn legal_moves(&self) -> Vec<u32> {
+/// #         vec![1, 2]
+/// #     }
+/// #     fn apply_move(&self, m: &u32) -> Self {
+/// #         Count(self.0 + m)
+/// #     }
+/// # }
+/// #[global_allocator]
+/// static ALLOC: puzzle::CountingAllocator<std::alloc::System> =
+///     puzzle::CountingAllocator::new(std::alloc::System);
+///
+/// fn main() {
+///     let (soln, stats) = puzzle::solve_with_memory_stats(Count(0));
+///     assert_eq!(soln.unwrap().0.len(), 5);
+///     assert!(stats.peak_bytes.is_some_and(|bytes| bytes > 0));
+/// }
+/// ```
+///
+/// The counts are global, not per thread; so, the peak of a search also
+/// counts the allocations of other threads during the search.
+#[derive(Debug)]
+pub struct CountingAllocator<A>(A);
+
+impl<A> CountingAllocator<A> {
+    /// A counting allocator that allocates with `alloc`.
+    pub const fn new(alloc: A) -> Self {
+        CountingAllocator(alloc)
+    }
+}
+
+/// Add `bytes` to the allocated bytes, updating the peak.
+fn allocate(bytes: usize) {
+    let now = ALLOCATED.fetch_add(bytes, Relaxed) + bytes;
+    PEAK.fetch_max(now, Relaxed);
+    if !INSTALLED.load(Relaxed) {
+        INSTALLED.store(true, Relaxed);
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { self.0.alloc(layout) };
+        if !ptr.is_null() {
+            allocate(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { self.0.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            allocate(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { self.0.dealloc(ptr, layout) };
+        ALLOCATED.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { self.0.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            ALLOCATED.fetch_sub(layout.size(), Relaxed);
+            allocate(new_size);
+        }
+        new
+    }
+}
+
+/// A measurement of the peak number of bytes allocated (by
+/// `CountingAllocator`) since it was started.
+pub(crate) struct PeakBytes {
+    baseline: usize,
+}
+
+impl PeakBytes {
+    /// Start measuring, by resetting the peak to the current number of bytes
+    /// allocated.
+    pub(crate) fn start() -> Self {
+        let baseline = ALLOCATED.load(Relaxed);
+        PEAK.store(baseline, Relaxed);
+        PeakBytes { baseline }
+    }
+
+    /// The peak number of bytes allocated since `start` (beyond those
+    /// allocated at `start`), or `None` if `CountingAllocator` is not the
+    /// global allocator.
+    pub(crate) fn finish(self) -> Option<usize> {
+        INSTALLED
+            .load(Relaxed)
+            .then(|| PEAK.load(Relaxed).saturating_sub(self.baseline))
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::hash_map::DefaultHasher;
+use std::convert::Infallible;
+use std::hash::{Hash, Hasher};
+
+use super::dfs::{dfs_by_config, Search};
+use super::frontier::{Ended, Solved};
+use super::{Heuristic, Observer, Puzzle, SolveOutcome, SolverConfig};
+
+/// Trait for puzzles whose moves can be made (and unmade) in place, so that a
+/// search can mutate a single puzzle state rather than cloning a puzzle state
+/// for every successor.
+pub trait MutablePuzzle: Puzzle {
+    /// Enumerates all of the (legal) moves from the current puzzle state, in
+    /// the same order as `next`.
+    ///
+    /// The default implementation returns `legal_moves`.
+    fn moves(&self) -> Vec<Self::Move> {
+        self.legal_moves()
+    }
+
+    /// Makes the (legal) move `m`, changing the current puzzle state to the
+    /// successor puzzle state that `m` leads to.
+    fn apply(&mut self, m: &Self::Move);
+
+    /// Unmakes the move `m`, changing the current puzzle state back to the
+    /// puzzle state from which `m` was made; that is, `p.apply(m)` followed by
+    /// `p.undo(m)` leaves `p` unchanged.
+    fn undo(&mut self, m: &Self::Move);
+}
+
+/// Solve a puzzle using IDA* search, making and unmaking moves in place.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  If `P::estimate` is admissible, then the sequence
+/// of moves `ms` is one of the shortest sequence of moves from `p0` to a goal
+/// state (as with `solve_idastar`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// The search is the same as that of `solve_idastar`, except that a single
+/// puzzle state is mutated (by `MutablePuzzle::apply` and
+/// `MutablePuzzle::undo`) as the search descends and backtracks, so that no
+/// puzzle state is cloned (except for the goal state, once).  Rather than the
+/// puzzle states along the current path, only their hashes are kept in order
+/// to avoid cycles; in the (astronomically unlikely) event of a hash
+/// collision, a sequence of moves that does not form a cycle may be skipped.
+pub fn solve_idastar_mut<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: MutablePuzzle + Heuristic + Hash,
+    P::Move: Clone,
+{
+    let unlimited = &mut |_: &P, _| Ok::<_, Infallible>(true);
+    let Ok(ended) = idastar_mut_by(p0, unlimited);
+    ended.goal()
+}
+
+/// Solve a puzzle using IDA* search, making and unmaking moves in place, as
+/// configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_idastar_mut(p0)` (or, with a `max_depth`, without expanding the
+/// puzzle states `max_depth` moves from `p0`) if the search finds it within the
+/// limits of `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_idastar_with_config`.
+pub fn solve_idastar_mut_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: MutablePuzzle + Heuristic + Hash,
+    P::Move: Clone,
+    O: Observer<P>,
+{
+    dfs_by_config(p0, config, |p0, check| idastar_mut_by(p0, check))
+}
+
+/// IDA* search from `p0`, making and unmaking moves in place, where `check`
+/// is called before each expansion (see `dfs::search`).
+fn idastar_mut_by<P, C, E>(p0: P, check: &mut C) -> Solved<P, E>
+where
+    P: MutablePuzzle + Heuristic + Hash,
+    P::Move: Clone,
+    C: FnMut(&P, usize) -> Result<bool, E> + ?Sized,
+{
+    let mut p = p0;
+    let mut bound = p.estimate();
+    let mut path = vec![hash(&p)];
+    let mut moves = Vec::new();
+    loop {
+        match search(&mut p, &mut path, &mut moves, bound, check)? {
+            Search::Found(()) => return Ok(Ended::Goal((moves, p))),
+            Search::Cutoff(None) => return Ok(Ended::Exhausted),
+            Search::Cutoff(Some(next_bound)) => bound = next_bound,
+        }
+    }
+}
+
+/// The hash of a puzzle state, used to detect cycles along the current path.
+fn hash<P: Hash>(p: &P) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    p.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Depth-first search from `p`, bounded by `bound` on the number of moves so
+/// far plus `P::estimate`; `path` holds the hashes of the puzzle states along
+/// the current path (ending with that of `p`) and `moves` is the sequence of
+/// moves leading to `p`.  On `Search::Found`, `p` is the goal state and `moves`
+/// is the sequence of moves leading to it; otherwise, `p`, `path`, and `moves`
+/// are restored to their initial contents (unless `check`, called before each
+/// expansion as for `dfs::search`, abandons the search).
+fn search<P, C, E>(
+    p: &mut P,
+    path: &mut Vec<u64>,
+    moves: &mut Vec<P::Move>,
+    bound: usize,
+    check: &mut C,
+) -> Result<Search<()>, E>
+where
+    P: MutablePuzzle + Heuristic + Hash,
+    P::Move: Clone,
+    C: FnMut(&P, usize) -> Result<bool, E> + ?Sized,
+{
+    let f = moves.len() + p.estimate();
+    if f > bound {
+        return Ok(Search::Cutoff(Some(f)));
+    }
+    if p.is_goal() {
+        return Ok(Search::Found(()));
+    }
+    if p.prune() || !check(p, moves.len())? {
+        return Ok(Search::Cutoff(None));
+    }
+    let mut min = None;
+    let mut ms = p.moves();
+    ms.sort_by_key(|m| std::cmp::Reverse(p.order_hint(m)));
+    for m in ms {
+        p.apply(&m);
+        let h = hash(p);
+        // avoid cycles along the current path
+        if !path.contains(&h) {
+            path.push(h);
+            moves.push(m.clone());
+            match search(p, path, moves, bound, check)? {
+                Search::Found(()) => return Ok(Search::Found(())),
+                Search::Cutoff(Some(f)) => min = Some(min.map_or(f, |min: usize| min.min(f))),
+                Search::Cutoff(None) => {}
+            }
+            moves.pop();
+            path.pop();
+        }
+        p.undo(&m);
+    }
+    Ok(Search::Cutoff(min))
+}
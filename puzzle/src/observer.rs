@@ -0,0 +1,207 @@
+use std::hash::{BuildHasher, Hash};
+use std::time::Instant;
+
+use super::{bounded_bfs, Puzzle, SearchStats, SolveOutcome, SolverConfig};
+
+/// Trait for observers of the progress of a search, as called by
+/// `solve_observed` and by the solvers configured by a `SolverConfig` with an
+/// observer (see `SolverConfig::observer`); e.g., so that a long search can
+/// report its depth and the number of visited puzzle states as it runs.
+///
+/// Each solver calls the methods for the events it has: e.g., a depth-first
+/// search has no layers to complete, and a parallel search only completes
+/// layers and solves the search.
+///
+/// Every method does nothing by default, so an observer need only implement
+/// the events of interest; `()` observes nothing.  The methods are called on
+/// the thread of the search, so they should be cheap (e.g., only print every
+/// so many expansions).
+pub trait Observer<P: Puzzle> {
+    /// Called before the puzzle state `p`, `depth` moves from the initial
+    /// puzzle state, is expanded.
+    fn on_expand(&mut self, p: &P, depth: usize) {
+        let _ = (p, depth);
+    }
+
+    /// Called when the puzzle state `p`, a successor of `parent`, is first
+    /// reached, `depth` moves from the initial puzzle state.
+    fn on_discover(&mut self, p: &P, parent: &P, depth: usize) {
+        let _ = (p, parent, depth);
+    }
+
+    /// Called once every puzzle state `depth` moves from the initial puzzle
+    /// state has been expanded, when `states` puzzle states have been visited.
+    fn on_layer_complete(&mut self, depth: usize, states: usize) {
+        let _ = (depth, states);
+    }
+
+    /// Called when the search is solved by the sequence of moves `ms` to the
+    /// goal state `p`.
+    fn on_solution(&mut self, ms: &[P::Move], p: &P) {
+        let _ = (ms, p);
+    }
+
+    /// Called once the search ends (solved or not, or abandoned at a limit),
+    /// with its statistics `stats` (see `SearchStats`).
+    fn on_finish(&mut self, stats: &SearchStats) {
+        let _ = stats;
+    }
+}
+
+impl<P: Puzzle> Observer<P> for () {}
+
+/// A `SearchStats` observes the statistics of a search; e.g.,
+/// `SolverConfig::new().observer(&mut stats)` collects the statistics of any
+/// solver configured by it in `stats`.
+impl<P: Puzzle> Observer<P> for SearchStats {
+    fn on_finish(&mut self, stats: &SearchStats) {
+        self.clone_from(stats);
+    }
+}
+
+impl<P: Puzzle, O: Observer<P> + ?Sized> Observer<P> for &mut O {
+    fn on_expand(&mut self, p: &P, depth: usize) {
+        (**self).on_expand(p, depth)
+    }
+
+    fn on_discover(&mut self, p: &P, parent: &P, depth: usize) {
+        (**self).on_discover(p, parent, depth)
+    }
+
+    fn on_layer_complete(&mut self, depth: usize, states: usize) {
+        (**self).on_layer_complete(depth, states)
+    }
+
+    fn on_solution(&mut self, ms: &[P::Move], p: &P) {
+        (**self).on_solution(ms, p)
+    }
+
+    fn on_finish(&mut self, stats: &SearchStats) {
+        (**self).on_finish(stats)
+    }
+}
+
+/// An observer of a search of puzzle states `Q` that wrap puzzle states `P`
+/// (e.g., `Deterministic`), which reports the wrapped puzzle states, as
+/// unwrapped by its function, to the observer `O` of puzzle states `P`.
+pub(crate) struct Mapped<O, F>(pub(crate) O, pub(crate) F);
+
+impl<P, Q, O, F> Observer<Q> for Mapped<O, F>
+where
+    P: Puzzle,
+    Q: Puzzle<Move = P::Move>,
+    O: Observer<P>,
+    F: Fn(&Q) -> &P,
+{
+    fn on_expand(&mut self, p: &Q, depth: usize) {
+        self.0.on_expand((self.1)(p), depth)
+    }
+
+    fn on_discover(&mut self, p: &Q, parent: &Q, depth: usize) {
+        self.0.on_discover((self.1)(p), (self.1)(parent), depth)
+    }
+
+    fn on_layer_complete(&mut self, depth: usize, states: usize) {
+        self.0.on_layer_complete(depth, states)
+    }
+
+    fn on_solution(&mut self, ms: &[P::Move], p: &Q) {
+        self.0.on_solution(ms, (self.1)(p))
+    }
+
+    fn on_finish(&mut self, stats: &SearchStats) {
+        self.0.on_finish(stats)
+    }
+}
+
+/// An event of a search, as emitted by `Events`, identifying puzzle states by
+/// their keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchEvent<K> {
+    /// The puzzle state `key`, `depth` moves from the initial puzzle state, is
+    /// expanded.
+    Expanded { key: K, depth: usize },
+    /// The puzzle state `key`, a successor of the puzzle state `parent`, is
+    /// first reached, `depth` moves from the initial puzzle state.
+    Discovered { key: K, parent: K, depth: usize },
+    /// The goal state `key`, `depth` moves from the initial puzzle state,
+    /// solves the search.
+    GoalFound { key: K, depth: usize },
+}
+
+/// An observer that emits a stream of `SearchEvent`s to a sink, e.g., so that
+/// an external visualizer can animate a search.
+///
+/// Each puzzle state of an event is identified by its key, as computed by
+/// `key` (e.g., `Keyed::key`, or a packed or ranked form of the puzzle state),
+/// and each event is passed to `sink` as it happens.  The sink may be any
+/// closure; e.g., `|event| { let _ = sender.send(event); }` sends the events
+/// on a channel to another thread.  Layers are not events of the stream,
+/// since they can be recovered from the depths.
+pub struct Events<F, G> {
+    key: F,
+    sink: G,
+}
+
+impl<F, G> Events<F, G> {
+    /// An observer that passes each event, with the puzzle states identified
+    /// by `key`, to `sink`.
+    pub fn new(key: F, sink: G) -> Self {
+        Events { key, sink }
+    }
+}
+
+impl<P, K, F, G> Observer<P> for Events<F, G>
+where
+    P: Puzzle,
+    F: Fn(&P) -> K,
+    G: FnMut(SearchEvent<K>),
+{
+    fn on_expand(&mut self, p: &P, depth: usize) {
+        let key = (self.key)(p);
+        (self.sink)(SearchEvent::Expanded { key, depth })
+    }
+
+    fn on_discover(&mut self, p: &P, parent: &P, depth: usize) {
+        let (key, parent) = ((self.key)(p), (self.key)(parent));
+        (self.sink)(SearchEvent::Discovered { key, parent, depth })
+    }
+
+    fn on_solution(&mut self, ms: &[P::Move], p: &P) {
+        let key = (self.key)(p);
+        (self.sink)(SearchEvent::GoalFound {
+            key,
+            depth: ms.len(),
+        })
+    }
+}
+
+/// Solve a puzzle using BFS with hashing of states, as configured by `config`,
+/// reporting its progress to `observer`.
+///
+/// Returns the same outcome as `solve_with_config(p0, config)`; this is
+/// `solve_with_config` with `observer` as the observer of `config` (see
+/// `SolverConfig::observer`), for a configuration that is kept for other
+/// searches.
+///
+/// The BFS calls `observer.on_expand` for each expanded puzzle state,
+/// `observer.on_discover` for each newly reached puzzle state,
+/// `observer.on_layer_complete` after each layer (i.e., each number of moves
+/// from `p0`) is expanded, and `observer.on_solution` for the solution, if any
+/// (see `Observer`).
+pub fn solve_observed<P, S, O>(p0: P, config: &SolverConfig<S>, observer: &mut O) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let limits = config.bfs_limits(&p0, Instant::now());
+    bounded_bfs(
+        p0,
+        config.hasher.clone(),
+        limits,
+        &mut SearchStats::default(),
+        observer,
+    )
+}
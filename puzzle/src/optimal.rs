@@ -0,0 +1,195 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::frontier::{explore_by, Reached, Search};
+use super::{Fifo, Puzzle};
+
+/// A hash table recording, for each puzzle state reached by the BFS, the number
+/// of moves from the initial puzzle state and all of its predecessors (with the
+/// connecting moves) at one fewer move from the initial puzzle state, hashed
+/// with the hasher built by `S`.
+pub(crate) type Parents<P, M, S = RandomState> = HashMap<P, (usize, Vec<(P, M)>), S>;
+
+/// Solve a puzzle using BFS with hashing of states, returning every shortest
+/// sequence of moves.
+///
+/// Returns `Some((mss,p))` if puzzle `p0` can be solved, where `mss` is the
+/// collection of all of the distinct shortest sequences of moves from `p0` to a
+/// goal state.  Note that there may not be a unique goal state for a puzzle,
+/// therefore, different sequences of moves of `mss` may reach different goal
+/// states; the goal state `p` is the goal state reached by the first sequence
+/// of moves of `mss`.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// The BFS proceeds one layer (i.e., number of moves from `p0`) at a time,
+/// recording every predecessor of each puzzle state from the previous layer
+/// (not just the first, as does `solve`), and stops after the first layer with
+/// a goal state.  The sequences of moves are then reconstructed by enumerating
+/// every path of predecessors from each goal state of that layer.  Note that
+/// the number of shortest sequences of moves may be exponential in their
+/// length; see `count_optimal_solutions` to count them without constructing
+/// them.
+pub fn solve_all<P>(p0: P) -> Option<(Vec<Vec<P::Move>>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    // the predecessors of each node, but its parent in the arena
+    let mut others: Vec<Vec<(usize, P::Move)>> = vec![vec![]];
+    let record = |i, m: &P::Move, j: usize, new| {
+        if new {
+            others.push(vec![]);
+        } else {
+            others[j].push((i, m.clone()));
+        }
+    };
+    let (reached, goals) = shortest(p0, record)?;
+    let mut vecs = vec![];
+    for (i, _) in &goals {
+        collect_paths(&reached, &others, *i, &mut vec![], &mut vecs);
+    }
+    let (_, p) = goals.into_iter().next()?;
+    Some((vecs, p))
+}
+
+/// Count the shortest sequences of moves that solve a puzzle, using BFS with
+/// hashing of states.
+///
+/// Returns `Some((n,c))` if puzzle `p0` can be solved, where `n` is the number
+/// of moves of a shortest sequence of moves from `p0` to a goal state and `c`
+/// is the number of distinct such sequences of moves (i.e., the number of
+/// sequences of moves returned by `solve_all`).  The count saturates at
+/// `usize::MAX`.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// The BFS proceeds one layer (i.e., number of moves from `p0`) at a time,
+/// recording for each puzzle state the number of shortest sequences of moves
+/// from `p0` to it; this is the sum of the numbers recorded for its
+/// predecessors from the previous layer.  Unlike `solve_all`, neither the
+/// predecessors nor the sequences of moves are kept.
+pub fn count_optimal_solutions<P>(p0: P) -> Option<(usize, usize)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+{
+    // the number of shortest sequences of moves to each node
+    let mut counts = vec![1];
+    let record = |i: usize, _: &P::Move, j: usize, new| {
+        if new {
+            counts.push(counts[i]);
+        } else {
+            counts[j] = usize::saturating_add(counts[j], counts[i]);
+        }
+    };
+    let (reached, goals) = shortest(p0, record)?;
+    let count = goals
+        .iter()
+        .fold(0, |count: usize, (i, _)| count.saturating_add(counts[*i]));
+    Some((reached.cost(goals[0].0), count))
+}
+
+/// BFS from `p0` that calls `record(i, m, j, new)` for each predecessor (node
+/// `i`, with the connecting move `m`) of each node `j` from the previous layer
+/// (where `new` is whether or not node `j` is newly reached, with node `i` as
+/// its parent), stopping after the first layer with a goal state.  Returns the
+/// reached puzzle states and the goal states of that layer (with their nodes,
+/// in the order in which they were reached), or `None` if no goal state can be
+/// reached.
+#[allow(clippy::type_complexity)]
+fn shortest<P, R>(p0: P, record: R) -> Option<(Reached<P, P::Move>, Vec<(usize, P)>)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    R: FnMut(usize, &P::Move, usize, bool),
+{
+    let mut shortest = Shortest {
+        record,
+        nodes: 1,
+        layer: None,
+        goals: vec![],
+    };
+    let mut reached = Reached::new();
+    explore_by(p0, &mut shortest, Fifo::new(), &mut reached);
+    if shortest.goals.is_empty() {
+        return None;
+    }
+    Some((reached, shortest.goals))
+}
+
+/// The hooks of `shortest`, which never stops at a goal state, but records
+/// the goal states of the first layer with one, and then skips the remaining
+/// puzzle states.
+struct Shortest<P, R> {
+    record: R,
+    /// The number of nodes that have been recorded.
+    nodes: usize,
+    /// The number of moves to the goal states, once one is reached.
+    layer: Option<usize>,
+    goals: Vec<(usize, P)>,
+}
+
+impl<P, R> Search<P> for Shortest<P, R>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    R: FnMut(usize, &P::Move, usize, bool),
+{
+    const NAME: &'static str = "shortest";
+    type Key = P;
+    type Frontier = Fifo<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.clone()
+    }
+
+    fn priority(&mut self, _: usize, _: &P) {}
+
+    fn reach<H>(
+        &mut self,
+        reached: &Reached<P, P::Move, H>,
+        i: usize,
+        m: &P::Move,
+        j: usize,
+        g: usize,
+    ) {
+        if j == self.nodes {
+            self.nodes += 1;
+            (self.record)(i, m, j, true);
+        } else if g == reached.cost(j) {
+            (self.record)(i, m, j, false);
+        }
+    }
+
+    fn skip(&mut self, p: &P, g: usize) -> bool {
+        self.layer.is_some_and(|layer| g > layer || !p.is_goal())
+    }
+
+    fn stop<H>(&mut self, reached: &Reached<P, P::Move, H>, i: usize, p: &P) -> bool {
+        self.layer = Some(reached.cost(i));
+        self.goals.push((i, p.clone()));
+        false
+    }
+}
+
+/// Push onto `vecs` every sequence of moves, ending with the (reversed)
+/// sequence of moves `suffix`, along a path of predecessors (the parent of
+/// each node in the arena of `reached`, and its `others`) from the initial
+/// puzzle state to node `i`.
+fn collect_paths<P, M: Clone>(
+    reached: &Reached<P, M>,
+    others: &[Vec<(usize, M)>],
+    i: usize,
+    suffix: &mut Vec<M>,
+    vecs: &mut Vec<Vec<M>>,
+) {
+    match reached.parent(i) {
+        Some((j, m)) => {
+            for (j, m) in std::iter::once((j, m)).chain(others[i].iter().map(|(j, m)| (*j, m))) {
+                suffix.push(m.clone());
+                collect_paths(reached, others, j, suffix, vecs);
+                suffix.pop();
+            }
+        }
+        None => vecs.push(suffix.iter().rev().cloned().collect()),
+    }
+}
@@ -0,0 +1,98 @@
+use super::frontier::Ended;
+use super::{Puzzle, Solution, SolveError};
+
+/// The outcome of a search, as returned by `solve_with_config` (and the other
+/// solvers configured by a `SolverConfig`), which tells a puzzle that was
+/// proven unsolvable apart from a search that was abandoned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveOutcome<P: Puzzle> {
+    /// The sequence of moves `moves` solves the puzzle, reaching the goal
+    /// state `goal`.
+    Solved { moves: Vec<P::Move>, goal: P },
+    /// No sequence of moves solves the puzzle, as proven by exploring
+    /// `states_explored` puzzle states.
+    Unsolvable { states_explored: usize },
+    /// No sequence of moves within the `max_depth` of the search, or found
+    /// without detecting every duplicate puzzle state (with a `dedupe_scope`),
+    /// or found before the search stopped with puzzle states left to expand,
+    /// solves the puzzle, after exploring `states_explored` puzzle states; a
+    /// longer sequence of moves may still solve it.
+    Inconclusive { states_explored: usize },
+    /// A limit of the search was exceeded (see `SolveError`).
+    Limit(SolveError),
+}
+
+impl<P: Puzzle> SolveOutcome<P> {
+    /// Determines whether or not the puzzle was solved.
+    pub fn is_solved(&self) -> bool {
+        matches!(self, SolveOutcome::Solved { .. })
+    }
+
+    /// Returns `Some((ms,p))` if the puzzle was solved by the sequence of moves
+    /// `ms` to the goal state `p`, and `None` otherwise.
+    pub fn into_solution(self) -> Option<(Vec<P::Move>, P)> {
+        match self {
+            SolveOutcome::Solved { moves, goal } => Some((moves, goal)),
+            _ => None,
+        }
+    }
+
+    /// Returns `Ok(Some((ms,p)))` if the puzzle was solved by the sequence of
+    /// moves `ms` to the goal state `p`, `Ok(None)` if it was not (whether or
+    /// not it was proven unsolvable), and `Err(err)` if a limit of the search
+    /// was exceeded.
+    pub fn into_result(self) -> Result<Solution<P>, SolveError> {
+        match self {
+            SolveOutcome::Solved { moves, goal } => Ok(Some((moves, goal))),
+            SolveOutcome::Unsolvable { .. } | SolveOutcome::Inconclusive { .. } => Ok(None),
+            SolveOutcome::Limit(err) => Err(err),
+        }
+    }
+
+    /// The outcome of a search (see `frontier::search`) that ended as `ended`,
+    /// after exploring `states_explored` puzzle states, where `solution`
+    /// recovers the solution from the goal state (and the index of its node).
+    pub(crate) fn new<T>(
+        ended: Result<Ended<T>, SolveError>,
+        states_explored: usize,
+        solution: impl FnOnce(T) -> (Vec<P::Move>, P),
+    ) -> Self {
+        match ended {
+            Ok(Ended::Goal(goal)) => {
+                let (moves, goal) = solution(goal);
+                SolveOutcome::Solved { moves, goal }
+            }
+            Ok(Ended::Exhausted) => SolveOutcome::Unsolvable { states_explored },
+            Ok(Ended::Cut | Ended::Paused) => SolveOutcome::Inconclusive { states_explored },
+            Err(err) => err.into(),
+        }
+    }
+
+    /// The outcome with the goal state `goal` (if solved) replaced by
+    /// `f(goal)`; e.g., to unwrap the goal state of a search of wrapped
+    /// puzzle states.
+    pub(crate) fn map_goal<Q>(self, f: impl FnOnce(P) -> Q) -> SolveOutcome<Q>
+    where
+        Q: Puzzle<Move = P::Move>,
+    {
+        match self {
+            SolveOutcome::Solved { moves, goal } => SolveOutcome::Solved {
+                moves,
+                goal: f(goal),
+            },
+            SolveOutcome::Unsolvable { states_explored } => {
+                SolveOutcome::Unsolvable { states_explored }
+            }
+            SolveOutcome::Inconclusive { states_explored } => {
+                SolveOutcome::Inconclusive { states_explored }
+            }
+            SolveOutcome::Limit(err) => SolveOutcome::Limit(err),
+        }
+    }
+}
+
+impl<P: Puzzle> From<SolveError> for SolveOutcome<P> {
+    fn from(err: SolveError) -> Self {
+        SolveOutcome::Limit(err)
+    }
+}
@@ -0,0 +1,73 @@
+use std::hash::{BuildHasher, Hash};
+
+use super::frontier::{solve_by, solve_by_config, Reached, Search};
+use super::{Fifo, Observer, Puzzle, SolveOutcome, SolverConfig};
+
+/// Trait for puzzles whose puzzle states can be packed into a compact form
+/// (e.g., a `u64` or a small byte array) and unpacked again, so that a solver
+/// can record only the packed forms (see `solve_packed`).
+pub trait PackState: Puzzle + Sized {
+    /// The type of packed puzzle states.
+    type Packed: Eq + Hash + Clone;
+
+    /// Returns the packed form of the puzzle state.
+    fn pack(&self) -> Self::Packed;
+
+    /// Returns the puzzle state with the packed form `packed`; that is,
+    /// `P::unpack(&p.pack())` must equal `p`.
+    fn unpack(packed: &Self::Packed) -> Self;
+}
+
+/// Solve a puzzle using BFS with hashing of packed states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`, which should be one of the shortest sequence of
+/// moves from `p0` to a goal state (as for `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// Like `solve`, but the hash table holds only the packed forms of puzzle
+/// states (see `PackState`), rather than (the representatives of) the puzzle
+/// states themselves; only the puzzle states of the BFS queue are kept whole.
+/// The representatives of puzzle states (see `Puzzle::canonicalize`) and the
+/// scores of goal states (see `Puzzle::goal_score`) are ignored.
+pub fn solve_packed<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: PackState,
+{
+    solve_by(p0, &mut Packing, Fifo::new(), &mut Reached::new())
+}
+
+/// Solve a puzzle using BFS with hashing of packed states, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_packed(p0)`
+/// (or, with a `max_depth`, without expanding the puzzle states `max_depth`
+/// moves from `p0`) if the search finds it within the limits of `config`, and
+/// otherwise the outcome of the search as for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_with_config`, with the hasher
+/// hashing the packed forms of puzzle states.
+pub fn solve_packed_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: PackState,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    solve_by_config(p0, &mut Packing, Fifo::new(), config)
+}
+
+/// The hooks of `solve_packed`.
+struct Packing;
+
+impl<P: PackState> Search<P> for Packing {
+    const NAME: &'static str = "solve_packed";
+    type Key = P::Packed;
+    type Frontier = Fifo<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P::Packed {
+        p.pack()
+    }
+
+    fn priority(&mut self, _: usize, _: &P) {}
+}
@@ -0,0 +1,100 @@
+use std::time::Instant;
+
+use super::lockfree::lockfree_bfs;
+use super::{BfsLimits, Observer, PackState, SearchStats, SolveOutcome, SolverConfig};
+
+/// Solve a puzzle using parallel BFS with a lock-free hash table of packed
+/// states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  The sequence of moves `ms` should be one of the
+/// shortest sequence of moves from `p0` to a goal state (as with `solve`).
+/// Note that, because of the nondeterministic scheduling of threads, the
+/// sequence of moves `ms` and goal state `p` may differ from those returned by
+/// `solve` (and between runs).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// The BFS proceeds one layer (i.e., number of moves from `p0`) at a time; the
+/// puzzle states of a layer are expanded in parallel (using `rayon`) and the
+/// successor puzzle states are recorded by their packed forms (see
+/// `PackState`, here with `u64` packed forms) in a lock-free hash table, so
+/// that threads never block one another.  The generic type parameter `P` (and
+/// its moves) must additionally implement `Send` and `Sync`, in order to be
+/// shared between threads.  The puzzle states of a layer are expanded in
+/// batches (see `Puzzle::next_batch`), each of which is a unit of work for a
+/// thread.  As with `solve`, of the goal states of the first layer with any,
+/// one with the least score (see `Puzzle::goal_score`) is returned.  As for
+/// `solve_packed`, the moves are recovered while backtracking, by unpacking
+/// each predecessor puzzle state and finding a move from it, and unlike
+/// `solve`, the representatives of puzzle states (see `Puzzle::canonicalize`)
+/// are ignored, since a representative need not have a packed form; so, the
+/// search may visit more puzzle states than that of `solve`.
+///
+/// Panics if `P::next_batch` returns a different number of vectors of
+/// successor puzzle states than puzzle states.
+pub fn solve_parallel<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: PackState<Packed = u64> + Send + Sync,
+    P::Move: Send,
+{
+    let limits = BfsLimits {
+        max_states: usize::MAX,
+        ..BfsLimits::new(&p0)
+    };
+    match lockfree_bfs(p0, limits, &mut 0, &mut ()) {
+        Ok(ended) => ended.goal(),
+        Err(err) => unreachable!("solve_parallel has no limit for {:?}", err),
+    }
+}
+
+/// Solve a puzzle using parallel BFS with a lock-free hash table of packed
+/// states, as configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_parallel(p0)` (or, with a `max_depth`, a shortest sequence of at
+/// most `max_depth` moves) if the search finds it within the limits of
+/// `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// The BFS is that of `solve_parallel`, run on a thread pool of
+/// `config.parallelism` threads (see `SolverConfig::parallelism`).  The limits
+/// are checked between layers: the time limit (and the cancel token) before
+/// each layer is expanded, and the limits on visited puzzle states (with the
+/// memory limit converted as for `solve_with_config`) after each layer is
+/// generated, so that the search may exceed them by (at most) a layer.  The
+/// hasher and the `dedupe_scope` of `config` are ignored: the lock-free hash
+/// table hashes the packed forms itself, and every duplicate is detected.  The
+/// puzzle states are expanded on the threads of the pool, so the observer of
+/// `config` is only told of each completed layer, of the solution, and of the
+/// number of visited puzzle states (as the statistics of the search).
+///
+/// Panics if the thread pool cannot be built.
+pub fn solve_parallel_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: PackState<Packed = u64> + Send + Sync,
+    P::Move: Send,
+    O: Observer<P> + Send,
+{
+    let start = Instant::now();
+    let limits = config.bfs_limits(&p0, start);
+    let pool = match rayon::ThreadPoolBuilder::new()
+        .num_threads(config.parallelism)
+        .build()
+    {
+        Ok(pool) => pool,
+        Err(err) => panic!(
+            "solve_parallel_with_config failed to build a thread pool: {}",
+            err
+        ),
+    };
+    let (mut stats, observer) = (SearchStats::default(), &mut *config.observer.borrow_mut());
+    let ended = pool.install(|| lockfree_bfs(p0, limits, &mut stats.visited, observer));
+    let outcome = SolveOutcome::new(ended, stats.visited, |(vec, p)| {
+        observer.on_solution(&vec, &p);
+        stats.depth = Some(vec.len());
+        (vec, p)
+    });
+    stats.finish(start, observer);
+    outcome
+}
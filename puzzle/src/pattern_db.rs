@@ -0,0 +1,258 @@
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+
+use super::{Heuristic, Puzzle};
+
+/// A pattern database: the exact number of moves to a goal state from each
+/// puzzle state of an abstraction of a puzzle.
+///
+/// An abstraction maps puzzle states of type `P` to abstract puzzle states of
+/// type `A` (which is itself a `Puzzle`), such that every move from a puzzle
+/// state `p` to `q` corresponds to a move from `abstraction(&p)` to
+/// `abstraction(&q)` and every goal state is mapped to an abstract goal state.
+/// Then, the number of moves from `abstraction(&p)` to an abstract goal state
+/// never exceeds the number of moves from `p` to a goal state, and so is an
+/// admissible (and consistent) estimate.  Abstractions typically forget some
+/// of the puzzle state (e.g., the identities of interchangeable objects, or
+/// the positions of objects other than a chosen few), so that the abstract
+/// state space is small enough to be searched exhaustively in advance.
+pub struct PatternDatabase<P, A> {
+    abstraction: fn(&P) -> A,
+    /// The number of moves to an abstract goal state, saturating at
+    /// `u8::MAX` (which remains admissible), for each abstract puzzle state
+    /// from which an abstract goal state can be reached.
+    distances: HashMap<A, u8>,
+}
+
+impl<P, A> PatternDatabase<P, A>
+where
+    A: Puzzle + Eq + Hash + Clone,
+{
+    /// Precompute a pattern database for `abstraction`, covering every
+    /// abstract puzzle state reachable from the abstraction of some puzzle
+    /// state of `seeds`.
+    ///
+    /// The abstract puzzle states reachable from the seeds are enumerated (by
+    /// BFS, using `A::next`), and then the number of moves to an abstract goal
+    /// state is computed for every one of them by a BFS backwards (along the
+    /// reversed moves) from all of the reachable abstract goal states.  Thus,
+    /// `A` need not implement `InvertiblePuzzle`.
+    pub fn new<I>(abstraction: fn(&P) -> A, seeds: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+    {
+        // enumerate the reachable abstract puzzle states, with reversed moves
+        let mut index = HashMap::<A, usize>::new();
+        let mut states = vec![];
+        let mut preds: Vec<Vec<usize>> = vec![];
+        for seed in seeds {
+            if let Vacant(e) = index.entry(abstraction(&seed)) {
+                states.push(e.key().clone());
+                preds.push(vec![]);
+                e.insert(states.len() - 1);
+            }
+        }
+        let mut i = 0;
+        while i < states.len() {
+            for (_, b) in states[i].next() {
+                let j = match index.entry(b) {
+                    Vacant(e) => {
+                        states.push(e.key().clone());
+                        preds.push(vec![]);
+                        *e.insert(states.len() - 1)
+                    }
+                    Occupied(e) => *e.get(),
+                };
+                preds[j].push(i);
+            }
+            i += 1;
+        }
+
+        // BFS backwards from all of the abstract goal states
+        let mut dists = vec![None; states.len()];
+        let mut queue = VecDeque::new();
+        for (i, a) in states.iter().enumerate() {
+            if a.is_goal() {
+                dists[i] = Some(0);
+                queue.push_back(i);
+            }
+        }
+        while let Some(i) = queue.pop_front() {
+            let d = dists[i].unwrap_or(0) + 1;
+            for &j in preds[i].iter() {
+                if dists[j].is_none() {
+                    dists[j] = Some(d);
+                    queue.push_back(j);
+                }
+            }
+        }
+
+        let distances = states
+            .into_iter()
+            .zip(dists)
+            .filter_map(|(a, d)| Some((a, u8::try_from(d?).unwrap_or(u8::MAX))))
+            .collect();
+        PatternDatabase {
+            abstraction,
+            distances,
+        }
+    }
+
+    /// The number of abstract puzzle states recorded in the pattern database.
+    pub fn len(&self) -> usize {
+        self.distances.len()
+    }
+
+    /// Determines whether or not the pattern database records no abstract
+    /// puzzle states.
+    pub fn is_empty(&self) -> bool {
+        self.distances.is_empty()
+    }
+
+    /// Returns `Some(d)` if the abstraction of `p` is recorded in the pattern
+    /// database, where `d` is the number of moves from it to an abstract goal
+    /// state (saturating at `u8::MAX`).  Returns `None` if the abstraction of
+    /// `p` was not reached from the seeds or cannot reach an abstract goal
+    /// state.
+    pub fn lookup(&self, p: &P) -> Option<usize> {
+        self.distances
+            .get(&(self.abstraction)(p))
+            .map(|d| *d as usize)
+    }
+
+    /// Estimates the number of moves from `p` to a goal state; that is,
+    /// `lookup(p)`, or `0` if the abstraction of `p` is not recorded in the
+    /// pattern database.
+    pub fn estimate(&self, p: &P) -> usize {
+        self.lookup(p).unwrap_or(0)
+    }
+
+    /// Pair the puzzle state `p` with this pattern database, so that it can be
+    /// solved by a solver that requires `Heuristic` (e.g., `solve_astar`).
+    pub fn guide(&self, p: P) -> Guided<'_, P, A> {
+        Guided { p, db: self }
+    }
+}
+
+impl<P, A> Debug for PatternDatabase<P, A> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("PatternDatabase")
+            .field("len", &self.distances.len())
+            .finish()
+    }
+}
+
+/// A puzzle state paired with a pattern database, whose `Heuristic` estimate
+/// (and `Puzzle::lower_bound`) is looked up in the pattern database.
+///
+/// The moves, successor puzzle states, and goal states are those of the
+/// puzzle state `p`, as are its costs, dead ends, pruning, representatives,
+/// and other hints, so that a solver runs the same search on it as on `p`
+/// (but guided by the pattern database); equality and hashing also only
+/// consider `p`.
+pub struct Guided<'a, P, A> {
+    pub p: P,
+    db: &'a PatternDatabase<P, A>,
+}
+
+impl<P, A> Puzzle for Guided<'_, P, A>
+where
+    P: Puzzle + Clone,
+    A: Puzzle + Eq + Hash + Clone,
+{
+    type Move = P::Move;
+
+    fn is_goal(&self) -> bool {
+        self.p.is_goal()
+    }
+
+    fn legal_moves(&self) -> Vec<Self::Move> {
+        self.p.legal_moves()
+    }
+
+    fn apply_move(&self, m: &Self::Move) -> Self {
+        self.db.guide(self.p.apply_move(m))
+    }
+
+    fn next(&self) -> Vec<(Self::Move, Self)> {
+        self.p
+            .next()
+            .into_iter()
+            .map(|(m, p)| (m, self.db.guide(p)))
+            .collect()
+    }
+
+    fn canonicalize(&self) -> Self {
+        self.db.guide(self.p.canonicalize())
+    }
+
+    fn move_cost(&self, m: &Self::Move) -> u32 {
+        self.p.move_cost(m)
+    }
+
+    fn is_dead(&self) -> bool {
+        self.p.is_dead()
+    }
+
+    fn prune(&self) -> bool {
+        self.p.prune()
+    }
+
+    /// The pattern database estimate (or the lower bound of `p`, if greater).
+    fn lower_bound(&self) -> usize {
+        self.db.estimate(&self.p).max(self.p.lower_bound())
+    }
+
+    fn goal_score(&self) -> i64 {
+        self.p.goal_score()
+    }
+
+    fn order_hint(&self, m: &Self::Move) -> i32 {
+        self.p.order_hint(m)
+    }
+
+    fn state_count_hint(&self) -> usize {
+        self.p.state_count_hint()
+    }
+}
+
+impl<P, A> Heuristic for Guided<'_, P, A>
+where
+    P: Puzzle + Clone,
+    A: Puzzle + Eq + Hash + Clone,
+{
+    fn estimate(&self) -> usize {
+        self.db.estimate(&self.p)
+    }
+}
+
+impl<P: Clone, A> Clone for Guided<'_, P, A> {
+    fn clone(&self) -> Self {
+        Guided {
+            p: self.p.clone(),
+            db: self.db,
+        }
+    }
+}
+
+impl<P: PartialEq, A> PartialEq for Guided<'_, P, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.p == other.p
+    }
+}
+
+impl<P: Eq, A> Eq for Guided<'_, P, A> {}
+
+impl<P: Hash, A> Hash for Guided<'_, P, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.p.hash(state)
+    }
+}
+
+impl<P: Debug, A> Debug for Guided<'_, P, A> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.p.fmt(f)
+    }
+}
@@ -0,0 +1,196 @@
+use std::cell::Cell;
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+use super::{Puzzle, Successors};
+
+/// The counts (and times) of the instrumented operations of `Measured`
+/// puzzle states, on the current thread.
+#[derive(Debug, Clone, Copy, Default)]
+struct Counters {
+    expansions: u64,
+    successors: u64,
+    hashes: u64,
+    expansion_time: Duration,
+    hashing_time: Duration,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = Cell::new(Counters::default());
+}
+
+/// Update the counters of the current thread by `f`.
+fn count(f: impl FnOnce(&mut Counters)) {
+    COUNTERS.with(|counters| {
+        let mut c = counters.get();
+        f(&mut c);
+        counters.set(c);
+    })
+}
+
+/// The throughput of a solver call, as returned by `measure`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PerfReport {
+    /// The number of expansions (i.e., enumerations of the successor puzzle
+    /// states of a puzzle state).
+    pub expansions: u64,
+    /// The number of successor puzzle states enumerated.
+    pub successors: u64,
+    /// The number of puzzle states hashed.
+    pub hashes: u64,
+    /// The time of the whole solver call.
+    pub elapsed: Duration,
+    /// The time spent in expansions.
+    pub expansion_time: Duration,
+    /// The time spent hashing puzzle states.
+    pub hashing_time: Duration,
+}
+
+impl PerfReport {
+    /// The number of expansions per second of the solver call.
+    pub fn expansions_per_sec(&self) -> f64 {
+        self.expansions as f64 / self.elapsed.as_secs_f64()
+    }
+
+    /// The number of successor puzzle states enumerated per second of the
+    /// solver call.
+    pub fn successors_per_sec(&self) -> f64 {
+        self.successors as f64 / self.elapsed.as_secs_f64()
+    }
+}
+
+/// A puzzle state instrumented to count (and time) its expansions and
+/// hashes, on behalf of `measure`.
+///
+/// It is the same puzzle as `P` (with the same moves, representatives, and
+/// hints), so that a solver runs the same search on it as on `P`, except that
+/// every expansion (`next`, `successors`, `next_into`, and `next_small`) and
+/// every hash is counted and timed.  Note that timing each operation has an
+/// overhead (of a few tens of nanoseconds), which is included in the measured
+/// times.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Measured<P>(pub P);
+
+/// Count an expansion (started at `start`) of `successors` successor puzzle
+/// states.
+fn expanded(start: Instant, successors: usize) {
+    let time = start.elapsed();
+    count(|c| {
+        c.expansions += 1;
+        c.successors += successors as u64;
+        c.expansion_time += time;
+    });
+}
+
+impl<P: Hash> Hash for Measured<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let start = Instant::now();
+        self.0.hash(state);
+        let time = start.elapsed();
+        count(|c| {
+            c.hashes += 1;
+            c.hashing_time += time;
+        });
+    }
+}
+
+impl<P: Puzzle + Clone> Puzzle for Measured<P> {
+    type Move = P::Move;
+
+    fn is_goal(&self) -> bool {
+        self.0.is_goal()
+    }
+
+    fn legal_moves(&self) -> Vec<Self::Move> {
+        self.0.legal_moves()
+    }
+
+    fn apply_move(&self, m: &Self::Move) -> Self {
+        Measured(self.0.apply_move(m))
+    }
+
+    fn next(&self) -> Vec<(Self::Move, Self)> {
+        let start = Instant::now();
+        let next: Vec<_> = self.0.successors().map(|(m, q)| (m, Measured(q))).collect();
+        expanded(start, next.len());
+        next
+    }
+
+    fn successors(&self) -> impl Iterator<Item = (Self::Move, Self)> {
+        self.next().into_iter()
+    }
+
+    fn next_into(&self, out: &mut Vec<(Self::Move, Self)>) {
+        let start = Instant::now();
+        let len = out.len();
+        out.extend(self.0.successors().map(|(m, q)| (m, Measured(q))));
+        expanded(start, out.len() - len);
+    }
+
+    fn next_small(&self) -> Successors<Self> {
+        let start = Instant::now();
+        let next: Successors<Self> = self.0.successors().map(|(m, q)| (m, Measured(q))).collect();
+        expanded(start, next.len());
+        next
+    }
+
+    fn canonicalize(&self) -> Self {
+        Measured(self.0.canonicalize())
+    }
+
+    fn move_cost(&self, m: &Self::Move) -> u32 {
+        self.0.move_cost(m)
+    }
+
+    fn is_dead(&self) -> bool {
+        self.0.is_dead()
+    }
+
+    fn prune(&self) -> bool {
+        self.0.prune()
+    }
+
+    fn lower_bound(&self) -> usize {
+        self.0.lower_bound()
+    }
+
+    fn goal_score(&self) -> i64 {
+        self.0.goal_score()
+    }
+
+    fn order_hint(&self, m: &Self::Move) -> i32 {
+        self.0.order_hint(m)
+    }
+
+    fn state_count_hint(&self) -> usize {
+        self.0.state_count_hint()
+    }
+}
+
+/// Measure the throughput of the solver call `solve(Measured(p0))`.
+///
+/// Returns `(r, report)`, where `r` is the result of the solver call, and
+/// `report` counts (and times) the expansions and hashes of its (`Measured`)
+/// puzzle states (see `PerfReport`); e.g.,
+/// `measure(p0, puzzle::solve)` reports the throughput of `solve` for `p0`,
+/// so that a performance regression of a puzzle implementation can be
+/// detected programmatically.
+///
+/// The counts are of the current thread only; so, the expansions and hashes
+/// of other threads (e.g., of `solve_parallel`) are not counted.
+pub fn measure<P, R>(p0: P, solve: impl FnOnce(Measured<P>) -> R) -> (R, PerfReport) {
+    let outer = COUNTERS.with(|counters| counters.replace(Counters::default()));
+    let start = Instant::now();
+    let r = solve(Measured(p0));
+    let elapsed = start.elapsed();
+    let c = COUNTERS.with(|counters| counters.replace(outer));
+    let report = PerfReport {
+        expansions: c.expansions,
+        successors: c.successors,
+        hashes: c.hashes,
+        elapsed,
+        expansion_time: c.expansion_time,
+        hashing_time: c.hashing_time,
+    };
+    (r, report)
+}
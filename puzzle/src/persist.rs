@@ -0,0 +1,88 @@
+use std::io::{Read, Write};
+
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::{Puzzle, Solution};
+
+/// Trait for puzzles whose puzzle states and moves can be serialized, so that
+/// they can be written to and read from files (e.g., by `write_puzzle` and
+/// `write_solution`).
+///
+/// Every puzzle whose puzzle states and moves implement `Serialize` and
+/// `DeserializeOwned` implements `PersistentPuzzle`.
+pub trait PersistentPuzzle:
+    Puzzle<Move: Serialize + DeserializeOwned> + Serialize + DeserializeOwned
+{
+}
+
+impl<P> PersistentPuzzle for P where
+    P: Puzzle<Move: Serialize + DeserializeOwned> + Serialize + DeserializeOwned
+{
+}
+
+/// Write the puzzle state `p` to `w`.
+///
+/// The puzzle state is encoded with `bincode` and compressed with `zlib`
+/// (i.e., in the format of the `.puz.btc.z` files of the puzzle assets).
+pub fn write_puzzle<P, W>(w: W, p: &P) -> bincode::Result<()>
+where
+    P: PersistentPuzzle,
+    W: Write,
+{
+    write_compressed(w, p)
+}
+
+/// Read a puzzle state, as written by `write_puzzle`, from `r`.
+pub fn read_puzzle<P, R>(r: R) -> bincode::Result<P>
+where
+    P: PersistentPuzzle,
+    R: Read,
+{
+    read_compressed(r)
+}
+
+/// Write the solution `soln` of a puzzle to `w`.
+///
+/// The solution is encoded with `bincode` and compressed with `zlib` (i.e., in
+/// the format of the `.soln.btc.z` files of the puzzle assets).
+pub fn write_solution<P, W>(w: W, soln: &Solution<P>) -> bincode::Result<()>
+where
+    P: PersistentPuzzle,
+    W: Write,
+{
+    write_compressed(w, soln)
+}
+
+/// Read a solution of a puzzle, as written by `write_solution`, from `r`.
+pub fn read_solution<P, R>(r: R) -> bincode::Result<Solution<P>>
+where
+    P: PersistentPuzzle,
+    R: Read,
+{
+    read_compressed(r)
+}
+
+/// Write `value` to `w`, encoded with `bincode` and compressed with `zlib`.
+pub(crate) fn write_compressed<T, W>(w: W, value: &T) -> bincode::Result<()>
+where
+    T: Serialize + ?Sized,
+    W: Write,
+{
+    let mut encoder = ZlibEncoder::new(w, Compression::default());
+    bincode::serialize_into(&mut encoder, value)?;
+    encoder.finish()?;
+    Ok(())
+}
+
+/// Read a value, as written by `write_compressed`, from `r`.
+pub(crate) fn read_compressed<T, R>(r: R) -> bincode::Result<T>
+where
+    T: DeserializeOwned,
+    R: Read,
+{
+    bincode::deserialize_from(ZlibDecoder::new(r))
+}
@@ -0,0 +1,184 @@
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
+use std::time::Instant;
+
+use super::frontier::{Ended, Solved};
+use super::optimal::Parents;
+use super::{BfsLimits, Observer, Puzzle, SearchStats, SolveOutcome, SolverConfig};
+
+/// Trait for puzzles that can declare when two moves commute, so that
+/// `solve_por` need not explore both orders of commuting moves.
+pub trait Commuting: Puzzle {
+    /// Determines whether or not the (legal) move `m1` from the current puzzle
+    /// state commutes with the move `m2`; that is, making `m1` and then `m2` is
+    /// legal exactly when making `m2` and then `m1` is legal, and both lead to
+    /// the same puzzle state.
+    ///
+    /// It is always safe to return `false` (at the expense of exploring both
+    /// orders of the moves).
+    fn commute(&self, m1: &Self::Move, m2: &Self::Move) -> bool;
+}
+
+/// Solve a puzzle using BFS with hashing of states and partial-order
+/// reduction of commuting moves.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  The sequence of moves `ms` should be one of the
+/// shortest sequence of moves from `p0` to a goal state (as with `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// Of the two orders of a pair of commuting moves (see `Commuting::commute`)
+/// made one after the other, only the order in which the lesser move (by the
+/// `Ord` of `P::Move`) is made first is explored.  The BFS proceeds one layer
+/// (i.e., number of moves from `p0`) at a time, recording every predecessor of
+/// each puzzle state from the previous layer (as does `solve_all`), and a move
+/// from a puzzle state is skipped only if, for every predecessor of the puzzle
+/// state, the move is less than (and commutes with) the move from the
+/// predecessor.  Any sequence of moves can be reordered, by repeatedly swapping
+/// such adjacent moves, into a sequence of moves of the same length in which
+/// no move is skipped, so the reduction preserves the shortest sequences of
+/// moves.  Skipped moves are not even applied, so for puzzles with many
+/// independent moves (whose interleavings reach the same puzzle states), this
+/// generates far fewer successor puzzle states than `solve`.
+pub fn solve_por<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Commuting + Eq + Hash + Clone,
+    P::Move: Ord + Clone,
+{
+    let limits = BfsLimits {
+        max_states: usize::MAX,
+        ..BfsLimits::new(&p0)
+    };
+    let stats = &mut SearchStats::default();
+    match por(p0, RandomState::new(), &limits, stats, &mut ()) {
+        Ok(ended) => ended.goal(),
+        Err(err) => unreachable!("solve_por has no limit for {:?}", err),
+    }
+}
+
+/// Solve a puzzle using BFS with hashing of states and partial-order reduction
+/// of commuting moves, as configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_por(p0)`
+/// (or, with a `max_depth`, without expanding the puzzle states `max_depth`
+/// moves from `p0`) if the search finds it within the limits of `config`, and
+/// otherwise the outcome of the search as for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_with_config`, except that every
+/// puzzle state reached is recorded with its predecessors: the `dedupe_scope`
+/// of `config` is ignored.
+pub fn solve_por_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Commuting + Eq + Hash + Clone,
+    P::Move: Ord + Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let start = Instant::now();
+    let limits = config.bfs_limits(&p0, start);
+    let (mut stats, observer) = (SearchStats::default(), &mut *config.observer.borrow_mut());
+    let ended = por(p0, config.hasher.clone(), &limits, &mut stats, observer);
+    let outcome = SolveOutcome::new(ended, stats.visited, |(vec, p)| {
+        observer.on_solution(&vec, &p);
+        stats.depth = Some(vec.len());
+        (vec, p)
+    });
+    stats.finish(start, observer);
+    outcome
+}
+
+/// BFS with partial-order reduction (see `solve_por`), hashing the puzzle
+/// states with the hasher built by `hash_builder`, within the `max_depth`,
+/// `max_states`, `max_memory_states`, `deadline` (and `cancel`) of `limits`,
+/// counting its expansions and visited puzzle states in `stats`, and reporting
+/// to `observer`.
+fn por<P, S>(
+    p0: P,
+    hash_builder: S,
+    limits: &BfsLimits,
+    stats: &mut SearchStats,
+    observer: &mut impl Observer<P>,
+) -> Solved<P>
+where
+    P: Commuting + Eq + Hash + Clone,
+    P::Move: Ord + Clone,
+    S: BuildHasher,
+{
+    limits.visit(0)?;
+    let mut hash_map = Parents::<P, P::Move, S>::with_hasher(hash_builder);
+    hash_map.insert(p0.clone(), (0, vec![]));
+    stats.visited = 1;
+    let mut layer = vec![p0];
+    let mut depth = 0;
+    // Loop till the layer is empty
+    while !layer.is_empty() {
+        if let Some(p) = layer.iter().find(|p| p.is_goal()) {
+            // backtrack using the first predecessor of each puzzle state
+            let mut vec = vec![];
+            let mut q = p;
+            while let Some((_, parents)) = hash_map.get(q) {
+                match parents.first() {
+                    Some((pred, m)) => {
+                        vec.push(m.clone());
+                        q = pred;
+                    }
+                    None => break,
+                }
+            }
+            vec.reverse();
+            return Ok(Ended::Goal((vec, p.clone())));
+        }
+        // the successor puzzle states of the horizon would be beyond it
+        if depth >= limits.max_depth {
+            return Ok(Ended::Cut);
+        }
+        limits.expand(stats.visited, depth)?;
+
+        depth += 1;
+        let mut next_layer = vec![];
+        for p in layer {
+            if p.prune() {
+                continue;
+            }
+            let parents = match hash_map.get(&p) {
+                Some((_, parents)) => parents.clone(),
+                None => continue,
+            };
+            observer.on_expand(&p, depth - 1);
+            stats.expanded += 1;
+            for m in p.legal_moves() {
+                let skip = !parents.is_empty()
+                    && parents
+                        .iter()
+                        .all(|(pred, mp)| m < *mp && pred.commute(mp, &m));
+                if skip {
+                    continue;
+                }
+                let q = p.apply_move(&m);
+                stats.generated += 1;
+                match hash_map.entry(q.clone()) {
+                    Vacant(e) => {
+                        limits.visit(stats.visited)?;
+                        observer.on_discover(&q, &p, depth);
+                        stats.visited += 1;
+                        e.insert((depth, vec![(p.clone(), m)]));
+                        next_layer.push(q);
+                    }
+                    Occupied(mut e) => {
+                        stats.duplicates += 1;
+                        let (d, parents) = e.get_mut();
+                        if *d == depth {
+                            parents.push((p.clone(), m));
+                        }
+                    }
+                }
+            }
+        }
+        observer.on_layer_complete(depth - 1, stats.visited);
+        layer = next_layer;
+    }
+
+    Ok(Ended::Exhausted)
+}
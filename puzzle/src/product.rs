@@ -0,0 +1,106 @@
+use super::{Heuristic, Puzzle};
+
+/// A move of a `Product` puzzle: a move of either the left or the right
+/// puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Side<M, N> {
+    Left(M),
+    Right(N),
+}
+
+/// The product of two independent puzzles: a puzzle state is a pair of a
+/// puzzle state of each puzzle, a move is a move of either puzzle (see
+/// `Side`), and a goal state is a pair of goal states.
+///
+/// A shortest sequence of moves of a product is an interleaving of a shortest
+/// sequence of moves of each puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Product<P, Q>(pub P, pub Q);
+
+impl<P, Q> Puzzle for Product<P, Q>
+where
+    P: Puzzle + Clone,
+    Q: Puzzle + Clone,
+{
+    type Move = Side<P::Move, Q::Move>;
+
+    fn is_goal(&self) -> bool {
+        self.0.is_goal() && self.1.is_goal()
+    }
+
+    fn legal_moves(&self) -> Vec<Self::Move> {
+        let mut ms: Vec<Self::Move> = self.0.legal_moves().into_iter().map(Side::Left).collect();
+        ms.extend(self.1.legal_moves().into_iter().map(Side::Right));
+        ms
+    }
+
+    fn apply_move(&self, m: &Self::Move) -> Self {
+        match m {
+            Side::Left(m) => Product(self.0.apply_move(m), self.1.clone()),
+            Side::Right(m) => Product(self.0.clone(), self.1.apply_move(m)),
+        }
+    }
+
+    fn next(&self) -> Vec<(Self::Move, Self)> {
+        let mut next: Vec<(Self::Move, Self)> = self
+            .0
+            .successors()
+            .map(|(m, p)| (Side::Left(m), Product(p, self.1.clone())))
+            .collect();
+        next.extend(
+            self.1
+                .successors()
+                .map(|(m, q)| (Side::Right(m), Product(self.0.clone(), q))),
+        );
+        next
+    }
+
+    fn canonicalize(&self) -> Self {
+        Product(self.0.canonicalize(), self.1.canonicalize())
+    }
+
+    fn move_cost(&self, m: &Self::Move) -> u32 {
+        match m {
+            Side::Left(m) => self.0.move_cost(m),
+            Side::Right(m) => self.1.move_cost(m),
+        }
+    }
+
+    fn is_dead(&self) -> bool {
+        self.0.is_dead() || self.1.is_dead()
+    }
+
+    fn prune(&self) -> bool {
+        self.0.prune() || self.1.prune()
+    }
+
+    fn lower_bound(&self) -> usize {
+        self.0.lower_bound() + self.1.lower_bound()
+    }
+
+    fn goal_score(&self) -> i64 {
+        self.0.goal_score() + self.1.goal_score()
+    }
+
+    fn order_hint(&self, m: &Self::Move) -> i32 {
+        match m {
+            Side::Left(m) => self.0.order_hint(m),
+            Side::Right(m) => self.1.order_hint(m),
+        }
+    }
+}
+
+impl<P, Q> Heuristic for Product<P, Q>
+where
+    P: Heuristic,
+    Q: Heuristic,
+{
+    // each move changes the estimate of only one of the puzzles
+    const ADMISSIBLE: bool = P::ADMISSIBLE && Q::ADMISSIBLE;
+    const CONSISTENT: bool = P::CONSISTENT && Q::CONSISTENT;
+
+    /// The sum of the estimates of the two puzzle states.
+    fn estimate(&self) -> usize {
+        self.0.estimate() + self.1.estimate()
+    }
+}
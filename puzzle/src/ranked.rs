@@ -0,0 +1,125 @@
+use super::{
+    solve_with_closed_set, solve_with_closed_set_with_config, ClosedSet, Observer, Puzzle,
+    SolveOutcome, SolverConfig,
+};
+
+/// Trait for puzzles whose puzzle states can be mapped to a dense range of
+/// indices (ranks), so that a set of puzzle states can be a bit vector (see
+/// `solve_ranked`).
+pub trait RankablePuzzle: Puzzle {
+    /// An upper bound (exclusive) on the ranks of the puzzle states reachable
+    /// from this puzzle state.
+    fn max_rank(&self) -> usize;
+
+    /// The rank of the puzzle state, which is less than `max_rank`.
+    ///
+    /// Distinct puzzle states reachable from one another must have distinct
+    /// ranks.
+    fn rank(&self) -> usize;
+}
+
+/// A set of ranks, as a bit vector.
+pub(crate) struct BitSet(Vec<u64>);
+
+impl BitSet {
+    /// An empty set of ranks less than `len`.
+    pub(crate) fn new(len: usize) -> Self {
+        BitSet(vec![0; len.div_ceil(64)])
+    }
+
+    /// Insert `i`, returning whether or not `i` was newly inserted.
+    pub(crate) fn insert(&mut self, i: usize) -> bool {
+        let (word, bit) = (i / 64, 1 << (i % 64));
+        let new = self.0[word] & bit == 0;
+        self.0[word] |= bit;
+        new
+    }
+}
+
+/// A closed set of puzzle states (see `ClosedSet`) recorded by their ranks
+/// (see `RankablePuzzle`), in a bit vector.
+pub struct RankSet {
+    bits: BitSet,
+    max_rank: usize,
+    len: usize,
+}
+
+impl RankSet {
+    /// An empty set of puzzle states whose ranks are less than `max_rank`.
+    pub fn new(max_rank: usize) -> Self {
+        RankSet {
+            bits: BitSet::new(max_rank),
+            max_rank,
+            len: 0,
+        }
+    }
+}
+
+impl<P: RankablePuzzle> ClosedSet<P> for RankSet {
+    /// Panics if the rank of `p` is not less than the `max_rank` of the set.
+    fn insert(&mut self, p: &P) -> bool {
+        let rank = p.rank();
+        if rank >= self.max_rank {
+            panic!(
+                "RankSet::insert rank (is {}) should be less than max_rank (is {})",
+                rank, self.max_rank
+            )
+        }
+        let new = self.bits.insert(rank);
+        self.len += usize::from(new);
+        new
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Solve a puzzle using BFS with a bit vector of visited ranks.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`, which should be one of the shortest sequence of
+/// moves from `p0` to a goal state (as for `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// Like `solve`, but the visited puzzle states are recorded by their ranks
+/// (see `RankablePuzzle`) in a bit vector of `p0.max_rank()` bits, rather than
+/// in a hash table of puzzle states, so that no puzzle state is stored except
+/// in the BFS queue; the path to each visited puzzle state is recorded in an
+/// arena of parent indices and moves.  For small, enumerable state spaces
+/// (where many of the ranks are reachable), this is much smaller than a hash
+/// table.  (This is `solve_with_closed_set` with a `RankSet`.)  The
+/// representatives of puzzle states (see `Puzzle::canonicalize`)
+/// and the scores of goal states (see `Puzzle::goal_score`) are ignored.
+///
+/// Panics if the rank of a reached puzzle state is not less than
+/// `p0.max_rank()`.
+pub fn solve_ranked<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: RankablePuzzle,
+{
+    let max_rank = p0.max_rank();
+    solve_with_closed_set(p0, RankSet::new(max_rank))
+}
+
+/// Solve a puzzle using BFS with a bit vector of visited ranks, as configured
+/// by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_ranked(p0)`
+/// (or, with a `max_depth`, without expanding the puzzle states `max_depth`
+/// moves from `p0`) if the search finds it within the limits of `config`, and
+/// otherwise the outcome of the search as for `solve_with_config`.
+///
+/// This is `solve_with_closed_set_with_config` with a `RankSet`.
+///
+/// Panics if the rank of a reached puzzle state is not less than
+/// `p0.max_rank()`.
+pub fn solve_ranked_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: RankablePuzzle,
+    O: Observer<P>,
+{
+    let max_rank = p0.max_rank();
+    solve_with_closed_set_with_config(p0, RankSet::new(max_rank), config)
+}
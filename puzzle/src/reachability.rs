@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use super::Puzzle;
+
+/// A summary of the puzzle states reachable from an initial puzzle state, as
+/// returned by `enumerate_reachable`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReachabilityReport<P> {
+    /// The total number of distinct puzzle states reachable from the initial
+    /// puzzle state (including the initial puzzle state itself).
+    pub states: usize,
+    /// The number of distinct puzzle states at each number of moves from the
+    /// initial puzzle state; `layers[0]` is `1` (the initial puzzle state) and
+    /// `layers.len() - 1` is the greatest number of moves needed to reach any
+    /// reachable puzzle state.
+    pub layers: Vec<usize>,
+    /// The number of distinct reachable goal states.
+    pub goals: usize,
+    /// The first goal state reached (i.e., one with the fewest number of moves
+    /// from the initial puzzle state) and its number of moves, if any goal
+    /// state is reachable.
+    pub nearest_goal: Option<(usize, P)>,
+}
+
+impl<P> ReachabilityReport<P> {
+    /// Determines whether or not any goal state is reachable.
+    pub fn is_solvable(&self) -> bool {
+        self.nearest_goal.is_some()
+    }
+}
+
+/// Enumerate all of the puzzle states reachable from a puzzle, using BFS with
+/// hashing of states.
+///
+/// Returns a `ReachabilityReport` counting the distinct puzzle states reachable
+/// from `p0`, in total and by number of moves from `p0`, and the goal states
+/// among them.
+///
+/// Unlike `solve`, the BFS does not stop at a goal state (nor are goal states
+/// treated as terminal; their successor puzzle states are also enumerated),
+/// and does not record predecessors; it continues until every puzzle state
+/// reachable from `p0` has been visited.  Note that this requires memory
+/// proportional to the size of the entire reachable state space.
+pub fn enumerate_reachable<P>(p0: P) -> ReachabilityReport<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+{
+    let mut hash_set = HashSet::new();
+    hash_set.insert(p0.clone());
+    let mut report = ReachabilityReport {
+        states: 0,
+        layers: vec![],
+        goals: 0,
+        nearest_goal: None,
+    };
+    let mut layer = vec![p0];
+    // Loop till the layer is empty
+    while !layer.is_empty() {
+        let depth = report.layers.len();
+        report.states += layer.len();
+        report.layers.push(layer.len());
+        for p in layer.iter().filter(|p| p.is_goal()) {
+            report.goals += 1;
+            if report.nearest_goal.is_none() {
+                report.nearest_goal = Some((depth, p.clone()));
+            }
+        }
+
+        let mut next_layer = vec![];
+        for p in layer {
+            for (_, q) in p.successors() {
+                if hash_set.insert(q.clone()) {
+                    next_layer.push(q);
+                }
+            }
+        }
+        layer = next_layer;
+    }
+
+    report
+}
@@ -0,0 +1,55 @@
+use std::collections::hash_map::Entry::Vacant;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::InvertiblePuzzle;
+
+/// Compute the exact number of moves to a goal state from every puzzle state
+/// that can reach one of the goal states `goals`, using retrograde analysis
+/// (BFS backwards from the goal states).
+///
+/// Returns a hash table mapping each puzzle state from which some goal state of
+/// `goals` can be reached to the fewest number of moves from it to any goal
+/// state of `goals`; the goal states themselves are mapped to `0`.  (Puzzle
+/// states of `goals` that are not goal states are ignored.)  If `goals`
+/// includes every goal state reachable from a puzzle state `p`, then the number
+/// of moves to which `p` is mapped is the number of moves of the sequence of
+/// moves returned by `solve(p)`, so the hash table is a perfect heuristic; the
+/// puzzle state with the largest number of moves is a hardest starting
+/// position.
+///
+/// The BFS proceeds one layer at a time from all of the goal states at once
+/// (using `P::prev`), until every puzzle state that can reach a goal state has
+/// been visited.  Note that this requires memory proportional to the number of
+/// such puzzle states.
+pub fn analyze_backwards<P, I>(goals: I) -> HashMap<P, usize>
+where
+    P: InvertiblePuzzle + Eq + Hash + Clone,
+    I: IntoIterator<Item = P>,
+{
+    let mut hash_map = HashMap::new();
+    let mut layer = vec![];
+    for goal in goals.into_iter().filter(|p| p.is_goal()) {
+        if let Vacant(e) = hash_map.entry(goal.clone()) {
+            e.insert(0);
+            layer.push(goal);
+        }
+    }
+    let mut depth = 0;
+    // Loop till the layer is empty
+    while !layer.is_empty() {
+        depth += 1;
+        let mut next_layer = vec![];
+        for p in layer {
+            for (_, q) in p.prev() {
+                if let Vacant(e) = hash_map.entry(q.clone()) {
+                    e.insert(depth);
+                    next_layer.push(q);
+                }
+            }
+        }
+        layer = next_layer;
+    }
+
+    hash_map
+}
@@ -0,0 +1,101 @@
+use std::hash::{BuildHasher, Hash};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use super::frontier::{solve_by, solve_by_config, Reached, Search};
+use super::{Fifo, Observer, Puzzle, SolveOutcome, SolverConfig};
+
+/// Solve a puzzle using BFS with hashing of states, exploring the successor
+/// puzzle states of each puzzle state in a random order.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  The sequence of moves `ms` should be one of the
+/// shortest sequence of moves from `p0` to a goal state (as with `solve`), but
+/// which one (and which goal state `p`) depends upon `seed`.
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// The BFS is the same as that of `solve`, except that the successor puzzle
+/// states returned by `P::next` are shuffled (using a random number generator
+/// seeded with `seed`) before being enqueued, which changes the ties broken
+/// between different shortest sequences of moves.  The same `seed` always
+/// returns the same sequence of moves, so that different shortest sequences of
+/// moves can be sampled reproducibly.
+pub fn solve_seeded<P>(p0: P, seed: u64) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    solve_seeded_with_rng(p0, &mut StdRng::seed_from_u64(seed))
+}
+
+/// Solve a puzzle using BFS with hashing of states, exploring the successor
+/// puzzle states of each puzzle state in an order shuffled by the random
+/// number generator `rng`.
+///
+/// Returns the same result as `solve_seeded`, except that which of the
+/// shortest sequences of moves is returned depends upon the draws from `rng`
+/// (rather than upon a seed).
+pub fn solve_seeded_with_rng<P, R>(p0: P, rng: &mut R) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    R: Rng + ?Sized,
+{
+    solve_by(p0, &mut Seeded { rng }, Fifo::new(), &mut Reached::new())
+}
+
+/// Solve a puzzle using BFS with hashing of states, exploring the successor
+/// puzzle states of each puzzle state in a random order, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_seeded(p0, seed)` (or, with a `max_depth`, without expanding the
+/// puzzle states `max_depth` moves from `p0`) if the search finds it within the
+/// limits of `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_with_config`.
+pub fn solve_seeded_with_config<P, S, O>(
+    p0: P,
+    seed: u64,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let rng = &mut StdRng::seed_from_u64(seed);
+    solve_by_config(p0, &mut Seeded { rng }, Fifo::new(), config)
+}
+
+/// The hooks of `solve_seeded_with_rng`, which shuffles the successor puzzle
+/// states of each expansion with `rng`.
+struct Seeded<'a, R: ?Sized> {
+    rng: &'a mut R,
+}
+
+impl<P, R> Search<P> for Seeded<'_, R>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    R: Rng + ?Sized,
+{
+    const NAME: &'static str = "solve_seeded";
+    type Key = P;
+    type Frontier = Fifo<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.clone()
+    }
+
+    fn priority(&mut self, _: usize, _: &P) {}
+
+    fn successors(&mut self, p: &P, _: usize, next: &mut Vec<(P::Move, P)>) {
+        p.next_into(next);
+        next.shuffle(self.rng);
+    }
+}
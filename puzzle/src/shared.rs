@@ -0,0 +1,173 @@
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::rc::Rc;
+use std::time::Instant;
+
+use super::frontier::{Ended, Solved};
+use super::{BfsLimits, Observer, Puzzle, SearchStats, SolveOutcome, SolverConfig};
+
+/// A node of the arena of `solve_shared`: the index of the parent node (with
+/// the connecting move), or `None` for the initial puzzle state.
+struct Node<M> {
+    parent: Option<(usize, M)>,
+}
+
+/// Solve a puzzle using BFS with hashing of shared states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`, which should be one of the shortest sequence of
+/// moves from `p0` to a goal state (as for `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// Like `solve`, but each reached puzzle state is allocated once, in an `Rc`,
+/// which is shared by the hash table and the BFS queue (and the path to each
+/// puzzle state is recorded in an arena of parent indices and moves); so,
+/// puzzle states are never cloned, and `P` need not implement `Clone`.  This
+/// is useful for puzzle states that are expensive to clone (e.g., large boards
+/// on the heap).  The representatives of puzzle states (see
+/// `Puzzle::canonicalize`) are ignored, because a representative would be a
+/// separate allocation.
+pub fn solve_shared<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash,
+{
+    let limits = BfsLimits {
+        max_states: usize::MAX,
+        ..BfsLimits::new(&p0)
+    };
+    let stats = &mut SearchStats::default();
+    match shared(p0, RandomState::new(), &limits, stats, &mut ()) {
+        Ok(ended) => ended.goal(),
+        Err(err) => unreachable!("solve_shared has no limit for {:?}", err),
+    }
+}
+
+/// Solve a puzzle using BFS with hashing of shared states, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_shared(p0)`
+/// (or, with a `max_depth`, without expanding the puzzle states `max_depth`
+/// moves from `p0`) if the search finds it within the limits of `config`, and
+/// otherwise the outcome of the search as for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_with_config`, except that
+/// every reached puzzle state is kept (in its `Rc`) until the search ends: the
+/// `dedupe_scope` of `config` is ignored.
+pub fn solve_shared_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let start = Instant::now();
+    let limits = config.bfs_limits(&p0, start);
+    let (mut stats, observer) = (SearchStats::default(), &mut *config.observer.borrow_mut());
+    let ended = shared(p0, config.hasher.clone(), &limits, &mut stats, observer);
+    let outcome = SolveOutcome::new(ended, stats.visited, |(vec, p)| {
+        observer.on_solution(&vec, &p);
+        stats.depth = Some(vec.len());
+        (vec, p)
+    });
+    stats.finish(start, observer);
+    outcome
+}
+
+/// BFS with hashing of shared states (see `solve_shared`), hashing the puzzle
+/// states with the hasher built by `hash_builder`, within the `max_depth`,
+/// `max_states`, `max_memory_states`, `deadline` (and `cancel`) of `limits`,
+/// counting its expansions and visited puzzle states in `stats`, and reporting
+/// to `observer`.
+fn shared<P, S>(
+    p0: P,
+    hash_builder: S,
+    limits: &BfsLimits,
+    stats: &mut SearchStats,
+    observer: &mut impl Observer<P>,
+) -> Solved<P>
+where
+    P: Puzzle + Eq + Hash,
+    S: BuildHasher,
+{
+    limits.visit(0)?;
+    let mut hash_map = HashMap::<Rc<P>, usize, S>::with_hasher(hash_builder);
+    let mut arena = vec![Node { parent: None }];
+    let mut queue = VecDeque::new();
+    let p0 = Rc::new(p0);
+    hash_map.insert(Rc::clone(&p0), 0);
+    queue.push_back((p0, 0, 0));
+    stats.visited = 1;
+    // the number of moves of the layer being expanded, and whether a puzzle
+    // state at the horizon was left unexpanded
+    let (mut layer, mut cut) = (0, false);
+    while let Some((p, i, d)) = queue.pop_front() {
+        if d > layer {
+            observer.on_layer_complete(layer, arena.len());
+            layer = d;
+        }
+        if p.is_goal() {
+            // the remaining puzzle states with the same number of moves are at
+            // the front of the queue; prefer the goal state with the least score
+            let (p, mut i) = queue
+                .into_iter()
+                .take_while(|(_, _, dq)| *dq == d)
+                .filter(|(q, _, _)| q.is_goal())
+                .fold((p, i), |(p, i), (q, j, _)| {
+                    if q.goal_score() < p.goal_score() {
+                        (q, j)
+                    } else {
+                        (p, i)
+                    }
+                });
+            // backtrack using the parent indices of the nodes
+            let mut vec = vec![];
+            while let Some((parent, m)) = arena[i].parent.take() {
+                vec.push(m);
+                i = parent;
+            }
+            vec.reverse();
+            // the hash table holds the only other reference to the goal state
+            drop(hash_map);
+            return match Rc::into_inner(p) {
+                Some(p) => Ok(Ended::Goal((vec, p))),
+                None => unreachable!("solve_shared goal state should have no other reference"),
+            };
+        }
+        if p.prune() {
+            continue;
+        }
+        // the successor puzzle states of the horizon would be beyond it
+        if d >= limits.max_depth {
+            cut = true;
+            continue;
+        }
+        limits.expand(arena.len(), d)?;
+        observer.on_expand(&p, d);
+        stats.expanded += 1;
+        for (m, q) in p.successors() {
+            stats.generated += 1;
+            // only allocate puzzle states that were not already reached
+            if hash_map.contains_key(&q) {
+                stats.duplicates += 1;
+                continue;
+            }
+            limits.visit(arena.len())?;
+            observer.on_discover(&q, &p, d + 1);
+            let q = Rc::new(q);
+            let j = arena.len();
+            arena.push(Node {
+                parent: Some((i, m)),
+            });
+            hash_map.insert(Rc::clone(&q), j);
+            stats.visited = arena.len();
+            // record dead ends as visited, but do not explore them
+            if !q.is_dead() {
+                queue.push_back((q, j, d + 1));
+            }
+        }
+    }
+
+    observer.on_layer_complete(layer, arena.len());
+    Ok(if cut { Ended::Cut } else { Ended::Exhausted })
+}
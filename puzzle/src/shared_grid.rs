@@ -0,0 +1,130 @@
+use std::ops::Index;
+use std::sync::Arc;
+
+use super::Grid;
+
+/// A persistent grid of `W` columns and `H` rows of cells `T`, as the board of
+/// a grid puzzle with a large board.
+///
+/// Like a `Grid`, the positions of the grid are `(row, col)` pairs; but each
+/// row is shared (by an `Arc`), so that cloning a grid only clones `H`
+/// pointers to rows, and changing a cell of a clone only copies the row of the
+/// cell (if that row is still shared).  So, a successor puzzle state (which
+/// typically changes one or two rows of its predecessor puzzle state) shares
+/// all of its other rows with its predecessor puzzle state, instead of cloning
+/// the whole board on every move.  Grids are equal, ordered, and hashed by
+/// their cells, regardless of sharing.
+///
+/// For small boards (e.g., a 5x5 board of one-byte cells), a `Grid` is
+/// usually cheaper; the persistent grid pays off once copying a board costs
+/// more than the reference counting of its rows.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SharedGrid<T, const W: usize, const H: usize>([Arc<[T; W]>; H]);
+
+impl<T, const W: usize, const H: usize> SharedGrid<T, W, H> {
+    /// The persistent grid with the same cells as `grid`.
+    pub fn new(grid: Grid<T, W, H>) -> Self
+    where
+        T: Copy,
+    {
+        SharedGrid((*grid.cells()).map(Arc::new))
+    }
+
+    /// The persistent grid whose cell at each position is `f(pos)`.
+    pub fn from_fn(mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        SharedGrid(std::array::from_fn(|row| {
+            Arc::new(std::array::from_fn(|col| f((row, col))))
+        }))
+    }
+
+    /// The cell at position `pos`, if `pos` is a position of the grid.
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<&T> {
+        self.0.get(row)?.get(col)
+    }
+
+    /// The row `row` of the grid.
+    ///
+    /// Panics if `row` is not a row of the grid.
+    pub fn row(&self, row: usize) -> &[T; W] {
+        if row >= H {
+            panic!("SharedGrid::row row (is {}) should be less than {}", row, H)
+        }
+        &self.0[row]
+    }
+
+    /// Set the cell at position `pos` to `cell`, copying its row first if the
+    /// row is shared with another grid.
+    ///
+    /// Panics if `pos` is not a position of the grid.
+    pub fn set(&mut self, (row, col): (usize, usize), cell: T)
+    where
+        T: Clone,
+    {
+        if row >= H || col >= W {
+            panic!(
+                "SharedGrid::set pos (is {:?}) should be a position of the grid",
+                (row, col)
+            )
+        }
+        Arc::make_mut(&mut self.0[row])[col] = cell;
+    }
+
+    /// Swap the cells at positions `a` and `b`, copying their rows first if
+    /// they are shared with another grid.
+    ///
+    /// Panics if `a` or `b` is not a position of the grid.
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize))
+    where
+        T: Clone,
+    {
+        let (cell_a, cell_b) = match (self.get(a), self.get(b)) {
+            (Some(cell_a), Some(cell_b)) => (cell_a.clone(), cell_b.clone()),
+            (None, _) => panic!(
+                "SharedGrid::swap a (is {:?}) should be a position of the grid",
+                a
+            ),
+            (_, None) => panic!(
+                "SharedGrid::swap b (is {:?}) should be a position of the grid",
+                b
+            ),
+        };
+        self.set(a, cell_b);
+        self.set(b, cell_a);
+    }
+
+    /// Determines whether or not the row `row` of the grid is shared with
+    /// (i.e., is the same allocation as) the row `row` of `other`.
+    ///
+    /// Panics if `row` is not a row of the grid.
+    pub fn shares_row(&self, other: &Self, row: usize) -> bool {
+        if row >= H {
+            panic!(
+                "SharedGrid::shares_row row (is {}) should be less than {}",
+                row, H
+            )
+        }
+        Arc::ptr_eq(&self.0[row], &other.0[row])
+    }
+
+    /// The grid with the same cells, unshared.
+    pub fn to_grid(&self) -> Grid<T, W, H>
+    where
+        T: Copy,
+    {
+        Grid::new(self.0.each_ref().map(|row| **row))
+    }
+}
+
+impl<T, const W: usize, const H: usize> Index<(usize, usize)> for SharedGrid<T, W, H> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.0[row][col]
+    }
+}
+
+impl<T: Copy, const W: usize, const H: usize> From<Grid<T, W, H>> for SharedGrid<T, W, H> {
+    fn from(grid: Grid<T, W, H>) -> Self {
+        SharedGrid::new(grid)
+    }
+}
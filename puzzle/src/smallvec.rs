@@ -0,0 +1,119 @@
+use std::iter::{Chain, Flatten};
+
+use super::Puzzle;
+
+/// The number of successor puzzle states that a `Successors` list stores
+/// inline (i.e., without a heap allocation); enough for the typical branching
+/// factor of most puzzles.
+pub const INLINE_SUCCESSORS: usize = 16;
+
+/// A list of successor puzzle states (with their moves), as returned by
+/// `Puzzle::next_small`, which only allocates (on the heap) for puzzle states
+/// with more than `INLINE_SUCCESSORS` successor puzzle states.
+pub type Successors<P> = SmallVec<(<P as Puzzle>::Move, P), INLINE_SUCCESSORS>;
+
+/// A vector that stores up to `N` elements inline, and spills all of its
+/// elements to the heap when more are pushed.
+#[derive(Debug, Clone)]
+pub struct SmallVec<T, const N: usize> {
+    /// The number of inline elements, which are the first `len` elements of
+    /// `inline` (and are `Some`), or `None` once spilled to `heap`.
+    len: Option<usize>,
+    inline: [Option<T>; N],
+    heap: Vec<T>,
+}
+
+impl<T, const N: usize> SmallVec<T, N> {
+    /// An empty vector.
+    pub fn new() -> Self {
+        SmallVec {
+            len: Some(0),
+            inline: std::array::from_fn(|_| None),
+            heap: Vec::new(),
+        }
+    }
+
+    /// The number of elements of the vector.
+    pub fn len(&self) -> usize {
+        self.len.unwrap_or(self.heap.len())
+    }
+
+    /// Determines whether or not the vector has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Determines whether or not the elements of the vector have been spilled
+    /// to the heap.
+    pub fn spilled(&self) -> bool {
+        self.len.is_none()
+    }
+
+    /// Append `x` to the vector.
+    pub fn push(&mut self, x: T) {
+        match self.len {
+            Some(len) if len < N => {
+                self.inline[len] = Some(x);
+                self.len = Some(len + 1);
+            }
+            Some(len) => {
+                self.heap = Vec::with_capacity(2 * len.max(1));
+                self.heap
+                    .extend(self.inline.iter_mut().filter_map(Option::take));
+                self.heap.push(x);
+                self.len = None;
+            }
+            None => self.heap.push(x),
+        }
+    }
+
+    /// Remove the last element of the vector, if any.
+    pub fn pop(&mut self) -> Option<T> {
+        match self.len {
+            Some(0) => None,
+            Some(len) => {
+                self.len = Some(len - 1);
+                self.inline[len - 1].take()
+            }
+            None => self.heap.pop(),
+        }
+    }
+
+    /// Sort the vector by the key `f` (stably, as `slice::sort_by_key`).
+    pub fn sort_by_key<K: Ord>(&mut self, mut f: impl FnMut(&T) -> K) {
+        match self.len {
+            Some(len) => self.inline[..len].sort_by_key(|x| x.as_ref().map(&mut f)),
+            None => self.heap.sort_by_key(f),
+        }
+    }
+
+    /// An iterator over (references to) the elements of the vector.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.inline.iter().flatten().chain(self.heap.iter())
+    }
+}
+
+impl<T, const N: usize> Default for SmallVec<T, N> {
+    fn default() -> Self {
+        SmallVec::new()
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SmallVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut v = SmallVec::new();
+        for x in iter {
+            v.push(x);
+        }
+        v
+    }
+}
+
+impl<T, const N: usize> IntoIterator for SmallVec<T, N> {
+    type Item = T;
+    type IntoIter = Chain<Flatten<std::array::IntoIter<Option<T>, N>>, std::vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inline.into_iter().flatten().chain(self.heap)
+    }
+}
@@ -0,0 +1,256 @@
+use std::hash::Hash;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use super::frontier::{resume, seed, Ended, Reached, Search};
+use super::{BfsLimits, ChunkedQueue, Puzzle, SearchStats, Solution, SolveError};
+
+/// A BFS with hashing of states (as of `solve`) as a resumable object, which
+/// is driven one expansion (or one layer) at a time; e.g., by a visual
+/// debugger that shows the frontier after each step.
+///
+/// ```
+/// # use puzzle::Puzzle;
+/// # #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// # struct Count(u32);
+/// # impl Puzzle for Count {
+/// #     type Move = u32;
+/// #     fn is_goal(&self) -> bool {
+/// #         self.0 == 10
+/// #     }
+/// #     fn legal_moves(&self) -> Vec<u32> {
+/// #         vec![1, 2]
+/// #     }
+/// #     fn apply_move(&self, m: &u32) -> Self {
+/// #         Count(self.0 + m)
+/// #     }
+/// # }
+/// # let p0 = Count(0);
+/// let mut s = puzzle::Solver::new(p0);
+/// s.step_n(3);
+/// for (p, depth) in s.frontier() {
+///     println!("{:?} at depth {}", p, depth);
+/// }
+/// println!("{:?}", s.stats());
+/// while !s.step_layer() {}
+/// assert_eq!(s.solution().unwrap().as_ref().unwrap().0.len(), 5);
+/// ```
+///
+/// The solver holds the whole state of the search of `solve`: the arena of
+/// parent indices and moves of the visited puzzle states, the hash table from
+/// (the representatives of) the visited puzzle states to their nodes, and the
+/// chunked queue of puzzle states to expand (the frontier).  It can be
+/// serialized (e.g., to save a search, as does `BfsCheckpoint`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "P: Serialize + Eq + Hash, P::Move: Serialize",
+    deserialize = "P: Deserialize<'de> + Eq + Hash, P::Move: Deserialize<'de>"
+))]
+pub struct Solver<P: Puzzle> {
+    p0: P,
+    reached: Reached<P, P::Move>,
+    queue: ChunkedQueue<(usize, P)>,
+    /// The number of moves of the layer being expanded.
+    layer: usize,
+    stats: SearchStats,
+    result: Option<Solution<P>>,
+}
+
+impl<P> Solver<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    /// A search from `p0` that has not yet expanded any puzzle state.
+    pub fn new(p0: P) -> Self {
+        let mut reached = Reached::new();
+        let mut queue = ChunkedQueue::new();
+        let mut stats = SearchStats::default();
+        let limits = limits(&p0);
+        let seeded = seed(
+            p0.clone(),
+            &mut Step::new(None),
+            &mut queue,
+            &mut reached,
+            &limits,
+            &mut stats,
+        );
+        if let Err(err) = seeded {
+            unreachable!("Solver has no limit for {:?}", err)
+        }
+        Solver {
+            p0,
+            reached,
+            queue,
+            layer: 0,
+            stats,
+            result: None,
+        }
+    }
+
+    /// The initial puzzle state of the search.
+    pub fn initial(&self) -> &P {
+        &self.p0
+    }
+
+    /// Expand the next puzzle state of the frontier.
+    ///
+    /// Returns whether or not the search has finished (see `solution`).
+    pub fn step(&mut self) -> bool {
+        self.step_n(1)
+    }
+
+    /// Expand the next `n` puzzle states of the frontier, or fewer if the
+    /// search finishes first.
+    ///
+    /// Returns whether or not the search has finished (see `solution`).
+    pub fn step_n(&mut self, n: usize) -> bool {
+        let mut step = Step::new(None);
+        step.budget = n;
+        self.run(&mut step)
+    }
+
+    /// Expand the remaining puzzle states of the layer (i.e., with the same
+    /// number of moves from the initial puzzle state) of the next puzzle state
+    /// of the frontier, or fewer if the search finishes first.
+    ///
+    /// Returns whether or not the search has finished (see `solution`).
+    pub fn step_layer(&mut self) -> bool {
+        let layer = self.depth();
+        self.run(&mut Step::new(layer))
+    }
+
+    /// Determines whether or not the search has finished.
+    pub fn is_finished(&self) -> bool {
+        self.result.is_some()
+    }
+
+    /// Returns `Some(soln)` if the search has finished, where `soln` is the
+    /// same result as `solve(p0)`; that is, `Some((ms,p))` for one of the
+    /// shortest sequence of moves `ms` from `p0` to a goal state `p`, or `None`
+    /// if `p0` cannot be solved by any sequence of moves.
+    ///
+    /// Returns `None` if the search has not yet finished.
+    pub fn solution(&self) -> Option<&Solution<P>> {
+        self.result.as_ref()
+    }
+
+    /// Returns `Some(soln)` if the search has finished (as for `solution`),
+    /// consuming the solver.
+    pub fn into_solution(self) -> Option<Solution<P>> {
+        self.result
+    }
+
+    /// The puzzle states of the frontier, in the order in which they will be
+    /// expanded, each with its number of moves from the initial puzzle state.
+    pub fn frontier(&self) -> impl ExactSizeIterator<Item = (&P, usize)> + '_ {
+        self.queue.iter().map(|(i, p)| (p, self.reached.cost(*i)))
+    }
+
+    /// The number of moves from the initial puzzle state of the next puzzle
+    /// state of the frontier, if any.
+    pub fn depth(&self) -> Option<usize> {
+        self.queue.front().map(|(i, _)| self.reached.cost(*i))
+    }
+
+    /// The number of puzzle states visited so far.
+    pub fn states(&self) -> usize {
+        self.reached.len()
+    }
+
+    /// The statistics of the search so far (see `SearchStats`), whose wall
+    /// time is the time spent stepping, with the statistics of each layer.
+    pub fn stats(&self) -> &SearchStats {
+        &self.stats
+    }
+
+    /// Continue the search of `solve` until it is paused by `step` or
+    /// finishes.
+    ///
+    /// Panics if more than `u32::MAX` puzzle states would be visited.
+    fn run(&mut self, step: &mut Step) -> bool {
+        if self.is_finished() {
+            return true;
+        }
+        let start = Instant::now();
+        let limits = limits(&self.p0);
+        let goal = resume(
+            step,
+            &mut self.queue,
+            &mut self.reached,
+            &mut self.layer,
+            &limits,
+            &mut self.stats,
+            &mut (),
+        );
+        match goal {
+            Ok(Ended::Goal((i, p))) => self.result = Some(Some((self.reached.take_path(i), p))),
+            Ok(Ended::Paused) => {}
+            Ok(_) => self.result = Some(None),
+            Err(SolveError::NodeLimit { states }) => {
+                panic!(
+                    "Solver::step states (is {}) should not exceed u32::MAX",
+                    states
+                )
+            }
+            Err(err) => unreachable!("Solver has no limit for {:?}", err),
+        }
+        self.stats.elapsed += start.elapsed();
+        self.is_finished()
+    }
+}
+
+/// The limits of the search of a `Solver` from `p0`: those of `solve`, with
+/// the statistics of each layer.
+fn limits<P: Puzzle>(p0: &P) -> BfsLimits {
+    BfsLimits {
+        layer_stats: true,
+        ..BfsLimits::new(p0)
+    }
+}
+
+/// The hooks of the search of a `Solver`, which are those of the BFS of
+/// `solve`, paused after `budget` expansions (or before the first puzzle
+/// state of the frontier beyond the layer `layer`, if any).
+struct Step {
+    budget: usize,
+    layer: Option<usize>,
+}
+
+impl Step {
+    /// Hooks that pause only at the end of the layer `layer`, if any.
+    fn new(layer: Option<usize>) -> Self {
+        Step {
+            budget: usize::MAX,
+            layer,
+        }
+    }
+}
+
+impl<P: Puzzle + Eq + Hash + Clone> Search<P> for Step {
+    const NAME: &'static str = "Solver";
+    const PREFER_GOAL_SCORE: bool = true;
+    type Key = P;
+    type Frontier = ChunkedQueue<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.canonicalize()
+    }
+
+    fn priority(&mut self, _: usize, _: &P) {}
+
+    fn successors(&mut self, p: &P, _: usize, next: &mut Vec<(P::Move, P)>) {
+        self.budget -= 1;
+        p.next_into(next)
+    }
+
+    fn pause<H>(&mut self, frontier: &Self::Frontier, reached: &Reached<P, P::Move, H>) -> bool {
+        self.budget == 0
+            || self.layer.is_some_and(|layer| {
+                frontier
+                    .front()
+                    .is_some_and(|(i, _)| reached.cost(*i) != layer)
+            })
+    }
+}
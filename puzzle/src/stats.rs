@@ -0,0 +1,124 @@
+use std::hash::{BuildHasher, Hash};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use super::{bounded_bfs, Observer, Puzzle, SolveOutcome, SolverConfig};
+
+/// Statistics of a search, as returned by `solve_with_stats` (or
+/// `Solver::stats`), or as observed from any solver configured by a
+/// `SolverConfig` (see `Observer::on_finish`); a solver that does not count
+/// some of them (e.g., the duplicates of a search without a closed list)
+/// leaves them `0`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SearchStats {
+    /// The number of puzzle states expanded (i.e., whose successor puzzle
+    /// states were generated).
+    pub expanded: usize,
+    /// The number of successor puzzle states generated.
+    pub generated: usize,
+    /// The number of generated successor puzzle states that had already been
+    /// visited (and so were discarded).
+    pub duplicates: usize,
+    /// The number of puzzle states visited (the initial puzzle state included);
+    /// with a `dedupe_scope`, a puzzle state that is forgotten and then
+    /// reached again is counted again.
+    pub visited: usize,
+    /// The maximum number of puzzle states in the frontier (the open list) at
+    /// once.
+    pub max_frontier: usize,
+    /// The maximum number of puzzle states in the closed list at once.
+    pub max_closed: usize,
+    /// The number of moves of the solution, or `None` if none was found.
+    pub depth: Option<usize>,
+    /// The wall time of the search.
+    pub elapsed: Duration,
+    /// The statistics of each layer (i.e., each number of moves from the
+    /// initial puzzle state), if recorded (see `SolverConfig::layer_stats`);
+    /// `layers[d]` is of the puzzle states `d` moves from the initial puzzle
+    /// state.
+    pub layers: Vec<LayerStats>,
+}
+
+impl SearchStats {
+    /// Record the expansion of a puzzle state `depth` moves from the initial
+    /// puzzle state, which generated `generated` successor puzzle states, of
+    /// which `duplicates` had already been visited (given that `layers[0]` is
+    /// of the initial puzzle state).
+    pub(crate) fn record_layer(&mut self, depth: usize, generated: usize, duplicates: usize) {
+        if self.layers.len() < depth + 2 {
+            self.layers.resize(depth + 2, LayerStats::default());
+        }
+        let layer = &mut self.layers[depth];
+        layer.expanded += 1;
+        layer.generated += generated;
+        layer.duplicates += duplicates;
+        self.layers[depth + 1].states += generated - duplicates;
+    }
+
+    /// Record the wall time of a search that started at `start`, and report
+    /// the statistics to `observer` (see `Observer::on_finish`).
+    pub(crate) fn finish<P: Puzzle>(&mut self, start: Instant, observer: &mut impl Observer<P>) {
+        self.elapsed = start.elapsed();
+        observer.on_finish(self);
+    }
+}
+
+/// Statistics of a layer of a search (see `SearchStats::layers`); e.g., to
+/// compare the branching factors of a puzzle with and without a pruning rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct LayerStats {
+    /// The number of puzzle states of the layer that were visited.
+    pub states: usize,
+    /// The number of puzzle states of the layer that were expanded (fewer than
+    /// `states` if some were dead ends, were pruned, or were not reached by
+    /// the end of the search).
+    pub expanded: usize,
+    /// The number of successor puzzle states generated by the expansions.
+    pub generated: usize,
+    /// The number of generated successor puzzle states that had already been
+    /// visited; the others are the puzzle states of the next layer.
+    pub duplicates: usize,
+}
+
+impl LayerStats {
+    /// The number of successor puzzle states that had not already been
+    /// visited.
+    pub fn new_states(&self) -> usize {
+        self.generated - self.duplicates
+    }
+
+    /// The average number of successor puzzle states generated per expanded
+    /// puzzle state (or `0.0`, if none was expanded).
+    pub fn branching_factor(&self) -> f64 {
+        if self.expanded == 0 {
+            0.0
+        } else {
+            self.generated as f64 / self.expanded as f64
+        }
+    }
+}
+
+/// Solve a puzzle using BFS with hashing of states, as configured by `config`,
+/// and collect statistics of the search.
+///
+/// Returns `(outcome, stats)`, where `outcome` is the same outcome as
+/// `solve_with_config(p0, config)`, and `stats` counts the work of the search
+/// (see `SearchStats`).  If a limit of `config` is exceeded, then `stats`
+/// counts the work done until then.
+///
+/// The counts are kept by the BFS itself, so, unlike `measure`, they
+/// include the duplicates and the frontier, at the cost of a few additions per
+/// expansion.  The statistics of the other solvers are observed instead (see
+/// `Observer::on_finish`).
+pub fn solve_with_stats<P, S>(p0: P, config: &SolverConfig<S>) -> (SolveOutcome<P>, SearchStats)
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+{
+    let limits = config.bfs_limits(&p0, Instant::now());
+    let mut stats = SearchStats::default();
+    let r = bounded_bfs(p0, config.hasher.clone(), limits, &mut stats, &mut ());
+    (r, stats)
+}
@@ -0,0 +1,106 @@
+use std::hash::{BuildHasher, Hash};
+
+use super::frontier::{solve_by, solve_by_config, Reached, Search};
+use super::{Fifo, Observer, Puzzle, SolveOutcome, SolverConfig};
+
+/// Trait for puzzles with symmetries (e.g., rotations and reflections of a
+/// gameboard) that preserve the moves and the goal states.
+pub trait Symmetric: Puzzle {
+    /// Enumerates all of the images of the puzzle state under the symmetries
+    /// of the puzzle, including (the image under the identity symmetry) the
+    /// puzzle state itself.
+    ///
+    /// Each image must need the same number of moves to reach a goal state as
+    /// the puzzle state.
+    fn symmetries(&self) -> Vec<Self>
+    where
+        Self: Sized;
+
+    /// Returns the canonical form of the puzzle state: a representative of the
+    /// puzzle states that are symmetric to it.
+    ///
+    /// Symmetric puzzle states must have the same canonical form (e.g., the
+    /// least of all of the symmetric puzzle states, as computed by
+    /// `least_symmetry`).  Moreover, symmetric puzzle states must need the same
+    /// number of moves to reach a goal state.
+    fn canonical(&self) -> Self
+    where
+        Self: Sized;
+}
+
+/// Returns the least of the images of the puzzle state `p` under the
+/// symmetries of the puzzle (see `Symmetric::symmetries`).
+///
+/// Every image of `p` has the same images as `p` (as the symmetries of a
+/// puzzle form a group), so symmetric puzzle states have the same least
+/// image; thus, this is a canonical form (see `Symmetric::canonical`) for any
+/// puzzle with totally ordered puzzle states.
+pub fn least_symmetry<P>(p: &P) -> P
+where
+    P: Symmetric + Ord,
+{
+    match p.symmetries().into_iter().min() {
+        Some(q) => q,
+        None => panic!("least_symmetry p.symmetries() (is empty) should include p"),
+    }
+}
+
+/// Solve a puzzle using BFS with hashing of canonical forms of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`.  The sequence of moves `ms` should be one of the
+/// shortest sequence of moves from `p0` to a goal state (as with `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// The BFS is the same as that of `solve`, except that the hash table is keyed
+/// by the canonical forms of puzzle states (see `Symmetric::canonical`), so
+/// that a puzzle state is not enqueued if a symmetric puzzle state has already
+/// been enqueued.  For a puzzle with `n` symmetries, this visits up to `n`
+/// times fewer puzzle states.  Only the first puzzle state reached with each
+/// canonical form is enqueued (and recorded as a predecessor), so the sequence
+/// of moves `ms` is a legal sequence of moves from `p0` (not from canonical
+/// forms).
+pub fn solve_symmetric<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: Symmetric + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    solve_by(p0, &mut Canonical, Fifo::new(), &mut Reached::new())
+}
+
+/// Solve a puzzle using BFS with hashing of canonical forms of states, as
+/// configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_symmetric(p0)` (or, with a `max_depth`, without expanding the puzzle
+/// states `max_depth` moves from `p0`) if the search finds it within the limits
+/// of `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_with_config`, with the hasher
+/// hashing the canonical forms of puzzle states.
+pub fn solve_symmetric_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: Symmetric + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    solve_by_config(p0, &mut Canonical, Fifo::new(), config)
+}
+
+/// The hooks of `solve_symmetric`.
+struct Canonical;
+
+impl<P: Symmetric + Eq + Hash> Search<P> for Canonical {
+    const NAME: &'static str = "solve_symmetric";
+    type Key = P;
+    type Frontier = Fifo<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.canonical()
+    }
+
+    fn priority(&mut self, _: usize, _: &P) {}
+}
@@ -0,0 +1,363 @@
+use std::fmt::{self, Debug, Formatter};
+use std::fs::{self, File};
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use super::{Heuristic, Puzzle, RankablePuzzle};
+
+/// The magic bytes at the start of a distance table file.
+const MAGIC: [u8; 8] = *b"PZDIST01";
+
+/// The length of the header of a distance table file: the magic bytes and the
+/// number of entries (as a little-endian `u64`).
+const HEADER_LEN: usize = 16;
+
+/// The entry of a rank with no recorded distance.
+const UNKNOWN: u8 = u8::MAX;
+
+/// Write a distance table, for `DistanceTable::open`, to `w`.
+///
+/// The table has an entry for each rank less than `max_rank` (see
+/// `RankablePuzzle`), which records the number of moves to a goal state from
+/// the puzzle state of that rank, as given by `distances` (e.g., by the hash
+/// table returned by `analyze_backwards`).  The numbers of moves saturate at
+/// `u8::MAX - 1` (which remains admissible); the entries of the other ranks
+/// are unknown.  The file is a short header followed by one byte per rank, so
+/// that the entry of a rank is found without reading the rest of the file.
+///
+/// Panics if the rank of a puzzle state of `distances` is not less than
+/// `max_rank`.
+pub fn write_distance_table<P, W, I>(w: W, max_rank: usize, distances: I) -> io::Result<()>
+where
+    P: RankablePuzzle,
+    W: Write,
+    I: IntoIterator<Item = (P, usize)>,
+{
+    let mut entries = vec![UNKNOWN; max_rank];
+    for (p, d) in distances {
+        let rank = p.rank();
+        if rank >= max_rank {
+            panic!(
+                "write_distance_table rank (is {}) should be less than max_rank (is {})",
+                rank, max_rank
+            )
+        }
+        entries[rank] = u8::try_from(d).unwrap_or(UNKNOWN - 1).min(UNKNOWN - 1);
+    }
+    let mut w = BufWriter::new(w);
+    w.write_all(&MAGIC)?;
+    w.write_all(&(max_rank as u64).to_le_bytes())?;
+    w.write_all(&entries)?;
+    w.flush()
+}
+
+/// A precomputed table of the exact number of moves to a goal state from each
+/// puzzle state, by rank (see `RankablePuzzle`), as written by
+/// `write_distance_table`.
+///
+/// The file is read into memory by `open`.  Alternatively, it is
+/// memory-mapped by `open_mapped` (on Unix), so that opening even a table of
+/// hundreds of millions of entries is immediate, and only the pages of the
+/// entries that are looked up are ever read from disk (and they may be shared
+/// with other processes mapping the same file).
+pub struct DistanceTable {
+    entries: Entries,
+}
+
+/// The bytes of a distance table file.
+enum Entries {
+    Read(Vec<u8>),
+    Mapped(Mapping),
+}
+
+impl Entries {
+    fn bytes(&self) -> &[u8] {
+        match self {
+            Entries::Read(bytes) => bytes,
+            Entries::Mapped(map) => map.bytes(),
+        }
+    }
+}
+
+impl DistanceTable {
+    /// Open the distance table file at `path`, reading it into memory.
+    ///
+    /// Returns an error of kind `InvalidData` if the file is not a distance
+    /// table file (or is truncated).
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let bytes = fs::read(path)?;
+        DistanceTable::new(Entries::Read(bytes))
+    }
+
+    /// Open the distance table file at `path`, memory-mapping it (on Unix; on
+    /// other platforms, it is read into memory, as by `open`).
+    ///
+    /// Returns an error of kind `InvalidData` if the file is not a distance
+    /// table file (or is truncated).
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified (e.g., truncated or rewritten, by this
+    /// or another process) while the table is open; the lookups of the table
+    /// read the file itself, so a modification is undefined behavior (and a
+    /// truncation may kill the process with `SIGBUS`).
+    pub unsafe fn open_mapped(path: impl AsRef<Path>) -> io::Result<Self> {
+        let map = Mapping::new(&File::open(path)?)?;
+        DistanceTable::new(Entries::Mapped(map))
+    }
+
+    /// Check the header of the bytes of a distance table file.
+    fn new(entries: Entries) -> io::Result<Self> {
+        let bytes = entries.bytes();
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg);
+        if bytes.len() < HEADER_LEN || bytes[..MAGIC.len()] != MAGIC {
+            return Err(invalid("not a distance table file"));
+        }
+        let mut len = [0; 8];
+        len.copy_from_slice(&bytes[MAGIC.len()..HEADER_LEN]);
+        if u64::from_le_bytes(len) != (bytes.len() - HEADER_LEN) as u64 {
+            return Err(invalid("truncated distance table file"));
+        }
+        Ok(DistanceTable { entries })
+    }
+
+    /// The number of entries of the table (i.e., the `max_rank` of
+    /// `write_distance_table`).
+    pub fn len(&self) -> usize {
+        self.entries.bytes().len() - HEADER_LEN
+    }
+
+    /// Determines whether or not the table has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `Some(d)` if the entry of the rank of `p` records `d` moves to a
+    /// goal state (saturating at `u8::MAX - 1`).  Returns `None` if the rank of
+    /// `p` has no entry or its entry is unknown.
+    pub fn lookup<P: RankablePuzzle>(&self, p: &P) -> Option<usize> {
+        match self.entries.bytes().get(HEADER_LEN + p.rank()) {
+            Some(&d) if d != UNKNOWN => Some(d as usize),
+            _ => None,
+        }
+    }
+
+    /// Estimates the number of moves from `p` to a goal state; that is,
+    /// `lookup(p)`, or `0` if `p` has no recorded distance.
+    pub fn estimate<P: RankablePuzzle>(&self, p: &P) -> usize {
+        self.lookup(p).unwrap_or(0)
+    }
+
+    /// Pair the puzzle state `p` with this distance table, so that it can be
+    /// solved by a solver that requires `Heuristic` (e.g., `solve_astar`).
+    pub fn guide<P>(&self, p: P) -> Tabled<'_, P> {
+        Tabled { p, table: self }
+    }
+}
+
+impl Debug for DistanceTable {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("DistanceTable")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// A read-only mapping of a whole file into memory.
+#[cfg(unix)]
+pub(crate) struct Mapping {
+    ptr: *mut libc::c_void,
+    len: usize,
+}
+
+// The mapping is read-only (and private), so it may be shared between threads.
+#[cfg(unix)]
+unsafe impl Send for Mapping {}
+#[cfg(unix)]
+unsafe impl Sync for Mapping {}
+
+#[cfg(unix)]
+impl Mapping {
+    pub(crate) fn new(file: &File) -> io::Result<Self> {
+        use std::os::unix::io::AsRawFd;
+
+        let len = usize::try_from(file.metadata()?.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "file too large to map"))?;
+        if len == 0 {
+            // an empty mapping is an error for `mmap`
+            return Ok(Mapping {
+                ptr: std::ptr::null_mut(),
+                len,
+            });
+        }
+        // SAFETY: a private read-only mapping of a whole open file, which is
+        // unmapped (only) when the mapping is dropped.
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Mapping { ptr, len })
+    }
+
+    pub(crate) fn bytes(&self) -> &[u8] {
+        if self.len == 0 {
+            return &[];
+        }
+        // SAFETY: the mapping is `len` readable bytes until it is dropped.
+        unsafe { std::slice::from_raw_parts(self.ptr as *const u8, self.len) }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            // SAFETY: the mapping was returned by `mmap` with length `len`.
+            unsafe {
+                libc::munmap(self.ptr, self.len);
+            }
+        }
+    }
+}
+
+/// The contents of a whole file, read into memory (where memory-mapping is not
+/// supported).
+#[cfg(not(unix))]
+pub(crate) struct Mapping(Vec<u8>);
+
+#[cfg(not(unix))]
+impl Mapping {
+    pub(crate) fn new(mut file: &File) -> io::Result<Self> {
+        let mut bytes = vec![];
+        io::Read::read_to_end(&mut file, &mut bytes)?;
+        Ok(Mapping(bytes))
+    }
+
+    pub(crate) fn bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A puzzle state paired with a distance table, whose `Heuristic` estimate is
+/// looked up in the distance table.
+///
+/// The moves, successor puzzle states, and goal states are those of the
+/// puzzle state `p` (as are its costs, representatives, and other hints);
+/// equality and hashing also only consider `p`.  Puzzle states of unknown
+/// distance are dead ends (and are pruned), so the distance table should be
+/// computed (e.g., by `analyze_backwards`) from every goal state reachable from
+/// `p`; then the estimate is exact (up to the saturation of the table).
+pub struct Tabled<'a, P> {
+    pub p: P,
+    table: &'a DistanceTable,
+}
+
+impl<P> Puzzle for Tabled<'_, P>
+where
+    P: RankablePuzzle + Clone,
+{
+    type Move = P::Move;
+
+    fn is_goal(&self) -> bool {
+        self.p.is_goal()
+    }
+
+    fn legal_moves(&self) -> Vec<Self::Move> {
+        self.p.legal_moves()
+    }
+
+    fn apply_move(&self, m: &Self::Move) -> Self {
+        Tabled {
+            p: self.p.apply_move(m),
+            table: self.table,
+        }
+    }
+
+    fn next(&self) -> Vec<(Self::Move, Self)> {
+        self.p
+            .next()
+            .into_iter()
+            .map(|(m, p)| (m, self.table.guide(p)))
+            .collect()
+    }
+
+    fn canonicalize(&self) -> Self {
+        self.table.guide(self.p.canonicalize())
+    }
+
+    fn move_cost(&self, m: &Self::Move) -> u32 {
+        self.p.move_cost(m)
+    }
+
+    fn is_dead(&self) -> bool {
+        self.p.is_dead() || self.table.lookup(&self.p).is_none()
+    }
+
+    fn prune(&self) -> bool {
+        self.p.prune() || self.table.lookup(&self.p).is_none()
+    }
+
+    fn lower_bound(&self) -> usize {
+        self.p.lower_bound()
+    }
+
+    fn goal_score(&self) -> i64 {
+        self.p.goal_score()
+    }
+
+    fn order_hint(&self, m: &Self::Move) -> i32 {
+        self.p.order_hint(m)
+    }
+
+    fn state_count_hint(&self) -> usize {
+        self.p.state_count_hint()
+    }
+}
+
+impl<P> Heuristic for Tabled<'_, P>
+where
+    P: RankablePuzzle + Clone,
+{
+    fn estimate(&self) -> usize {
+        self.table.estimate(&self.p)
+    }
+}
+
+impl<P: Clone> Clone for Tabled<'_, P> {
+    fn clone(&self) -> Self {
+        Tabled {
+            p: self.p.clone(),
+            table: self.table,
+        }
+    }
+}
+
+impl<P: PartialEq> PartialEq for Tabled<'_, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.p == other.p
+    }
+}
+
+impl<P: Eq> Eq for Tabled<'_, P> {}
+
+impl<P: Hash> Hash for Tabled<'_, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.p.hash(state)
+    }
+}
+
+impl<P: Debug> Debug for Tabled<'_, P> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.p.fmt(f)
+    }
+}
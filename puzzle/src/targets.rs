@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+use super::frontier::{solve_by, solve_by_config, Reached, Search};
+use super::{ChunkedQueue, Observer, Puzzle, SolveOutcome, SolverConfig};
+
+/// Solve a puzzle to any of a set of target puzzle states, using BFS with
+/// hashing of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be brought by the sequence of
+/// moves `ms` to a puzzle state `p` of `goals`.  The sequence of moves `ms`
+/// should be one of the shortest sequence of moves from `p0` to any puzzle
+/// state of `goals`.
+///
+/// Returns `None` if no puzzle state of `goals` can be reached from `p0` by any
+/// sequence of moves (in particular, if `goals` is empty).
+///
+/// This is `solve_until(p0, |p| goals.contains(p))`; `P::is_goal` is not used.
+pub fn solve_to_any<P>(p0: P, goals: &HashSet<P>) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+{
+    if goals.is_empty() {
+        return None;
+    }
+    solve_until(p0, |p| goals.contains(p))
+}
+
+/// Solve a puzzle to any of a set of target puzzle states, using BFS with
+/// hashing of states, as configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_to_any(p0, goals)` (or, with a `max_depth`, without expanding the
+/// puzzle states `max_depth` moves from `p0`) if the search finds it within the
+/// limits of `config`, and otherwise the outcome of the search as for
+/// `solve_with_config` (in particular, `SolveOutcome::Unsolvable` if `goals` is
+/// empty).
+///
+/// This is `solve_until_with_config(p0, |p| goals.contains(p), config)`.
+pub fn solve_to_any_with_config<P, S, O>(
+    p0: P,
+    goals: &HashSet<P>,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    if goals.is_empty() {
+        return SolveOutcome::Unsolvable { states_explored: 0 };
+    }
+    solve_until_with_config(p0, |p| goals.contains(p), config)
+}
+
+/// Solve a puzzle for a caller-supplied goal condition, using BFS with hashing
+/// of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be brought by the sequence of
+/// moves `ms` to a puzzle state `p` for which `is_goal(&p)` holds.  The
+/// sequence of moves `ms` should be one of the shortest such sequences of moves
+/// from `p0`.
+///
+/// Returns `None` if no puzzle state satisfying `is_goal` can be reached from
+/// `p0` by any sequence of moves.
+///
+/// The BFS is that of `solve`, except that `is_goal` is used in place of
+/// `P::is_goal`; this allows the same puzzle to be solved for different goal
+/// conditions without defining a different `Puzzle` type for each.  Since the
+/// other hooks of `Puzzle` are about the goal states of `P::is_goal`, they are
+/// not used either: the puzzle states themselves (not their representatives,
+/// see `Puzzle::canonicalize`) are the keys of the hash table, no puzzle state
+/// is skipped as a dead end (`Puzzle::is_dead`) or pruned (`Puzzle::prune`),
+/// and the first goal state reached is returned (whatever its
+/// `Puzzle::goal_score`).  So, `solve_until(p0, P::is_goal)` returns a
+/// solution as short as that of `solve(p0)`, but may explore more puzzle
+/// states and reach a different goal state.
+pub fn solve_until<P, F>(p0: P, is_goal: F) -> Option<(Vec<P::Move>, P)>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    F: Fn(&P) -> bool,
+{
+    let mut until = Until(is_goal);
+    solve_by(p0, &mut until, ChunkedQueue::new(), &mut Reached::new())
+}
+
+/// Solve a puzzle for a caller-supplied goal condition, using BFS with hashing
+/// of states, as configured by `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_until(p0, is_goal)` (or, with a `max_depth`, without expanding the
+/// puzzle states `max_depth` moves from `p0`) if the search finds it within the
+/// limits of `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_with_config`.
+pub fn solve_until_with_config<P, F, S, O>(
+    p0: P,
+    is_goal: F,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    P::Move: Clone,
+    F: Fn(&P) -> bool,
+    S: BuildHasher + Clone,
+    O: Observer<P>,
+{
+    let mut until = Until(is_goal);
+    solve_by_config(p0, &mut until, ChunkedQueue::new(), config)
+}
+
+/// The hooks of `solve_until`, whose goal states are those of its function.
+struct Until<F>(F);
+
+impl<P, F> Search<P> for Until<F>
+where
+    P: Puzzle + Eq + Hash + Clone,
+    F: Fn(&P) -> bool,
+{
+    const NAME: &'static str = "solve_until";
+    type Key = P;
+    type Frontier = ChunkedQueue<(usize, P)>;
+
+    fn key(&mut self, p: &P) -> P {
+        p.clone()
+    }
+
+    fn priority(&mut self, _: usize, _: &P) {}
+
+    fn is_goal(&mut self, p: &P) -> bool {
+        (self.0)(p)
+    }
+
+    fn prune(&mut self, _: &P) -> bool {
+        false
+    }
+
+    fn is_dead(&mut self, _: &P) -> bool {
+        false
+    }
+}
@@ -78,27 +78,27 @@ impl<M, V> MoveTree<M, V> {
                 let mqs = p.next();
                 for (k, _) in kvs.iter() {
                     if mqs.iter().all(|(m, _)| k != m) {
-                        let prefix: Vec<P::Move> = prefix.drain(..).collect();
+                        let prefix: Vec<P::Move> = std::mem::take(prefix);
                         return Err((prefix, MoveTreeVerifyError::MissingMove(k.clone())));
                     }
                 }
                 for (m, _) in mqs.iter() {
                     if mqs.iter().filter(|(n, _)| m == n).count() > 1 {
-                        let prefix: Vec<P::Move> = prefix.drain(..).collect();
+                        let prefix: Vec<P::Move> = std::mem::take(prefix);
                         return Err((prefix, MoveTreeVerifyError::DuplicateMove(m.clone())));
                     }
                 }
                 for (m, q) in mqs.into_iter() {
                     match kvs.iter().find(|(k, _)| &m == k) {
                         None => {
-                            let prefix: Vec<P::Move> = prefix.drain(..).collect();
+                            let prefix: Vec<P::Move> = std::mem::take(prefix);
                             return Err((prefix, MoveTreeVerifyError::ExtraMove(m)));
                         }
                         Some((_, (v, nt))) => {
                             match chk(&q, v) {
                                 Ok(()) => {}
                                 Err(err) => {
-                                    let prefix: Vec<P::Move> = prefix.drain(..).collect();
+                                    let prefix: Vec<P::Move> = std::mem::take(prefix);
                                     return Err((prefix, MoveTreeVerifyError::ChkState(m, err)));
                                 }
                             };
@@ -0,0 +1,337 @@
+use std::hash::{BuildHasherDefault, Hash, Hasher};
+
+use super::frontier::{solve_by, solve_by_index, Reached, Scoped, Search};
+use super::observer::Mapped;
+use super::{Fifo, Observer, Puzzle, SolveOutcome, SolverConfig};
+
+/// Trait for puzzles with a Zobrist hash: a 64-bit hash of the puzzle state
+/// that is the exclusive-or of (random) keys of its features (e.g., of each
+/// piece at each position of a board), so that it can be updated incrementally
+/// as moves are made, by exclusive-oring the keys of the features that the
+/// move removes and adds.
+///
+/// See `zobrist_keys` for the keys and `solve_zobrist` for a solver that uses
+/// the hashes as the keys of its hash table.
+pub trait ZobristHash: Puzzle {
+    /// Returns the Zobrist hash of the puzzle state (computed from scratch).
+    fn zobrist(&self) -> u64;
+
+    /// Returns the Zobrist hash of the puzzle state reached by the (legal)
+    /// move `m`, given the Zobrist hash `hash` of the puzzle state.
+    ///
+    /// Must equal `self.apply_move(m).zobrist()`, which is the default
+    /// implementation; a puzzle should override it to only update `hash` by
+    /// the features changed by `m`.
+    fn zobrist_after(&self, hash: u64, m: &Self::Move) -> u64
+    where
+        Self: Sized,
+    {
+        let _ = hash;
+        self.apply_move(m).zobrist()
+    }
+
+    /// Enumerates all of the (legal) successor puzzle states of the current
+    /// puzzle state, along with the move that leads to that successor puzzle
+    /// state and the Zobrist hash of that successor puzzle state, given the
+    /// Zobrist hash `hash` of the puzzle state.
+    ///
+    /// This should return the same successor puzzle states (in the same order)
+    /// as `next`, each with the hash `zobrist_after(hash, m)`, which is the
+    /// default implementation; a puzzle that computes the changed features of
+    /// a move while making it can override it to update `hash` at the same
+    /// time.
+    fn successors_hashed(&self, hash: u64) -> Vec<(Self::Move, Self, u64)>
+    where
+        Self: Sized,
+    {
+        self.successors()
+            .map(|(m, q)| {
+                let hash = self.zobrist_after(hash, &m);
+                (m, q, hash)
+            })
+            .collect()
+    }
+}
+
+/// Returns `N` pseudo-random keys for Zobrist hashing (see `ZobristHash`),
+/// generated by SplitMix64 from `seed`.
+///
+/// This is a `const fn`, so that a puzzle can compute its keys at compile
+/// time; e.g., `const KEYS: [u64; 64 * 12] = puzzle::zobrist_keys(1);`.
+pub const fn zobrist_keys<const N: usize>(seed: u64) -> [u64; N] {
+    let mut keys = [0; N];
+    let mut state = seed;
+    let mut i = 0;
+    while i < N {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        keys[i] = z ^ (z >> 31);
+        i += 1;
+    }
+    keys
+}
+
+/// A hasher for keys that are already (Zobrist) hashes, which it passes
+/// through unchanged.
+#[derive(Default)]
+struct ZobristHasher(u64);
+
+impl Hasher for ZobristHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 << 8) | u64::from(b);
+        }
+    }
+
+    fn write_u64(&mut self, hash: u64) {
+        self.0 = hash;
+    }
+}
+
+/// A puzzle state with its (incrementally computed) Zobrist hash, as a
+/// puzzle state of the BFS of `solve_zobrist` (and of `solve_zobrist_exact`),
+/// whose successor puzzle states are hashed incrementally (see
+/// `ZobristHash::successors_hashed`), and as a key of the hash table of
+/// `solve_zobrist_exact`: hashed by the Zobrist hash alone (with a
+/// `ZobristHasher`), but equal only to the same puzzle state.
+#[derive(Clone)]
+struct Hashed<P> {
+    hash: u64,
+    p: P,
+}
+
+impl<P: Eq> PartialEq for Hashed<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.p == other.p
+    }
+}
+
+impl<P: Eq> Eq for Hashed<P> {}
+
+impl<P> Hash for Hashed<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash)
+    }
+}
+
+impl<P: ZobristHash> Puzzle for Hashed<P> {
+    type Move = P::Move;
+
+    fn is_goal(&self) -> bool {
+        self.p.is_goal()
+    }
+
+    fn legal_moves(&self) -> Vec<P::Move> {
+        self.p.legal_moves()
+    }
+
+    fn apply_move(&self, m: &P::Move) -> Self {
+        Hashed {
+            hash: self.p.zobrist_after(self.hash, m),
+            p: self.p.apply_move(m),
+        }
+    }
+
+    fn next_into(&self, out: &mut Vec<(P::Move, Self)>) {
+        let next = self.p.successors_hashed(self.hash);
+        out.extend(next.into_iter().map(|(m, p, hash)| (m, Hashed { hash, p })))
+    }
+
+    fn move_cost(&self, m: &P::Move) -> u32 {
+        self.p.move_cost(m)
+    }
+
+    fn is_dead(&self) -> bool {
+        self.p.is_dead()
+    }
+
+    fn prune(&self) -> bool {
+        self.p.prune()
+    }
+
+    fn lower_bound(&self) -> usize {
+        self.p.lower_bound()
+    }
+
+    fn goal_score(&self) -> i64 {
+        self.p.goal_score()
+    }
+
+    fn order_hint(&self, m: &P::Move) -> i32 {
+        self.p.order_hint(m)
+    }
+
+    fn state_count_hint(&self) -> usize {
+        self.p.state_count_hint()
+    }
+}
+
+/// The hooks of `solve_zobrist` (and, if `EXACT`, of `solve_zobrist_exact`),
+/// keyed by the Zobrist hashes of puzzle states (and, if `EXACT`, by the
+/// puzzle states with their hashes).
+struct Zobrist<const EXACT: bool>;
+
+impl<P: ZobristHash> Search<Hashed<P>> for Zobrist<false> {
+    const NAME: &'static str = "solve_zobrist";
+    type Key = u64;
+    type Frontier = Fifo<(usize, Hashed<P>)>;
+
+    fn key(&mut self, p: &Hashed<P>) -> u64 {
+        p.hash
+    }
+
+    fn priority(&mut self, _: usize, _: &Hashed<P>) {}
+}
+
+impl<P: ZobristHash + Eq + Clone> Search<Hashed<P>> for Zobrist<true> {
+    const NAME: &'static str = "solve_zobrist_exact";
+    type Key = Hashed<P>;
+    type Frontier = Fifo<(usize, Hashed<P>)>;
+
+    fn key(&mut self, p: &Hashed<P>) -> Hashed<P> {
+        p.clone()
+    }
+
+    fn priority(&mut self, _: usize, _: &Hashed<P>) {}
+}
+
+/// Solve a puzzle using BFS with Zobrist hashing of states.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`, which should be one of the shortest sequence of
+/// moves from `p0` to a goal state (as for `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// Like `solve`, but the hash table is keyed by the Zobrist hashes of the
+/// puzzle states (see `ZobristHash`), rather than by the puzzle states
+/// themselves: the hash of each successor puzzle state is computed
+/// incrementally from the hash of its predecessor puzzle state (see
+/// `ZobristHash::zobrist_after`), and the hash table stores neither puzzle
+/// states nor their `Hash`es.  Note that two different
+/// puzzle states with the same Zobrist hash are (wrongly) treated as the same
+/// puzzle state; with 64-bit random keys, such a collision is very unlikely
+/// unless the number of puzzle states approaches billions.  Moreover, the
+/// representatives of puzzle states (see `Puzzle::canonicalize`) and the
+/// scores of goal states (see `Puzzle::goal_score`) are ignored.
+pub fn solve_zobrist<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: ZobristHash,
+{
+    let hash = p0.zobrist();
+    let mut reached = Reached::with_hasher(BuildHasherDefault::<ZobristHasher>::default());
+    let soln = solve_by(
+        Hashed { hash, p: p0 },
+        &mut Zobrist::<false>,
+        Fifo::new(),
+        &mut reached,
+    );
+    soln.map(|(ms, goal)| (ms, goal.p))
+}
+
+/// Solve a puzzle using BFS with Zobrist hashing of states, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as `solve_zobrist(p0)`
+/// (or, with a `max_depth`, without expanding the puzzle states `max_depth`
+/// moves from `p0`) if the search finds it within the limits of `config`, and
+/// otherwise the outcome of the search as for `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_with_config`, except that the
+/// hash tables are keyed by Zobrist hashes: the hasher of `config` is ignored.
+pub fn solve_zobrist_with_config<P, S, O>(p0: P, config: &SolverConfig<S, O>) -> SolveOutcome<P>
+where
+    P: ZobristHash,
+    O: Observer<P>,
+{
+    zobrist_by_config(p0, &mut Zobrist::<false>, config)
+}
+
+/// Solve a puzzle using BFS with hashing of states, where the hash of each
+/// puzzle state is its (incrementally computed) Zobrist hash.
+///
+/// Returns `Some((ms,p))` if puzzle `p0` can be solved by the sequence of moves
+/// `ms` to a goal state `p`, which should be one of the shortest sequence of
+/// moves from `p0` to a goal state (as for `solve`).
+///
+/// Returns `None` if `p0` cannot be solved by any sequence of moves.
+///
+/// Like `solve_zobrist`, the hash of each successor puzzle state is computed
+/// incrementally from the hash of its predecessor puzzle state, here along
+/// with the successor puzzle state itself (see
+/// `ZobristHash::successors_hashed`), and the hash table never computes a
+/// `Hash` of a puzzle state; but, unlike `solve_zobrist`, the hash table is
+/// keyed by the puzzle states themselves (with their hashes), so that puzzle
+/// states with the same Zobrist hash are compared, and a collision of hashes
+/// costs only a comparison, never a wrong solution.  The representatives of
+/// puzzle states (see `Puzzle::canonicalize`) and the scores of goal states
+/// (see `Puzzle::goal_score`) are ignored.
+pub fn solve_zobrist_exact<P>(p0: P) -> Option<(Vec<P::Move>, P)>
+where
+    P: ZobristHash + Eq + Clone,
+{
+    let hash = p0.zobrist();
+    let mut reached = Reached::with_hasher(BuildHasherDefault::<ZobristHasher>::default());
+    let soln = solve_by(
+        Hashed { hash, p: p0 },
+        &mut Zobrist::<true>,
+        Fifo::new(),
+        &mut reached,
+    );
+    soln.map(|(ms, goal)| (ms, goal.p))
+}
+
+/// Solve a puzzle using BFS with hashing of states, where the hash of each
+/// puzzle state is its (incrementally computed) Zobrist hash, as configured by
+/// `config`.
+///
+/// Returns `SolveOutcome::Solved` with the same solution as
+/// `solve_zobrist_exact(p0)` (or, with a `max_depth`, without expanding the
+/// puzzle states `max_depth` moves from `p0`) if the search finds it within the
+/// limits of `config`, and otherwise the outcome of the search as for
+/// `solve_with_config`.
+///
+/// The tunables of `config` apply as for `solve_zobrist_with_config`.
+pub fn solve_zobrist_exact_with_config<P, S, O>(
+    p0: P,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: ZobristHash + Eq + Clone,
+    O: Observer<P>,
+{
+    zobrist_by_config(p0, &mut Zobrist::<true>, config)
+}
+
+/// Solve a puzzle by `search` (see `solve_zobrist`), as configured by
+/// `config` (see `solve_by_config`), but with hash tables keyed by Zobrist
+/// hashes, and reporting the puzzle states (without their hashes) to the
+/// observer of `config`.
+fn zobrist_by_config<P, Z, S, O>(
+    p0: P,
+    search: &mut Z,
+    config: &SolverConfig<S, O>,
+) -> SolveOutcome<P>
+where
+    P: ZobristHash,
+    Z: Search<Hashed<P>, Frontier = Fifo<(usize, Hashed<P>)>>,
+    O: Observer<P>,
+{
+    let hash = p0.zobrist();
+    let hasher = BuildHasherDefault::<ZobristHasher>::default();
+    let index = Scoped::new(hasher, config.dedupe_scope);
+    let observer = &mut Mapped(&mut *config.observer.borrow_mut(), unhashed);
+    let p0 = Hashed { hash, p: p0 };
+    solve_by_index(p0, search, Fifo::new(), index, config, observer).map_goal(|goal| goal.p)
+}
+
+/// The puzzle state of `p` (without its hash).
+fn unhashed<P>(p: &Hashed<P>) -> &P {
+    &p.p
+}